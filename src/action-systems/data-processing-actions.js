@@ -123,13 +123,15 @@ export class DataProcessing {
      * @param step.args.source {Array} - the data to group
      * @param step.args.intent {object} - the intent to group on
      * @param step.args.rows {Array} - optional indexes of rows to group
+     * @param step.args.sort {Array} - optional sort intent, groups on a sorted field follow that sort
      * @returns {Promise<any>}
      */
     static async group(step, context, process, item) {
         const data = await crs.process.getValue(step.args.source, context, process, item);
         const intent = await crs.process.getValue(step.args.intent, context, process, item);
         const rows = await crs.process.getValue(step.args.rows, context, process, item);
-        const result = group(data, intent, rows)
+        const sort = await crs.process.getValue(step.args.sort, context, process, item);
+        const result = group(data, intent, rows, sort)
 
         if (step.args.target) {
             await crs.process.setValue(step.args.target, result, context, process, item);
@@ -7,6 +7,7 @@
  * -subtract - This function subtracts the value of the second input from the value of the first input
  * -multiply - This function multiplies two numbers together
  * -divide - This function divides the value of the first input by the value of the second input
+ * -evaluate - This function evaluates an expression such as "qty * price" on the values given
  * -do_math -  It takes two values, performs a mathematical operation on them, and then stores the result in a variable and returns it.
  * -do_math_api - it gets the value of each of the arguments, calls the appropriate Math function, and then sets the result to the target
  * -normalize - This function normalizes a value between a min and max value
@@ -158,6 +159,55 @@ export class MathActions {
         return await this.do_math(step, context, process, item, (value1, value2) => value1 / value2);
     }
 
+    /**
+     * @method evaluate - Evaluates an expression such as "qty * price" with the data processing module.
+     * The expression is parsed, not run as javascript, so it is safe to use with expressions from stored definitions.
+     * Missing values and division by zero give null.
+     * @param step {object} - the step object
+     * @param context {object} - The context object that is passed to the function.
+     * @param process {object} - The process object that is currently running.
+     * @param item {object} - the item that is being processed
+     *
+     * @param step.args.expression {string} - The expression, for example "round(qty * price, 2)"
+     * @param step.args.values {object} - The object the fields of the expression are read from
+     * @param [step.args.target = "$context.result"] {string} - The target to store the result in
+     *
+     * @returns The result of the expression.
+     *
+     * @example <caption>javascript example</caption>
+     * const result = await crs.call("math", "evaluate", {
+     *   expression: "qty * price",
+     *   values: { qty: 2, price: 10 }
+     * }, context, process, item);
+     *
+     * @example <caption>json example</caption>
+     * {
+     *   "type": "math",
+     *   "action": "evaluate",
+     *   "args": {
+     *          "expression": "qty * price",
+     *          "values": "$context.line",
+     *          "target": "$context.result"
+     *    }
+     * }
+     */
+    static async evaluate(step, context, process, item) {
+        const expression = await crs.process.getValue(step.args.expression, context, process, item);
+        const values = await crs.process.getValue(step.args.values, context, process, item);
+
+        // loaded when first used so the math actions don't need the wasm module
+        const module = await import("../wasm/data_processing.js");
+        await module.default();
+
+        const result = module.evaluate_expression(expression, values ?? {});
+
+        if (step.args.target != null) {
+            await crs.process.setValue(step.args.target, result, context, process, item);
+        }
+
+        return result;
+    }
+
     /**
      * @method do_math - It takes two values, performs a mathematical operation on them,and then stores the result in a variable
      * and returns it.
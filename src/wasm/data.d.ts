@@ -1,124 +1,213 @@
 /* tslint:disable */
 /* eslint-disable */
+
 /**
-*/
-export function init_panic_hook(): void;
+ * A filter intent compiled once so it can be run against many datasets without parsing the intent again.
+ */
+export class FilterHandle {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+}
+
 /**
-* Test if a object is visible in the scope of the defined filter.
-* @param {string} intent
-* @param {string} object
-* @param {boolean} case_sensitive
-* @returns {boolean}
-*/
-export function in_filter(intent: string, object: string, case_sensitive: boolean): boolean;
+ * A perspective kept in wasm that is updated with row deltas instead of being built again.
+ */
+export class PerspectiveHandle {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+}
+
+export function aggregate_rows(intent: string, data: string, rows: Uint32Array): string;
+
+export function aggregate_store(id: number, intent: string, rows: Uint32Array): string;
+
+export function build_perspective(intent: string, data: string, rows: Uint32Array): string;
+
+export function calculate_group_aggregate(group: string, aggregate_intent: string, data: string): string;
+
+/**
+ * Compile the filter intent into a plan that is kept in wasm, use run_filter to apply it.
+ */
+export function compile_filter(intent: string, case_sensitive: boolean): FilterHandle;
+
 /**
-* Filter a set of records and give back the indexes of the records visible in the filter.
-* @param {string} intent
-* @param {string} data
-* @param {boolean} case_sensitive
-* @returns {Uint32Array}
-*/
+ * Build a live perspective on the data, with an id field the deltas can find rows by id.
+ */
+export function create_perspective(intent: string, data: string, id_field?: string | null): PerspectiveHandle;
+
+export function evaluate_obj(expr: string, object: string, case_sensitive: boolean): boolean;
+
+/**
+ * Filter a set of records and give back the indexes of the records visible in the filter.
+ */
 export function filter_data(intent: string, data: string, case_sensitive: boolean): Uint32Array;
+
+export function filter_store(id: number, intent: string, case_sensitive: boolean): Uint32Array;
+
 /**
-* @param {string} intent
-* @param {string} data
-* @returns {string}
-*/
+ * The current result of the live perspective, the same as build_perspective.
+ */
+export function get_perspective(handle: PerspectiveHandle): string;
+
+export function group_aggregate_store(id: number, group: string, aggregate_intent: string): string;
+
 export function group_data(intent: string, data: string): string;
+
+export function group_store(id: number, intent: string): string;
+
+/**
+ * Test if a object is visible in the scope of the defined filter.
+ */
+export function in_filter(intent: string, object: string, case_sensitive: boolean): boolean;
+
+export function init_panic_hook(): void;
+
+export function iso8601_batch(dates: string, field_name?: string | null): string;
+
+/**
+ * Convert PT100H30M into "0:0:100:30:0"
+ */
+export function iso8601_to_string(duration: string): string;
+
+export function perspective_store(id: number, intent: string, rows: Uint32Array): string;
+
+/**
+ * The cross tab of the records for a grid or an export, rows and columns are group fields with their totals.
+ * An empty rows collection uses all the records.
+ */
+export function pivot_data(intent: string, data: string, rows: Uint32Array): string;
+
+export function pivot_store(id: number, intent: string, rows: Uint32Array): string;
+
 /**
-* @param {string} intent
-* @param {string} data
-* @param {Uint32Array} rows
-* @returns {Uint32Array}
-*/
+ * Give back the indexes of the records visible in the compiled filter.
+ */
+export function run_filter(handle: FilterHandle, data: string): Uint32Array;
+
+export function run_filter_store(handle: FilterHandle, id: number): Uint32Array;
+
 export function sort_data(intent: string, data: string, rows: Uint32Array): Uint32Array;
+
+export function sort_store(id: number, intent: string, rows: Uint32Array): Uint32Array;
+
 /**
-* @param {string} intent
-* @param {string} data
-* @param {Uint32Array} rows
-* @returns {string}
-*/
-export function aggregate_rows(intent: string, data: string, rows: Uint32Array): string;
+ * Add a json array of records to the end of the dataset, gives back the new record count.
+ */
+export function store_append(id: number, data: string): number;
+
 /**
-* @param {string} group
-* @param {string} aggregate_intent
-* @param {string} data
-* @returns {string}
-*/
-export function calculate_group_aggregate(group: string, aggregate_intent: string, data: string): string;
+ * Release the dataset, the id can't be used after this.
+ */
+export function store_drop(id: number): void;
+
 /**
-* @param {string} intent
-* @param {string} data
-* @param {Uint32Array} rows
-* @returns {string}
-*/
-export function unique_values(intent: string, data: string, rows: Uint32Array): string;
+ * Load a json array of records into the store and give back the id of the dataset.
+ * The processors ending in "_store" work on the stored dataset so the records are only sent across once.
+ */
+export function store_load(data: string): number;
+
 /**
-* Convert PT100H30M into "0:0:100:30:0"
-* @param {string} duration
-* @returns {string}
-*/
-export function iso8601_to_string(duration: string): string;
+ * Same as store_load but the dataset is columnar, the processors read typed columns instead of the json rows.
+ * Use it for large datasets that are filtered, sorted or grouped often, the results are the same.
+ */
+export function store_load_columnar(data: string): number;
+
 /**
-* @param {string} dates
-* @param {string | undefined} [field_name]
-* @returns {string}
-*/
-export function iso8601_batch(dates: string, field_name?: string): string;
-/**
-* @param {string} expr
-* @param {string} object
-* @param {boolean} case_sensitive
-* @returns {boolean}
-*/
-export function evaluate_obj(expr: string, object: string, case_sensitive: boolean): boolean;
+ * Remove the records at the indexes, the records after them move up. Gives back the new record count.
+ */
+export function store_remove_rows(id: number, rows: Uint32Array): number;
+
 /**
-* @param {string} intent
-* @param {string} data
-* @param {Uint32Array} rows
-* @returns {string}
-*/
-export function build_perspective(intent: string, data: string, rows: Uint32Array): string;
+ * Replace the record at the index with the json object.
+ */
+export function store_update_row(id: number, index: number, row: string): void;
+
+export function unique_store(id: number, intent: string, rows: Uint32Array): string;
+
+export function unique_values(intent: string, data: string, rows: Uint32Array): string;
+
+/**
+ * Apply a json array of insert, update and delete deltas and give back a json diff of what changed.
+ * When a delta fails the ones before it stay applied, get_perspective gives the current state.
+ */
+export function update_perspective(handle: PerspectiveHandle, deltas: string): string;
+
+/**
+ * Check an intent of the given kind (filter, sort, group, aggregate, perspective or pivot) without running it.
+ * Gives back a json array of all the problems found, each with a code, path and message.
+ */
+export function validate_intent(kind: string, intent: string): string;
 
 export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
 
 export interface InitOutput {
-  readonly memory: WebAssembly.Memory;
-  readonly init_panic_hook: () => void;
-  readonly in_filter: (a: number, b: number, c: number, d: number, e: number) => number;
-  readonly filter_data: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-  readonly group_data: (a: number, b: number, c: number, d: number, e: number) => void;
-  readonly sort_data: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
-  readonly aggregate_rows: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
-  readonly calculate_group_aggregate: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
-  readonly unique_values: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
-  readonly iso8601_to_string: (a: number, b: number, c: number) => void;
-  readonly iso8601_batch: (a: number, b: number, c: number, d: number, e: number) => void;
-  readonly evaluate_obj: (a: number, b: number, c: number, d: number, e: number) => number;
-  readonly build_perspective: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
-  readonly __wbindgen_malloc: (a: number, b: number) => number;
-  readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
-  readonly __wbindgen_add_to_stack_pointer: (a: number) => number;
-  readonly __wbindgen_free: (a: number, b: number, c: number) => void;
+    readonly memory: WebAssembly.Memory;
+    readonly __wbg_filterhandle_free: (a: number, b: number) => void;
+    readonly __wbg_perspectivehandle_free: (a: number, b: number) => void;
+    readonly aggregate_rows: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+    readonly aggregate_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+    readonly build_perspective: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+    readonly calculate_group_aggregate: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+    readonly compile_filter: (a: number, b: number, c: number) => [number, number, number];
+    readonly create_perspective: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number];
+    readonly evaluate_obj: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+    readonly filter_data: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+    readonly filter_store: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+    readonly get_perspective: (a: number) => [number, number];
+    readonly group_aggregate_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+    readonly group_data: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+    readonly group_store: (a: number, b: number, c: number) => [number, number, number, number];
+    readonly in_filter: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+    readonly init_panic_hook: () => void;
+    readonly iso8601_batch: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+    readonly iso8601_to_string: (a: number, b: number) => [number, number];
+    readonly perspective_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+    readonly pivot_data: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+    readonly pivot_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+    readonly run_filter: (a: number, b: number, c: number) => [number, number, number, number];
+    readonly run_filter_store: (a: number, b: number) => [number, number, number, number];
+    readonly sort_data: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+    readonly sort_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+    readonly store_append: (a: number, b: number, c: number) => [number, number, number];
+    readonly store_drop: (a: number) => [number, number];
+    readonly store_load: (a: number, b: number) => [number, number, number];
+    readonly store_load_columnar: (a: number, b: number) => [number, number, number];
+    readonly store_remove_rows: (a: number, b: number, c: number) => [number, number, number];
+    readonly store_update_row: (a: number, b: number, c: number, d: number) => [number, number];
+    readonly unique_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+    readonly unique_values: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+    readonly update_perspective: (a: number, b: number, c: number) => [number, number, number, number];
+    readonly validate_intent: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+    readonly __wbindgen_free: (a: number, b: number, c: number) => void;
+    readonly __wbindgen_exn_store: (a: number) => void;
+    readonly __externref_table_alloc: () => number;
+    readonly __wbindgen_externrefs: WebAssembly.Table;
+    readonly __wbindgen_malloc: (a: number, b: number) => number;
+    readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+    readonly __externref_table_dealloc: (a: number) => void;
+    readonly __wbindgen_start: () => void;
 }
 
 export type SyncInitInput = BufferSource | WebAssembly.Module;
+
 /**
-* Instantiates the given `module`, which can either be bytes or
-* a precompiled `WebAssembly.Module`.
-*
-* @param {{ module: SyncInitInput }} module - Passing `SyncInitInput` directly is deprecated.
-*
-* @returns {InitOutput}
-*/
+ * Instantiates the given `module`, which can either be bytes or
+ * a precompiled `WebAssembly.Module`.
+ *
+ * @param {{ module: SyncInitInput }} module - Passing `SyncInitInput` directly is deprecated.
+ *
+ * @returns {InitOutput}
+ */
 export function initSync(module: { module: SyncInitInput } | SyncInitInput): InitOutput;
 
 /**
-* If `module_or_path` is {RequestInfo} or {URL}, makes a request and
-* for everything else, calls `WebAssembly.instantiate` directly.
-*
-* @param {{ module_or_path: InitInput | Promise<InitInput> }} module_or_path - Passing `InitInput` directly is deprecated.
-*
-* @returns {Promise<InitOutput>}
-*/
+ * If `module_or_path` is {RequestInfo} or {URL}, makes a request and
+ * for everything else, calls `WebAssembly.instantiate` directly.
+ *
+ * @param {{ module_or_path: InitInput | Promise<InitInput> }} module_or_path - Passing `InitInput` directly is deprecated.
+ *
+ * @returns {Promise<InitOutput>}
+ */
 export default function __wbg_init (module_or_path?: { module_or_path: InitInput | Promise<InitInput> } | InitInput | Promise<InitInput>): Promise<InitOutput>;
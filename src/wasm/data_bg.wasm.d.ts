@@ -1,19 +1,47 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
-export function init_panic_hook(): void;
-export function in_filter(a: number, b: number, c: number, d: number, e: number): number;
-export function filter_data(a: number, b: number, c: number, d: number, e: number, f: number): void;
-export function group_data(a: number, b: number, c: number, d: number, e: number): void;
-export function sort_data(a: number, b: number, c: number, d: number, e: number, f: number, g: number): void;
-export function aggregate_rows(a: number, b: number, c: number, d: number, e: number, f: number, g: number): void;
-export function calculate_group_aggregate(a: number, b: number, c: number, d: number, e: number, f: number, g: number): void;
-export function unique_values(a: number, b: number, c: number, d: number, e: number, f: number, g: number): void;
-export function iso8601_to_string(a: number, b: number, c: number): void;
-export function iso8601_batch(a: number, b: number, c: number, d: number, e: number): void;
-export function evaluate_obj(a: number, b: number, c: number, d: number, e: number): number;
-export function build_perspective(a: number, b: number, c: number, d: number, e: number, f: number, g: number): void;
-export function __wbindgen_malloc(a: number, b: number): number;
-export function __wbindgen_realloc(a: number, b: number, c: number, d: number): number;
-export function __wbindgen_add_to_stack_pointer(a: number): number;
-export function __wbindgen_free(a: number, b: number, c: number): void;
+export const __wbg_filterhandle_free: (a: number, b: number) => void;
+export const __wbg_perspectivehandle_free: (a: number, b: number) => void;
+export const aggregate_rows: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+export const aggregate_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+export const build_perspective: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+export const calculate_group_aggregate: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+export const compile_filter: (a: number, b: number, c: number) => [number, number, number];
+export const create_perspective: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number];
+export const evaluate_obj: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+export const filter_data: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+export const filter_store: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+export const get_perspective: (a: number) => [number, number];
+export const group_aggregate_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+export const group_data: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+export const group_store: (a: number, b: number, c: number) => [number, number, number, number];
+export const in_filter: (a: number, b: number, c: number, d: number, e: number) => [number, number, number];
+export const init_panic_hook: () => void;
+export const iso8601_batch: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+export const iso8601_to_string: (a: number, b: number) => [number, number];
+export const perspective_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+export const pivot_data: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+export const pivot_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+export const run_filter: (a: number, b: number, c: number) => [number, number, number, number];
+export const run_filter_store: (a: number, b: number) => [number, number, number, number];
+export const sort_data: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+export const sort_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+export const store_append: (a: number, b: number, c: number) => [number, number, number];
+export const store_drop: (a: number) => [number, number];
+export const store_load: (a: number, b: number) => [number, number, number];
+export const store_load_columnar: (a: number, b: number) => [number, number, number];
+export const store_remove_rows: (a: number, b: number, c: number) => [number, number, number];
+export const store_update_row: (a: number, b: number, c: number, d: number) => [number, number];
+export const unique_store: (a: number, b: number, c: number, d: number, e: number) => [number, number, number, number];
+export const unique_values: (a: number, b: number, c: number, d: number, e: number, f: number) => [number, number, number, number];
+export const update_perspective: (a: number, b: number, c: number) => [number, number, number, number];
+export const validate_intent: (a: number, b: number, c: number, d: number) => [number, number, number, number];
+export const __wbindgen_free: (a: number, b: number, c: number) => void;
+export const __wbindgen_exn_store: (a: number) => void;
+export const __externref_table_alloc: () => number;
+export const __wbindgen_externrefs: WebAssembly.Table;
+export const __wbindgen_malloc: (a: number, b: number) => number;
+export const __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+export const __externref_table_dealloc: (a: number) => void;
+export const __wbindgen_start: () => void;
@@ -1,158 +1,163 @@
 /* tslint:disable */
 /* eslint-disable */
+
 /**
-*/
-export function init_panic_hook(): void;
-/**
-*
-*    Utility function to get a value on a object path
-*    Exposed for testing purposes
-**
-* @param {any} obj
-* @param {string} path
-* @returns {any}
-*/
-export function get_value(obj: any, path: string): any;
+ *
+ *    JHR: todo
+ *    We want to pass in sort direction as a parameter that can be
+ *    1. Ascending
+ *    2. Descending
+ *    3. None
+ *
+ *    Order the results based on that so that you can see the values in the order you want.
+ *
+ *    @Example
+ *    calculate aggregate on asset for the count of work orders on it and pass it back so tha the asset
+ *    with the most work orders is first and the asset the least is last.
+ *
+ *    @example
+ *    calculate aggregate on asset for the count of work orders
+ *    pass it back where the assets are sorted alphabetically
+ * *
+ */
+export function aggregate(data: Array<any>, intent: any[], row_indexes?: Uint32Array | null): any;
+
 /**
-*
-*    Check if a object matches the filter intent.
-*    Based on the filter intent, return true if the object passes evaluation.
-*    If the object is excluded by the evaluation it returns false.
-**
-* @param {any} intent
-* @param {any} row
-* @param {boolean} case_sensitive
-* @returns {boolean}
-*/
-export function in_filter(intent: any, row: any, case_sensitive: boolean): boolean;
+ *
+ *    Evaluate an expression such as "qty * price" or "coalesce(discount, 0)" on the object.
+ *    The fields of the expression are read from the object, math on missing values gives null.
+ * *
+ */
+export function evaluate_expression(expression: string, values: any): any;
+
 /**
-*
-*    Given an array of objects execute the filter and return an array of indexes of the items that
-*    passes the filter criteria
-**
-* @param {Array<any>} data
-* @param {any} intent
-* @param {boolean} case_sensitive
-* @returns {Array<any>}
-*/
+ *
+ *    Given an array of objects execute the filter and return an array of indexes of the items that
+ *    passes the filter criteria
+ * *
+ */
 export function filter(data: Array<any>, intent: any, case_sensitive: boolean): Array<any>;
-/**
-* @param {Array<any>} data
-* @param {any} intent
-* @returns {Array<any>}
-*/
+
 export function fuzzy_filter(data: Array<any>, intent: any): Array<any>;
+
 /**
-*
-*    Sort the array of objects based on the sort intent.
-*    If you only want to sort a subset of the records, pass in an array of indexes for the objects
-*    that must make up the sort result.
-**
-* @param {Array<any>} data
-* @param {Array<any>} intent
-* @param {Uint32Array | undefined} [rows]
-* @returns {Uint32Array}
-*/
-export function sort(data: Array<any>, intent: Array<any>, rows?: Uint32Array): Uint32Array;
+ *
+ *    Filter, sort and group the records in one call.
+ *    With a page, { offset, limit }, the result is { rows, total } with the records on the page
+ *    and the number of records in all the pages, a page can't be used with group or aggregate.
+ * *
+ */
+export function get_perspective(data: Array<any>, intent: any): any;
+
 /**
-*
-*    JHR todo: we need to be able to pass in sort data so that the group items can be sorted if a group field matched a sort field.
-**
-* @param {Array<any>} data
-* @param {Array<any>} intent
-* @param {Uint32Array | undefined} [rows]
-* @returns {object}
-*/
-export function group(data: Array<any>, intent: Array<any>, rows?: Uint32Array): object;
+ *
+ *    Utility function to get a value on a object path
+ *    Exposed for testing purposes
+ * *
+ */
+export function get_value(obj: any, path: string): any;
+
+/**
+ *
+ *    Every group has an id made from the keys on its path and the value it groups on.
+ *    The children of a group are ordered, groups on a field of the sort intent follow that sort
+ *    unless the group intent gives an order of its own.
+ * *
+ */
+export function group(data: Array<any>, intent: Array<any>, row_indexes?: Uint32Array | null, sort?: Array<any> | null): object;
+
+/**
+ *
+ *    Check if a object matches the filter intent.
+ *    Based on the filter intent, return true if the object passes evaluation.
+ *    If the object is excluded by the evaluation it returns false.
+ * *
+ */
+export function in_filter(intent: any, row: any, case_sensitive: boolean): boolean;
+
+export function init_panic_hook(): void;
+
 /**
-*
-*    JHR: todo
-*    We want to pass in sort direction as a parameter that can be
-*    1. Ascending
-*    2. Descending
-*    3. None
-*
-*    Order the results based on that so that you can see the values in the order you want.
-*
-*    @Example
-*    calculate aggregate on asset for the count of work orders on it and pass it back so tha the asset
-*    with the most work orders is first and the asset the least is last.
-*
-*    @example
-*    calculate aggregate on asset for the count of work orders
-*    pass it back where the assets are sorted alphabetically
-**
-* @param {Array<any>} data
-* @param {any[]} intent
-* @param {Uint32Array | undefined} [rows]
-* @returns {any}
-*/
-export function aggregate(data: Array<any>, intent: any[], rows?: Uint32Array): any;
+ *
+ *    Cross tab the records on the row and column groups of the intent with the aggregates in the cells.
+ *    Every group is followed by its total and the grand total is last.
+ *    The records and fields of the result are the flat rows and columns for export_to_excel.
+ * *
+ */
+export function pivot(data: Array<any>, intent: any, row_indexes?: Uint32Array | null): any;
+
 /**
-*
-*    JHR: todo
-*    Allow sorting of the unique values.
-*    1. Ascending
-*    2. Descending
-*    3. None
-*
-*    @example
-*    Show me the values where the count is the highest to the lowest
-*
-*    @example
-*    Show me the values in a ascending order of the value itself
-**
-* @param {Array<any>} data
-* @param {any[]} intent
-* @param {Uint32Array | undefined} [rows]
-* @returns {any}
-*/
-export function unique_values(data: Array<any>, intent: any[], rows?: Uint32Array): any;
+ *
+ *    Sort the array of objects based on the sort intent.
+ *    If you only want to sort a subset of the records, pass in an array of indexes for the objects
+ *    that must make up the sort result.
+ *    Pass a limit and offset to get one page of the sorted records, only the records up to the end
+ *    of the page are sorted.
+ * *
+ */
+export function sort(data: Array<any>, intent: Array<any>, row_indexes?: Uint32Array | null, limit?: number | null, offset?: number | null): Uint32Array;
+
 /**
-* @param {Array<any>} data
-* @param {any} intent
-* @returns {any}
-*/
-export function get_perspective(data: Array<any>, intent: any): any;
+ *
+ *    JHR: todo
+ *    Allow sorting of the unique values.
+ *    1. Ascending
+ *    2. Descending
+ *    3. None
+ *
+ *    @example
+ *    Show me the values where the count is the highest to the lowest
+ *
+ *    @example
+ *    Show me the values in a ascending order of the value itself
+ * *
+ */
+export function unique_values(data: Array<any>, intent: any[], row_indexes?: Uint32Array | null): any;
 
 export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
 
 export interface InitOutput {
-  readonly memory: WebAssembly.Memory;
-  readonly init_panic_hook: () => void;
-  readonly get_value: (a: number, b: number, c: number) => number;
-  readonly in_filter: (a: number, b: number, c: number, d: number) => void;
-  readonly filter: (a: number, b: number, c: number, d: number) => void;
-  readonly fuzzy_filter: (a: number, b: number, c: number) => void;
-  readonly sort: (a: number, b: number, c: number, d: number, e: number) => void;
-  readonly group: (a: number, b: number, c: number, d: number, e: number) => void;
-  readonly aggregate: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-  readonly unique_values: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-  readonly get_perspective: (a: number, b: number, c: number) => void;
-  readonly __wbindgen_malloc: (a: number, b: number) => number;
-  readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
-  readonly __wbindgen_add_to_stack_pointer: (a: number) => number;
-  readonly __wbindgen_free: (a: number, b: number, c: number) => void;
-  readonly __wbindgen_exn_store: (a: number) => void;
+    readonly memory: WebAssembly.Memory;
+    readonly aggregate: (a: any, b: number, c: number, d: number, e: number) => [number, number, number];
+    readonly evaluate_expression: (a: number, b: number, c: any) => [number, number, number];
+    readonly filter: (a: any, b: any, c: number) => [number, number, number];
+    readonly fuzzy_filter: (a: any, b: any) => [number, number, number];
+    readonly get_perspective: (a: any, b: any) => [number, number, number];
+    readonly get_value: (a: any, b: number, c: number) => any;
+    readonly group: (a: any, b: any, c: number, d: number, e: number) => [number, number, number];
+    readonly in_filter: (a: any, b: any, c: number) => [number, number, number];
+    readonly init_panic_hook: () => void;
+    readonly pivot: (a: any, b: any, c: number, d: number) => [number, number, number];
+    readonly sort: (a: any, b: any, c: number, d: number, e: number, f: number) => [number, number, number, number];
+    readonly unique_values: (a: any, b: number, c: number, d: number, e: number) => [number, number, number];
+    readonly __wbindgen_malloc: (a: number, b: number) => number;
+    readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+    readonly __wbindgen_exn_store: (a: number) => void;
+    readonly __externref_table_alloc: () => number;
+    readonly __wbindgen_externrefs: WebAssembly.Table;
+    readonly __wbindgen_free: (a: number, b: number, c: number) => void;
+    readonly __externref_table_dealloc: (a: number) => void;
+    readonly __wbindgen_start: () => void;
 }
 
 export type SyncInitInput = BufferSource | WebAssembly.Module;
+
 /**
-* Instantiates the given `module`, which can either be bytes or
-* a precompiled `WebAssembly.Module`.
-*
-* @param {{ module: SyncInitInput }} module - Passing `SyncInitInput` directly is deprecated.
-*
-* @returns {InitOutput}
-*/
+ * Instantiates the given `module`, which can either be bytes or
+ * a precompiled `WebAssembly.Module`.
+ *
+ * @param {{ module: SyncInitInput }} module - Passing `SyncInitInput` directly is deprecated.
+ *
+ * @returns {InitOutput}
+ */
 export function initSync(module: { module: SyncInitInput } | SyncInitInput): InitOutput;
 
 /**
-* If `module_or_path` is {RequestInfo} or {URL}, makes a request and
-* for everything else, calls `WebAssembly.instantiate` directly.
-*
-* @param {{ module_or_path: InitInput | Promise<InitInput> }} module_or_path - Passing `InitInput` directly is deprecated.
-*
-* @returns {Promise<InitOutput>}
-*/
+ * If `module_or_path` is {RequestInfo} or {URL}, makes a request and
+ * for everything else, calls `WebAssembly.instantiate` directly.
+ *
+ * @param {{ module_or_path: InitInput | Promise<InitInput> }} module_or_path - Passing `InitInput` directly is deprecated.
+ *
+ * @returns {Promise<InitOutput>}
+ */
 export default function __wbg_init (module_or_path?: { module_or_path: InitInput | Promise<InitInput> } | InitInput | Promise<InitInput>): Promise<InitOutput>;
@@ -1,18 +1,23 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
-export function init_panic_hook(): void;
-export function get_value(a: number, b: number, c: number): number;
-export function in_filter(a: number, b: number, c: number, d: number): void;
-export function filter(a: number, b: number, c: number, d: number): void;
-export function fuzzy_filter(a: number, b: number, c: number): void;
-export function sort(a: number, b: number, c: number, d: number, e: number): void;
-export function group(a: number, b: number, c: number, d: number, e: number): void;
-export function aggregate(a: number, b: number, c: number, d: number, e: number, f: number): void;
-export function unique_values(a: number, b: number, c: number, d: number, e: number, f: number): void;
-export function get_perspective(a: number, b: number, c: number): void;
-export function __wbindgen_malloc(a: number, b: number): number;
-export function __wbindgen_realloc(a: number, b: number, c: number, d: number): number;
-export function __wbindgen_add_to_stack_pointer(a: number): number;
-export function __wbindgen_free(a: number, b: number, c: number): void;
-export function __wbindgen_exn_store(a: number): void;
+export const aggregate: (a: any, b: number, c: number, d: number, e: number) => [number, number, number];
+export const evaluate_expression: (a: number, b: number, c: any) => [number, number, number];
+export const filter: (a: any, b: any, c: number) => [number, number, number];
+export const fuzzy_filter: (a: any, b: any) => [number, number, number];
+export const get_perspective: (a: any, b: any) => [number, number, number];
+export const get_value: (a: any, b: number, c: number) => any;
+export const group: (a: any, b: any, c: number, d: number, e: number) => [number, number, number];
+export const in_filter: (a: any, b: any, c: number) => [number, number, number];
+export const init_panic_hook: () => void;
+export const pivot: (a: any, b: any, c: number, d: number) => [number, number, number];
+export const sort: (a: any, b: any, c: number, d: number, e: number, f: number) => [number, number, number, number];
+export const unique_values: (a: any, b: number, c: number, d: number, e: number) => [number, number, number];
+export const __wbindgen_malloc: (a: number, b: number) => number;
+export const __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+export const __wbindgen_exn_store: (a: number) => void;
+export const __externref_table_alloc: () => number;
+export const __wbindgen_externrefs: WebAssembly.Table;
+export const __wbindgen_free: (a: number, b: number, c: number) => void;
+export const __externref_table_dealloc: (a: number) => void;
+export const __wbindgen_start: () => void;
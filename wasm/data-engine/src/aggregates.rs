@@ -12,6 +12,7 @@ pub use numbers::Ave;
 
 pub use date_time::Max as DateMax;
 pub use date_time::Min as DateMin;
+pub use date_time::DATE_FORMAT;
 
 pub use durations::Max as DurationMax;
 pub use durations::Min as DurationMin;
@@ -1,5 +1,6 @@
 use serde_json::Value;
-use crate::traits::Aggregate;
+use crate::traits::{same_kind, Aggregate};
+use crate::error::DataResult;
 use hashbrown::HashMap;
 
 pub struct Count {
@@ -15,7 +16,7 @@ impl Count {
 }
 
 impl Aggregate for Count {
-    fn add_value(&mut self, obj: &Value) {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
         let key = match obj {
             Value::Null => String::from("null"),
             _ => obj.to_string()
@@ -29,6 +30,15 @@ impl Aggregate for Count {
         }
 
         self.values.insert(key.to_string(), count);
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Count>(other) {
+            for (key, count) in other.values.iter() {
+                *self.values.entry(key.clone()).or_insert(0) += count;
+            }
+        }
     }
 
     fn value(&self) -> Value {
@@ -37,11 +47,11 @@ impl Aggregate for Count {
         for (key, val) in self.values.iter() {
             let mut item = Value::Object(Default::default());
             item["value"] = Value::from(key.clone());
-            item["count"] = Value::from(val.clone());
+            item["count"] = Value::from(*val);
             result.push(item);
         }
 
-        return Value::from(result);
+        Value::from(result)
     }
 }
 
@@ -54,10 +64,10 @@ mod test {
     #[test]
     fn sum_test() {
         let mut count = Count::new();
-        count.add_value(&Value::from(10));
-        count.add_value(&Value::from(20));
-        count.add_value(&Value::from(5));
-        count.add_value(&Value::from(10));
+        count.add_value(&Value::from(10)).unwrap();
+        count.add_value(&Value::from(20)).unwrap();
+        count.add_value(&Value::from(5)).unwrap();
+        count.add_value(&Value::from(10)).unwrap();
 
 
         let result = count.value();
@@ -66,15 +76,32 @@ mod test {
             let val_key = val["value"].clone();
             let val_count = val["count"].clone();
 
-            if val_key == Value::from("20") {
+            if val_key == "20" {
                 assert_eq!(val_count, Value::from(1))
             }
-            if val_key == Value::from("10") {
+            if val_key == "10" {
                 assert_eq!(val_count, Value::from(2))
             }
-            if val_key == Value::from("5") {
+            if val_key == "5" {
                 assert_eq!(val_count, Value::from(1))
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn merge_test() {
+        let mut count = Count::new();
+        count.add_value(&Value::from(10)).unwrap();
+        count.add_value(&Value::Null).unwrap();
+
+        let mut other = Count::new();
+        other.add_value(&Value::from(10)).unwrap();
+        other.add_value(&Value::from(20)).unwrap();
+
+        count.merge(&other);
+
+        assert_eq!(count.values.get("10"), Some(&2));
+        assert_eq!(count.values.get("20"), Some(&1));
+        assert_eq!(count.values.get("null"), Some(&1));
+    }
+}
@@ -0,0 +1,18 @@
+mod min;
+mod max;
+
+use chrono::NaiveDateTime;
+use serde_json::Value;
+use crate::error::{DataError, DataResult, ErrorCode};
+
+pub use min::Min;
+pub use max::Max;
+
+pub const DATE_FORMAT: &str = "%Y/%m/%d %H:%M:%S";
+
+fn parse_date(obj: &Value) -> DataResult<NaiveDateTime> {
+    let date_str = obj.as_str().ok_or_else(|| DataError::invalid_type("", "a date string"))?;
+
+    NaiveDateTime::parse_from_str(date_str, DATE_FORMAT)
+        .map_err(|_| DataError::new(ErrorCode::InvalidValue, "", format!("'{}' is not a date in the format {}", date_str, DATE_FORMAT)))
+}
@@ -0,0 +1,64 @@
+use chrono::NaiveDateTime;
+use serde_json::Value;
+use crate::traits::{same_kind, Aggregate};
+use crate::error::DataResult;
+use crate::aggregates::date_time::{parse_date, DATE_FORMAT};
+
+pub struct Max {
+    pub value: NaiveDateTime
+}
+
+impl Max {
+    pub fn new() -> Max {
+        Max {
+            value: NaiveDateTime::MIN
+        }
+    }
+}
+
+impl Aggregate for Max {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
+        if obj == &Value::Null {
+            return Ok(());
+        }
+
+        let date = parse_date(obj)?;
+        self.value = self.value.max(date);
+        Ok(())
+    }
+
+    fn add_date(&mut self, _obj: &Value, date: &NaiveDateTime) -> DataResult<()> {
+        self.value = self.value.max(*date);
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Max>(other) {
+            self.value = self.value.max(other.value);
+        }
+    }
+
+    fn value(&self) -> Value {
+        let result = self.value.format(DATE_FORMAT).to_string();
+        Value::from(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+    use crate::aggregates::DateMax;
+    use crate::traits::Aggregate;
+
+    #[test]
+    fn test() {
+        let mut max: DateMax = DateMax::new();
+        max.add_value(&Value::from("2020/01/01 00:00:00")).unwrap();
+        max.add_value(&Value::from("2022/01/01 00:00:00")).unwrap();
+        max.add_value(&Value::from("1970/01/01 00:00:00")).unwrap();
+
+        let result = max.value();
+        let result_str = result.as_str().unwrap();
+        assert_eq!(result_str, "2022/01/01 00:00:00");
+    }
+}
\ No newline at end of file
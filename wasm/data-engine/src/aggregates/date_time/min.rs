@@ -0,0 +1,64 @@
+use chrono::NaiveDateTime;
+use serde_json::Value;
+use crate::traits::{same_kind, Aggregate};
+use crate::error::DataResult;
+use crate::aggregates::date_time::{parse_date, DATE_FORMAT};
+
+pub struct Min {
+    pub value: NaiveDateTime
+}
+
+impl Min {
+    pub fn new() -> Min {
+        Min {
+            value: NaiveDateTime::MAX
+        }
+    }
+}
+
+impl Aggregate for Min {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
+        if obj == &Value::Null {
+            return Ok(());
+        }
+
+        let date = parse_date(obj)?;
+        self.value = self.value.min(date);
+        Ok(())
+    }
+
+    fn add_date(&mut self, _obj: &Value, date: &NaiveDateTime) -> DataResult<()> {
+        self.value = self.value.min(*date);
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Min>(other) {
+            self.value = self.value.min(other.value);
+        }
+    }
+
+    fn value(&self) -> Value {
+        let result = self.value.format(DATE_FORMAT).to_string();
+        Value::from(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+    use crate::aggregates::DateMin;
+    use crate::traits::Aggregate;
+
+    #[test]
+    fn test() {
+        let mut min: DateMin = DateMin::new();
+        min.add_value(&Value::from("2020/01/01 00:00:00")).unwrap();
+        min.add_value(&Value::from("2022/01/01 00:00:00")).unwrap();
+        min.add_value(&Value::from("1970/01/01 00:00:00")).unwrap();
+
+        let result = min.value();
+        let result_str = result.as_str().unwrap();
+        assert_eq!(result_str, "1970/01/01 00:00:00");
+    }
+}
\ No newline at end of file
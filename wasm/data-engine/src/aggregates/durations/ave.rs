@@ -0,0 +1,74 @@
+use iso8601_duration::Duration;
+use serde_json::Value;
+use crate::duration::duration_to_seconds;
+use crate::traits::{same_kind, Aggregate};
+use crate::error::{DataError, DataResult};
+
+pub struct Ave {
+    pub sum: f32,
+    pub count: f32
+}
+
+impl Ave {
+    pub fn new() -> Ave {
+        Ave {
+            sum: 0.,
+            count: 0.
+        }
+    }
+
+    fn add_seconds(&mut self, seconds: f32) {
+        self.sum += seconds;
+        self.count += 1.;
+    }
+}
+
+impl Aggregate for Ave {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
+        let value = match obj {
+            Value::Null => return Ok(()),
+            _ => obj.as_str().ok_or_else(|| DataError::invalid_type("", "a duration string"))?
+        };
+
+        let result = Duration::parse(value);
+
+        if let Ok(result) = result {
+            self.add_seconds(duration_to_seconds(&result));
+        }
+
+        Ok(())
+    }
+
+    fn add_duration(&mut self, _obj: &Value, duration: &Duration) -> DataResult<()> {
+        self.add_seconds(duration_to_seconds(duration));
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Ave>(other) {
+            self.sum += other.sum;
+            self.count += other.count;
+        }
+    }
+
+    fn value(&self) -> Value {
+        Value::from(self.sum / self.count)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+    use crate::aggregates::durations::Ave;
+    use crate::traits::Aggregate;
+
+    #[test]
+    fn ave_test() {
+        let mut instance = Ave::new();
+        instance.add_value(&Value::from("PT1M")).unwrap();
+        instance.add_value(&Value::from("PT3M")).unwrap();
+
+        let value = instance.value();
+        assert_eq!(value, Value::from(120.0));
+    }
+}
\ No newline at end of file
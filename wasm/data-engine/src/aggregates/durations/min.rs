@@ -0,0 +1,73 @@
+use iso8601_duration::Duration;
+use serde_json::Value;
+use crate::duration::{duration_to_seconds};
+use crate::traits::{same_kind, Aggregate};
+use crate::error::{DataError, DataResult};
+
+pub struct Min {
+    pub value: f32
+}
+
+impl Min {
+    pub fn new() -> Min {
+        Min {
+            value: f32::MAX
+        }
+    }
+
+    fn add_seconds(&mut self, seconds: f32) {
+        if seconds < self.value {
+            self.value = seconds;
+        }
+    }
+}
+
+impl Aggregate for Min {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
+        let value = match obj {
+            Value::Null => return Ok(()),
+            _ => obj.as_str().ok_or_else(|| DataError::invalid_type("", "a duration string"))?
+        };
+
+        let result = Duration::parse(value);
+
+        if let Ok(result) = result {
+            self.add_seconds(duration_to_seconds(&result));
+        }
+
+        Ok(())
+    }
+
+    fn add_duration(&mut self, _obj: &Value, duration: &Duration) -> DataResult<()> {
+        self.add_seconds(duration_to_seconds(duration));
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Min>(other) {
+            self.add_seconds(other.value);
+        }
+    }
+
+    fn value(&self) -> Value {
+        Value::from(self.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{Value};
+    use crate::aggregates::durations::Min;
+    use crate::traits::Aggregate;
+
+    #[test]
+    fn max_test() {
+        let mut instance = Min::new();
+        instance.add_value(&Value::from("PT2.2S")).unwrap();
+        instance.add_value(&Value::from("PT3.2S")).unwrap();
+        instance.add_value(&Value::from("PT1.2S")).unwrap();
+
+        let value = instance.value();
+        assert_eq!(value, Value::from(1.2000000476837158));
+    }
+}
\ No newline at end of file
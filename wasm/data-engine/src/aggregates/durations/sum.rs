@@ -0,0 +1,82 @@
+use iso8601_duration::Duration;
+use serde_json::Value;
+use crate::duration::{duration_to_seconds};
+use crate::traits::{same_kind, Aggregate};
+use crate::error::{DataError, DataResult};
+
+pub struct Sum {
+    pub value: f32
+}
+
+impl Sum {
+    pub fn new() -> Sum {
+        Sum {
+            value: 0.0
+        }
+    }
+
+    fn add_seconds(&mut self, seconds: f32) {
+        self.value += seconds;
+    }
+}
+
+impl Aggregate for Sum {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
+        let value = match obj {
+            Value::Null => return Ok(()),
+            _ => obj.as_str().ok_or_else(|| DataError::invalid_type("", "a duration string"))?
+        };
+
+        let result = Duration::parse(value);
+
+        if let Ok(result) = result {
+            self.add_seconds(duration_to_seconds(&result));
+        }
+
+        Ok(())
+    }
+
+    fn add_duration(&mut self, _obj: &Value, duration: &Duration) -> DataResult<()> {
+        self.add_seconds(duration_to_seconds(duration));
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Sum>(other) {
+            self.add_seconds(other.value);
+        }
+    }
+
+    fn value(&self) -> Value {
+        Value::from(self.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{Value};
+    use crate::aggregates::durations::Sum;
+    use crate::traits::Aggregate;
+
+    #[test]
+    fn max_test() {
+        let mut instance = Sum::new();
+        instance.add_value(&Value::from("PT1H")).unwrap();
+        instance.add_value(&Value::from("PT1M")).unwrap();
+
+        let value = instance.value();
+        assert_eq!(value, Value::from(3660.0));
+    }
+
+    #[test]
+    fn merge_test() {
+        let mut instance = Sum::new();
+        instance.add_value(&Value::from("PT1H")).unwrap();
+
+        let mut other = Sum::new();
+        other.add_value(&Value::from("PT30M")).unwrap();
+
+        instance.merge(&other);
+        assert_eq!(instance.value(), Value::from(5400.0));
+    }
+}
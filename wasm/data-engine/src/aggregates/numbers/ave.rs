@@ -0,0 +1,80 @@
+use serde_json::Value;
+use crate::traits::{same_kind, Aggregate};
+use crate::error::{DataError, DataResult};
+
+pub struct Ave {
+    pub value: f64,
+    sum: f64,
+    count: f64
+}
+
+impl Ave {
+    pub fn new() -> Ave {
+        Ave {
+            value: 0.,
+            sum: 0.,
+            count: 0.
+        }
+    }
+}
+
+impl Aggregate for Ave {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
+        let value: f64 = match obj {
+            Value::Null => 0.,
+            _ => obj.as_f64().ok_or_else(|| DataError::invalid_type("", "a number"))?
+        };
+
+        self.sum += value;
+        self.count += 1.;
+        self.value = self.sum / self.count;
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Ave>(other) {
+            self.sum += other.sum;
+            self.count += other.count;
+
+            if self.count > 0. {
+                self.value = self.sum / self.count;
+            }
+        }
+    }
+
+    fn value(&self) -> Value {
+        Value::from(self.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+    use crate::aggregates::Ave;
+    use crate::traits::Aggregate;
+
+    #[test]
+    fn sum_test() {
+        let mut ave = Ave::new();
+        ave.add_value(&Value::from(10)).unwrap();
+        ave.add_value(&Value::from(20)).unwrap();
+        ave.add_value(&Value::from(30)).unwrap();
+        ave.add_value(&Value::Null).unwrap();
+
+        assert_eq!(ave.value, 15.);
+    }
+
+    #[test]
+    fn merge_test() {
+        let mut ave = Ave::new();
+        ave.add_value(&Value::from(10)).unwrap();
+
+        let mut other = Ave::new();
+        other.add_value(&Value::from(20)).unwrap();
+        other.add_value(&Value::from(30)).unwrap();
+
+        ave.merge(&other);
+        ave.merge(&Ave::new());
+        assert_eq!(ave.value, 20.);
+    }
+}
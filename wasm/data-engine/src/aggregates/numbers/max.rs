@@ -0,0 +1,60 @@
+use serde_json::Value;
+use crate::traits::{same_kind, Aggregate};
+use crate::error::{DataError, DataResult};
+
+pub struct Max {
+    pub value: f64
+}
+
+impl Max {
+    pub fn new() -> Max {
+        Max {
+            value: f64::MIN
+        }
+    }
+}
+
+impl Aggregate for Max {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
+        let value: f64 = match obj {
+            Value::Null => 0.,
+            _ => obj.as_f64().ok_or_else(|| DataError::invalid_type("", "a number"))?
+        };
+
+        if value > self.value {
+            self.value = value;
+        }
+
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Max>(other) {
+            if other.value > self.value {
+                self.value = other.value;
+            }
+        }
+    }
+
+    fn value(&self) -> Value {
+        Value::from(self.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+    use crate::aggregates::Max;
+    use crate::traits::Aggregate;
+
+    #[test]
+    fn sum_test() {
+        let mut max = Max::new();
+        max.add_value(&Value::from(10)).unwrap();
+        max.add_value(&Value::from(20)).unwrap();
+        max.add_value(&Value::from(5)).unwrap();
+        max.add_value(&Value::Null).unwrap();
+
+        assert_eq!(max.value, 20.);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use serde_json::Value;
+use crate::traits::{same_kind, Aggregate};
+use crate::error::{DataError, DataResult};
+
+pub struct Min {
+    pub value: f64
+}
+
+impl Min {
+    pub fn new() -> Min {
+        Min {
+            value: f64::MAX
+        }
+    }
+}
+
+impl Aggregate for Min {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
+        if obj == &Value::Null {
+            return Ok(());
+        }
+
+        let value = obj.as_f64().ok_or_else(|| DataError::invalid_type("", "a number"))?;
+
+        if value < self.value {
+            self.value = value;
+        }
+
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Min>(other) {
+            if other.value < self.value {
+                self.value = other.value;
+            }
+        }
+    }
+
+    fn value(&self) -> Value {
+        Value::from(self.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+    use crate::aggregates::{Max, Min};
+    use crate::traits::Aggregate;
+
+    #[test]
+    fn sum_test() {
+        let mut min = Min::new();
+        min.add_value(&Value::from(10)).unwrap();
+        min.add_value(&Value::from(20)).unwrap();
+        min.add_value(&Value::from(5)).unwrap();
+        min.add_value(&Value::Null).unwrap();
+
+        assert_eq!(min.value, 5.);
+    }
+
+    #[test]
+    fn merge_test() {
+        let mut min = Min::new();
+        min.add_value(&Value::from(10)).unwrap();
+
+        let mut other = Min::new();
+        other.add_value(&Value::from(5)).unwrap();
+
+        min.merge(&other);
+        min.merge(&Min::new());
+        min.merge(&Max::new());
+        assert_eq!(min.value, 5.);
+    }
+}
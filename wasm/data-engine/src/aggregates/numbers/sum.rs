@@ -0,0 +1,55 @@
+use serde_json::Value;
+use crate::traits::{same_kind, Aggregate};
+use crate::error::{DataError, DataResult};
+
+pub struct Sum {
+    pub value: f64
+}
+
+impl Sum {
+    pub fn new() -> Sum {
+        Sum {
+            value: 0.0
+        }
+    }
+}
+
+impl Aggregate for Sum {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()> {
+        let value: f64 = match obj {
+            Value::Null => 0.,
+            _ => obj.as_f64().ok_or_else(|| DataError::invalid_type("", "a number"))?
+        };
+
+        self.value += value;
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &dyn Aggregate) {
+        if let Some(other) = same_kind::<Sum>(other) {
+            self.value += other.value;
+        }
+    }
+
+    fn value(&self) -> Value {
+        Value::from(self.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+    use crate::aggregates::Sum;
+    use crate::traits::Aggregate;
+
+    #[test]
+    fn sum_test() {
+        let mut sum = Sum::new();
+        sum.add_value(&Value::from(10)).unwrap();
+        sum.add_value(&Value::from(11)).unwrap();
+        sum.add_value(&Value::from(12)).unwrap();
+        sum.add_value(&Value::Null).unwrap();
+
+        assert_eq!(sum.value, 33.);
+    }
+}
\ No newline at end of file
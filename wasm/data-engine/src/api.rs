@@ -0,0 +1,143 @@
+use serde_json::Value;
+use crate::error::DataResult;
+use crate::intent::{Aggregates, FilterExpr, GroupSpec, Page, Perspective, Pivot, SortField, UniqueField};
+use crate::processors;
+use crate::row::Row;
+use crate::utils::check_rows;
+
+/// Indexes of the rows that pass all the expressions of the filter.
+pub fn filter<R: Row>(data: &[R], intent: &[FilterExpr], case_sensitive: bool) -> Vec<usize> {
+    processors::filter(intent, data, case_sensitive)
+}
+
+/// Indexes of the rows in sort order, None sorts all the rows.
+pub fn sort<R: Row>(data: &[R], intent: &[SortField], rows: Option<Vec<usize>>) -> DataResult<Vec<usize>> {
+    let rows = checked(rows, data)?;
+    Ok(processors::sort(intent, data, rows))
+}
+
+/// Indexes of the rows on the page in sort order, without sorting the rows after the page.
+pub fn sort_page<R: Row>(data: &[R], intent: &[SortField], rows: Option<Vec<usize>>, page: &Page) -> DataResult<Vec<usize>> {
+    let rows = checked(rows, data)?;
+    Ok(processors::sort_page(intent, data, rows, page))
+}
+
+/// The group tree of the rows, with the aggregates on every group when there are any, the root holds the grand total.
+pub fn group<R: Row>(data: &[R], intent: &[GroupSpec], rows: Option<Vec<usize>>, aggregates: Option<&Aggregates>) -> DataResult<Value> {
+    let rows = checked(rows, data)?;
+    processors::group(intent, data, rows, aggregates.map(|aggregates| aggregates.0.as_slice()))
+}
+
+/// One object per aggregate in the intent, with the aggregate, the field and the value.
+pub fn aggregate<R: Row>(data: &[R], intent: &Aggregates, rows: Option<Vec<usize>>) -> DataResult<Value> {
+    let rows = checked(rows, data)?;
+    processors::aggregate_rows(&intent.0, data, rows)
+}
+
+/// The unique values of each field with the number of rows they are on.
+pub fn unique<R: Row>(data: &[R], intent: &[UniqueField], rows: Option<Vec<usize>>) -> DataResult<Value> {
+    let rows = checked(rows, data)?;
+    processors::get_unique(intent, data, rows)
+}
+
+/// Filter, sort, group and aggregate the rows in one pass, the same result the wasm modules give.
+pub fn perspective<R: Row>(data: &[R], intent: &Perspective, rows: Option<Vec<usize>>) -> DataResult<Value> {
+    let rows = checked(rows, data)?.unwrap_or_default();
+    processors::perspective_result(intent, data, None, &rows)
+}
+
+/// The cross tab of the rows with the totals of every row and column group, shaped for a grid or an export.
+pub fn pivot<R: Row>(data: &[R], intent: &Pivot, rows: Option<Vec<usize>>) -> DataResult<Value> {
+    let rows = checked(rows, data)?;
+    processors::pivot(intent, data, rows)
+}
+
+fn checked<R>(rows: Option<Vec<usize>>, data: &[R]) -> DataResult<Option<Vec<usize>>> {
+    if let Some(rows) = &rows {
+        check_rows(rows, data.len())?;
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::error::ErrorCode;
+    use crate::intent;
+    use crate::intent::{Aggregates, FilterExpr, GroupSpec, Page, Perspective, Pivot, SortField, UniqueField};
+    use crate::processors::child_group;
+
+    fn child<'a>(group: &'a Value, value: &str) -> &'a Value {
+        child_group(group, value).unwrap()
+    }
+
+    fn get_data() -> Vec<Value> {
+        vec![
+            json!({"id": 0, "code": "A", "value": 10, "site": "north"}),
+            json!({"id": 1, "code": "B", "value": 30, "site": "south"}),
+            json!({"id": 2, "code": "C", "value": 20, "site": "north"}),
+            json!({"id": 3, "code": "D", "value": 5, "site": "south"})
+        ]
+    }
+
+    #[test]
+    fn processors_test() {
+        let data = get_data();
+
+        let filter: Vec<FilterExpr> = intent::parse(&json!([{ "field": "value", "operator": ">", "value": 5 }]), "filter").unwrap();
+        assert_eq!(crate::filter(&data, &filter, true), vec![0, 1, 2]);
+
+        let sort: Vec<SortField> = intent::parse(&json!([{ "name": "value" }]), "sort").unwrap();
+        assert_eq!(crate::sort(&data, &sort, None).unwrap(), vec![3, 0, 2, 1]);
+        assert_eq!(crate::sort(&data, &sort, Some(vec![1, 0])).unwrap(), vec![0, 1]);
+        assert_eq!(crate::sort_page(&data, &sort, None, &Page::new(1, Some(2))).unwrap(), vec![0, 2]);
+
+        let group: Vec<GroupSpec> = intent::parse(&json!(["site"]), "group").unwrap();
+        let aggregates: Aggregates = intent::parse(&json!({ "sum": "value" }), "aggregates").unwrap();
+        let result = crate::group(&data, &group, None, Some(&aggregates)).unwrap();
+        assert_eq!(child(&result["root"], "north")["rows"], json!([0, 2]));
+        assert_eq!(child(&result["root"], "south")["aggregates"][0]["value"], json!(35.0));
+
+        let result = crate::aggregate(&data, &aggregates, Some(vec![0, 1])).unwrap();
+        assert_eq!(result[0]["value"], json!(40.0));
+
+        let unique: Vec<UniqueField> = intent::parse(&json!([{ "name": "site" }]), "fields").unwrap();
+        let result = crate::unique(&data, &unique, None).unwrap();
+        assert_eq!(result["site"], json!([{ "value": "north", "count": 2 }, { "value": "south", "count": 2 }]));
+
+        let pivot: Pivot = intent::parse(&json!({ "rows": ["site"], "columns": ["code"], "values": [{ "agg": "sum", "field": "value" }] }), "pivot").unwrap();
+        let result = crate::pivot(&data, &pivot, Some(vec![0, 1, 2])).unwrap();
+        assert_eq!(result["rows"][0]["cells"], json!([[10.0], [null], [20.0], [30.0]]));
+    }
+
+    #[test]
+    fn perspective_test() {
+        let data = get_data();
+
+        let perspective: Perspective = intent::parse(&json!({
+            "filter": [{ "field": "site", "operator": "==", "value": "north" }],
+            "sort": [{ "name": "value", "direction": "desc" }]
+        }), "").unwrap();
+
+        assert_eq!(crate::perspective(&data, &perspective, None).unwrap(), json!([2, 0]));
+        assert_eq!(crate::perspective(&data, &perspective, Some(vec![0, 1])).unwrap(), json!([0]));
+
+        let perspective: Perspective = intent::parse(&json!({
+            "sort": [{ "name": "value" }],
+            "page": { "offset": 1, "limit": 2 }
+        }), "").unwrap();
+
+        assert_eq!(crate::perspective(&data, &perspective, None).unwrap(), json!({ "rows": [0, 2], "total": 4 }));
+    }
+
+    #[test]
+    fn row_out_of_range_test() {
+        let data = get_data();
+        let sort: Vec<SortField> = intent::parse(&json!([{ "name": "value" }]), "sort").unwrap();
+
+        let error = crate::sort(&data, &sort, Some(vec![0, 4])).unwrap_err();
+        assert_eq!(error.code, ErrorCode::RowOutOfRange);
+        assert_eq!(error.path, "rows[1]");
+    }
+}
@@ -0,0 +1,187 @@
+use std::cmp::Ordering;
+use serde::{Deserialize, Serialize};
+
+/// How strings compare when sorting, the default compares the bytes of the strings.
+/// Case insensitive ignores case, natural compares runs of digits as numbers so "item2" is before "item10"
+/// and ignore accents compares "é" as "e".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Collation {
+    #[serde(default)]
+    pub case_insensitive: bool,
+
+    #[serde(default)]
+    pub natural: bool,
+
+    #[serde(default)]
+    pub ignore_accents: bool
+}
+
+impl Collation {
+    pub fn compare(&self, value1: &str, value2: &str) -> Ordering {
+        if *self == Collation::default() {
+            return value1.cmp(value2);
+        }
+
+        let value1 = self.fold(value1);
+        let value2 = self.fold(value2);
+
+        match self.natural {
+            true => compare_natural(&value1, &value2),
+            false => value1.cmp(&value2)
+        }
+    }
+
+    /// The characters of the string as the collation compares them.
+    fn fold(&self, value: &str) -> Vec<char> {
+        let mut result = Vec::with_capacity(value.len());
+
+        for char in value.chars() {
+            let char = match self.ignore_accents {
+                true => fold_accent(char),
+                false => char
+            };
+
+            match self.case_insensitive {
+                true => result.extend(char.to_lowercase()),
+                false => result.push(char)
+            }
+        }
+
+        result
+    }
+}
+
+/// Runs of digits compare on their value, the other characters one by one.
+fn compare_natural(value1: &[char], value2: &[char]) -> Ordering {
+    let mut index1 = 0;
+    let mut index2 = 0;
+
+    while index1 < value1.len() && index2 < value2.len() {
+        if value1[index1].is_ascii_digit() && value2[index2].is_ascii_digit() {
+            let digits1 = digit_run(value1, &mut index1);
+            let digits2 = digit_run(value2, &mut index2);
+
+            let ordering = digits1.len().cmp(&digits2.len()).then_with(|| digits1.cmp(digits2));
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+
+            continue;
+        }
+
+        let ordering = value1[index1].cmp(&value2[index2]);
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        index1 += 1;
+        index2 += 1;
+    }
+
+    (value1.len() - index1).cmp(&(value2.len() - index2))
+}
+
+/// The digits starting at the index without leading zeros, the index moves past the digits.
+fn digit_run<'a>(value: &'a [char], index: &mut usize) -> &'a [char] {
+    let start = *index;
+
+    while *index < value.len() && value[*index].is_ascii_digit() {
+        *index += 1;
+    }
+
+    let digits = &value[start..*index];
+    let zeros = digits.iter().take_while(|char| **char == '0').count();
+    &digits[zeros.min(digits.len() - 1)..]
+}
+
+/// The latin letter without its accent, other characters stay the same.
+fn fold_accent(char: char) -> char {
+    match char {
+        'À'..='Å' | 'Ā' | 'Ă' | 'Ą' => 'A',
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => 'C',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'Ď' | 'Đ' => 'D',
+        'ď' | 'đ' => 'd',
+        'È'..='Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => 'E',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => 'G',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'Ĥ' | 'Ħ' => 'H',
+        'ĥ' | 'ħ' => 'h',
+        'Ì'..='Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => 'I',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'Ĵ' => 'J',
+        'ĵ' => 'j',
+        'Ķ' => 'K',
+        'ķ' => 'k',
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => 'L',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => 'N',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'Ò'..='Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => 'O',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'Ŕ' | 'Ŗ' | 'Ř' => 'R',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => 'S',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'Ţ' | 'Ť' | 'Ŧ' => 'T',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'Ù'..='Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => 'U',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'Ŵ' => 'W',
+        'ŵ' => 'w',
+        'Ý' | 'Ŷ' | 'Ÿ' => 'Y',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'Ź' | 'Ż' | 'Ž' => 'Z',
+        'ź' | 'ż' | 'ž' => 'z',
+        _ => char
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+    use crate::collation::Collation;
+
+    fn sorted(collation: Collation, values: &[&str]) -> Vec<String> {
+        let mut result: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        result.sort_by(|a, b| collation.compare(a, b));
+        result
+    }
+
+    #[test]
+    fn default_test() {
+        assert_eq!(sorted(Collation::default(), &["apple", "Zebra", "item2", "item10"]), vec!["Zebra", "apple", "item10", "item2"]);
+    }
+
+    #[test]
+    fn case_insensitive_test() {
+        let collation = Collation { case_insensitive: true, ..Default::default() };
+
+        assert_eq!(sorted(collation, &["apple", "Zebra", "Banana"]), vec!["apple", "Banana", "Zebra"]);
+        assert_eq!(collation.compare("ABC", "abc"), Ordering::Equal);
+    }
+
+    #[test]
+    fn natural_test() {
+        let collation = Collation { natural: true, ..Default::default() };
+
+        assert_eq!(sorted(collation, &["item10", "item2", "item1", "item02b", "item"]), vec!["item", "item1", "item2", "item02b", "item10"]);
+        assert_eq!(collation.compare("v1.10", "v1.9"), Ordering::Greater);
+        assert_eq!(collation.compare("a007", "a7"), Ordering::Equal);
+        assert_eq!(collation.compare("a0", "a00"), Ordering::Equal);
+    }
+
+    #[test]
+    fn ignore_accents_test() {
+        let collation = Collation { ignore_accents: true, ..Default::default() };
+        assert_eq!(sorted(collation, &["Émile", "Zoë", "Eva", "Adèle"]), vec!["Adèle", "Émile", "Eva", "Zoë"]);
+
+        let collation = Collation { case_insensitive: true, natural: true, ignore_accents: true };
+        assert_eq!(sorted(collation, &["élément 10", "Element 9", "zone 1"]), vec!["Element 9", "élément 10", "zone 1"]);
+    }
+}
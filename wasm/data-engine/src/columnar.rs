@@ -0,0 +1,63 @@
+mod bitmap;
+mod column;
+mod filter;
+mod sort;
+mod group;
+mod aggregate;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use hashbrown::HashMap;
+use crate::path::FieldPath;
+use crate::row::Row;
+
+pub use column::{Column, ColumnData};
+pub use filter::{filter, ColumnFilter};
+pub use sort::{sort, sort_page};
+pub use group::group;
+pub use aggregate::{aggregate_rows, calculate_group_aggregate};
+
+/// The columns of a dataset, an alternative to reading the fields from the json rows.
+/// A column is built the first time its field is used and kept until the rows change.
+/// The functions in this module give the same results as the processors of the same name.
+#[derive(Default)]
+pub struct ColumnTable {
+    columns: RefCell<HashMap<FieldPath, Rc<Column>>>
+}
+
+impl ColumnTable {
+    /// The column of the field, the data must be the rows the table belongs to.
+    pub fn column<R: Row>(&self, data: &[R], path: &FieldPath) -> Rc<Column> {
+        self.columns.borrow_mut()
+            .entry(path.clone())
+            .or_insert_with(|| Rc::new(Column::build(data, path)))
+            .clone()
+    }
+
+    /// The rows changed, columns are built again when they are used next.
+    pub fn clear(&mut self) {
+        self.columns.get_mut().clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+    use serde_json::{json, Value};
+    use crate::columnar::ColumnTable;
+    use crate::path::FieldPath;
+
+    #[test]
+    fn column_cache_test() {
+        let mut data: Vec<Value> = vec![json!({"code": "A"}), json!({"code": "B"})];
+        let mut table = ColumnTable::default();
+        let path = FieldPath::new("code");
+
+        let column = table.column(&data, &path);
+        assert!(Rc::ptr_eq(&column, &table.column(&data, &path)));
+
+        data[1]["code"] = json!("C");
+        table.clear();
+        assert_eq!(table.column(&data, &path).value(1).as_ref(), "C");
+    }
+}
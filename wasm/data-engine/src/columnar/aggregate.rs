@@ -0,0 +1,135 @@
+use std::rc::Rc;
+use serde_json::Value;
+use crate::columnar::{Column, ColumnData, ColumnTable};
+use crate::error::DataResult;
+use crate::path::FieldPath;
+use crate::intent::AggregateSpec;
+use crate::processors::{aggregate_summary, calculate_group_aggregate_with, create_aggregators};
+use crate::traits::Aggregate;
+use crate::utils::{check_rows, flood_indexes};
+use crate::row::Row;
+
+/// Same as processors::aggregate_rows using the columns of the dataset.
+/// Dates and durations are handed to the aggregates already parsed.
+pub fn aggregate_rows<R: Row>(table: &ColumnTable, intent: &[AggregateSpec], data: &[R], rows: Option<Vec<usize>>) -> DataResult<Value> {
+    let rows = match rows {
+        Some(array) => array,
+        None => flood_indexes(data)
+    };
+
+    let mut aggregator = create_aggregators(intent);
+    add_rows(table, &mut aggregator, intent, data, &rows)?;
+
+    Ok(aggregate_summary(intent, &aggregator))
+}
+
+/// Same as processors::add_rows using the columns of the dataset.
+pub(crate) fn add_rows<R: Row>(table: &ColumnTable, aggregator: &mut [Box<dyn Aggregate>], intent: &[AggregateSpec], data: &[R], rows: &[usize]) -> DataResult<()> {
+    let columns: Vec<Rc<Column>> = intent.iter().map(|spec| table.column(data, &FieldPath::new(&spec.field))).collect();
+
+    for row_index in rows {
+        for (i, spec) in intent.iter().enumerate() {
+            add_cell(aggregator[i].as_mut(), &columns[i], *row_index)
+                .map_err(|error| error.at(&spec.field).at(&format!("data[{}]", row_index)))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Same as processors::calculate_group_aggregate using the columns of the dataset.
+pub fn calculate_group_aggregate<R: Row>(table: &ColumnTable, group_data: &mut Value, aggregate_intent: &[AggregateSpec], data: &[R]) -> DataResult<()> {
+    calculate_group_aggregate_with(group_data, aggregate_intent, &|aggregator, rows| {
+        check_rows(rows, data.len())?;
+        add_rows(table, aggregator, aggregate_intent, data, rows)
+    })
+}
+
+/// Missing fields are skipped the same as the row based aggregate.
+fn add_cell(aggregator: &mut dyn Aggregate, column: &Column, row: usize) -> DataResult<()> {
+    if column.is_missing(row) {
+        return Ok(());
+    }
+
+    let value = column.value(row);
+
+    if column.is_null(row) {
+        return aggregator.add_value(&value);
+    }
+
+    match &column.data {
+        ColumnData::Duration(_, durations) => aggregator.add_duration(&value, &durations[column.code(row)]),
+        ColumnData::Date(_, dates) => aggregator.add_date(&value, &dates[column.code(row)]),
+        _ => aggregator.add_value(&value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::columnar::{aggregate_rows, calculate_group_aggregate, ColumnTable};
+    use crate::intent;
+    use crate::intent::{Aggregates, GroupSpec};
+    use crate::processors;
+    use random_data::generate_data;
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "value": 10, "price": 1.5, "duration": "PT1H", "date": "2022/01/01 10:00:00"}));
+        result.push(json!({"id": 1, "value": 10, "price": null, "duration": "PT2H30M", "date": "2022/02/01 10:00:00"}));
+        result.push(json!({"id": 2, "value": null, "price": 2.5, "date": null}));
+        result.push(json!({"id": 3, "value": 20, "duration": null, "date": "2021/01/01 10:00:00"}));
+        result.push(json!({"id": 4, "value": 5, "price": 0.5, "duration": "P1D"}));
+        result
+    }
+
+    fn parse(intent: Value) -> Aggregates {
+        intent::parse(&intent, "aggregates").unwrap()
+    }
+
+    #[test]
+    fn aggregate_test() {
+        let data = get_data();
+        let table = ColumnTable::default();
+
+        let intents = [
+            json!({ "ave": "value", "max": "value", "min": "price", "sum": "price" }),
+            json!({ "ave:duration": "duration", "max:duration": "duration", "min:duration": "duration", "sum:duration": "duration" }),
+            json!({ "max:date": "date", "min:date": "date", "max": "id" })
+        ];
+
+        for intent in intents {
+            let intent = parse(intent);
+            let expected = processors::aggregate_rows(&intent.0, &data, None).unwrap();
+            assert_eq!(aggregate_rows(&table, &intent.0, &data, None).unwrap(), expected);
+
+            let expected = processors::aggregate_rows(&intent.0, &data, Some(vec![3, 1])).unwrap();
+            assert_eq!(aggregate_rows(&table, &intent.0, &data, Some(vec![3, 1])).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn aggregate_error_test() {
+        let data = get_data();
+        let table = ColumnTable::default();
+        let intent = parse(json!({ "sum": "price", "max:date": "duration" }));
+
+        let expected = processors::aggregate_rows(&intent.0, &data, None).unwrap_err();
+        assert_eq!(aggregate_rows(&table, &intent.0, &data, None).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn group_aggregate_test() {
+        let data = generate_data(1000);
+        let table = ColumnTable::default();
+        let intent = parse(json!({ "sum": "number", "ave:duration": "duration" }));
+
+        let group = processors::group(&[GroupSpec::from("externalCode")], &data, None, None).unwrap();
+        let mut expected = group.clone();
+        let mut result = group;
+
+        processors::calculate_group_aggregate(&mut expected, &intent.0, &data).unwrap();
+        calculate_group_aggregate(&table, &mut result, &intent.0, &data).unwrap();
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,40 @@
+/// One bit per row, used to mark the null and missing cells of a column.
+#[derive(Debug, Clone, Default)]
+pub struct Bitmap {
+    words: Vec<u64>
+}
+
+impl Bitmap {
+    pub fn new(length: usize) -> Bitmap {
+        Bitmap {
+            words: vec![0; length.div_ceil(64)]
+        }
+    }
+
+    pub fn set(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::columnar::bitmap::Bitmap;
+
+    #[test]
+    fn bitmap_test() {
+        let mut bitmap = Bitmap::new(130);
+        bitmap.set(0);
+        bitmap.set(64);
+        bitmap.set(129);
+
+        assert!(bitmap.get(0));
+        assert!(!bitmap.get(1));
+        assert!(bitmap.get(64));
+        assert!(!bitmap.get(128));
+        assert!(bitmap.get(129));
+    }
+}
@@ -0,0 +1,268 @@
+use std::borrow::Cow;
+use chrono::NaiveDateTime;
+use hashbrown::HashMap;
+use iso8601_duration::Duration;
+use serde_json::Value;
+use crate::aggregates::DATE_FORMAT;
+use crate::columnar::bitmap::Bitmap;
+use crate::path::FieldPath;
+use crate::row::Row;
+
+static NULL: Value = Value::Null;
+
+/// The values of one field for every row of a dataset.
+/// Null and missing cells are marked in the bitmaps, the typed vectors hold a default value for them.
+pub struct Column {
+    pub data: ColumnData,
+    nulls: Bitmap,
+    missing: Bitmap
+}
+
+pub enum ColumnData {
+    Int(Vec<i64>),
+    Float(Vec<f64>),
+    Bool(Vec<bool>),
+
+    /// Each distinct string is kept once, the rows hold the code of their entry.
+    Text(Dictionary),
+
+    /// Strings that are all dates in the format of the date aggregates, parsed once per entry.
+    Date(Dictionary, Vec<NaiveDateTime>),
+
+    /// Strings that are all iso8601 durations, parsed once per entry.
+    Duration(Dictionary, Vec<Duration>),
+
+    /// Fields with more than one type of value or with values such as arrays and objects.
+    Mixed(Vec<Value>)
+}
+
+pub struct Dictionary {
+    pub codes: Vec<u32>,
+    pub entries: Vec<Value>
+}
+
+impl Dictionary {
+    fn build<'a>(values: impl Iterator<Item = &'a Value>) -> Dictionary {
+        let mut lookup: HashMap<&str, u32> = HashMap::new();
+        let mut codes = Vec::new();
+        let mut entries = Vec::new();
+
+        for value in values {
+            let code = match value.as_str() {
+                None => 0,
+                Some(text) => *lookup.entry(text).or_insert_with(|| {
+                    entries.push(value.clone());
+                    entries.len() as u32 - 1
+                })
+            };
+
+            codes.push(code);
+        }
+
+        Dictionary {
+            codes,
+            entries
+        }
+    }
+
+    fn texts(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().filter_map(Value::as_str)
+    }
+}
+
+/// The type of the values found in a column, anything that can't be typed is mixed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Empty,
+    Int,
+    Float,
+    Bool,
+    Text,
+    Mixed
+}
+
+impl Kind {
+    fn of(value: &Value) -> Kind {
+        match value {
+            Value::Number(number) if number.is_i64()    => Kind::Int,
+            Value::Number(number) if number.is_f64()    => Kind::Float,
+            Value::Bool(_)                              => Kind::Bool,
+            Value::String(_)                            => Kind::Text,
+            _                                           => Kind::Mixed
+        }
+    }
+
+    fn merge(self, other: Kind) -> Kind {
+        match self {
+            Kind::Empty => other,
+            _ if self == other => self,
+            _ => Kind::Mixed
+        }
+    }
+}
+
+impl Column {
+    /// Read the field from every row of the data.
+    pub fn build<R: Row>(data: &[R], path: &FieldPath) -> Column {
+        let mut nulls = Bitmap::new(data.len());
+        let mut missing = Bitmap::new(data.len());
+        let mut kind = Kind::Empty;
+
+        let cells: Vec<Option<Cow<Value>>> = data.iter().map(|row| row.field(path)).collect();
+
+        for (index, cell) in cells.iter().enumerate() {
+            match cell.as_deref() {
+                None => {
+                    nulls.set(index);
+                    missing.set(index);
+                }
+                Some(Value::Null) => nulls.set(index),
+                Some(value) => kind = kind.merge(Kind::of(value))
+            }
+        }
+
+        let values = cells.iter().map(|cell| cell.as_deref().unwrap_or(&NULL));
+
+        let data = match kind {
+            Kind::Int   => ColumnData::Int(values.map(|value| value.as_i64().unwrap_or(0)).collect()),
+            Kind::Float => ColumnData::Float(values.map(|value| value.as_f64().unwrap_or(0.)).collect()),
+            Kind::Bool  => ColumnData::Bool(values.map(|value| value.as_bool().unwrap_or(false)).collect()),
+            Kind::Text  => text_data(Dictionary::build(values)),
+            Kind::Empty | Kind::Mixed => ColumnData::Mixed(values.cloned().collect())
+        };
+
+        Column {
+            data,
+            nulls,
+            missing
+        }
+    }
+
+    /// The cell is null or the row does not have the field.
+    pub fn is_null(&self, row: usize) -> bool {
+        self.nulls.get(row)
+    }
+
+    pub fn is_missing(&self, row: usize) -> bool {
+        self.missing.get(row)
+    }
+
+    /// The value of the cell as it was on the row, a missing field reads as null.
+    pub fn value(&self, row: usize) -> Cow<'_, Value> {
+        if self.is_null(row) {
+            return Cow::Borrowed(&NULL);
+        }
+
+        match &self.data {
+            ColumnData::Int(values)     => Cow::Owned(Value::from(values[row])),
+            ColumnData::Float(values)   => Cow::Owned(Value::from(values[row])),
+            ColumnData::Bool(values)    => Cow::Owned(Value::from(values[row])),
+            ColumnData::Mixed(values)   => Cow::Borrowed(&values[row]),
+            ColumnData::Text(dictionary) | ColumnData::Date(dictionary, _) | ColumnData::Duration(dictionary, _) => {
+                Cow::Borrowed(&dictionary.entries[dictionary.codes[row] as usize])
+            }
+        }
+    }
+
+    pub fn int(&self, row: usize) -> Option<i64> {
+        match &self.data {
+            ColumnData::Int(values) => Some(values[row]),
+            _ => None
+        }
+    }
+
+    pub fn float(&self, row: usize) -> Option<f64> {
+        match &self.data {
+            ColumnData::Float(values) => Some(values[row]),
+            _ => None
+        }
+    }
+
+    /// The dictionary entry of a cell that is not null, booleans use 0 for false and 1 for true.
+    pub fn code(&self, row: usize) -> usize {
+        match &self.data {
+            ColumnData::Bool(values) => values[row] as usize,
+            ColumnData::Text(dictionary) | ColumnData::Date(dictionary, _) | ColumnData::Duration(dictionary, _) => {
+                dictionary.codes[row] as usize
+            }
+            _ => 0
+        }
+    }
+
+    pub fn dictionary(&self) -> Option<&Dictionary> {
+        match &self.data {
+            ColumnData::Text(dictionary) | ColumnData::Date(dictionary, _) | ColumnData::Duration(dictionary, _) => Some(dictionary),
+            _ => None
+        }
+    }
+}
+
+/// Strings are checked for durations and dates so they only have to be parsed once.
+fn text_data(dictionary: Dictionary) -> ColumnData {
+    let durations: Option<Vec<Duration>> = dictionary.texts().map(|text| Duration::parse(text).ok()).collect();
+    if let Some(durations) = durations {
+        return ColumnData::Duration(dictionary, durations);
+    }
+
+    let dates: Option<Vec<NaiveDateTime>> = dictionary.texts()
+        .map(|text| NaiveDateTime::parse_from_str(text, DATE_FORMAT).ok())
+        .collect();
+
+    match dates {
+        None => ColumnData::Text(dictionary),
+        Some(dates) => ColumnData::Date(dictionary, dates)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::columnar::column::{Column, ColumnData};
+    use crate::path::FieldPath;
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "A", "value": 1.5, "duration": "PT1H", "date": "2022/01/01 10:00:00", "mixed": 1}));
+        result.push(json!({"id": 1, "code": "B", "value": null, "duration": "PT2H", "date": "2022/01/02 10:00:00", "mixed": "a"}));
+        result.push(json!({"id": 2, "code": "A", "duration": null, "date": "2022/01/01 10:00:00", "mixed": [1]}));
+        result
+    }
+
+    fn build(field: &str) -> Column {
+        Column::build(&get_data(), &FieldPath::new(field))
+    }
+
+    #[test]
+    fn typed_column_test() {
+        assert!(matches!(build("id").data, ColumnData::Int(_)));
+        assert!(matches!(build("value").data, ColumnData::Float(_)));
+        assert!(matches!(build("code").data, ColumnData::Text(_)));
+        assert!(matches!(build("duration").data, ColumnData::Duration(_, _)));
+        assert!(matches!(build("date").data, ColumnData::Date(_, _)));
+        assert!(matches!(build("mixed").data, ColumnData::Mixed(_)));
+        assert!(matches!(build("nothing").data, ColumnData::Mixed(_)));
+    }
+
+    #[test]
+    fn dictionary_test() {
+        let column = build("code");
+        let dictionary = column.dictionary().unwrap();
+
+        assert_eq!(dictionary.entries, vec![json!("A"), json!("B")]);
+        assert_eq!(dictionary.codes, vec![0, 1, 0]);
+    }
+
+    #[test]
+    fn value_test() {
+        let data = get_data();
+
+        for field in ["id", "code", "value", "duration", "date", "mixed"] {
+            let column = build(field);
+
+            for (index, row) in data.iter().enumerate() {
+                assert_eq!(column.value(index).as_ref(), &row[field]);
+                assert_eq!(column.is_missing(index), row.get(field).is_none());
+            }
+        }
+    }
+}
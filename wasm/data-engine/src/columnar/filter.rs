@@ -0,0 +1,309 @@
+use std::rc::Rc;
+use chrono::NaiveDateTime;
+use serde_json::Value;
+use crate::columnar::{Column, ColumnData, ColumnTable};
+use crate::date;
+use crate::evaluators::{compile_field, compile_pair, compile_quantified, compile_typed, folds_case, get_evaluator, FilterContext};
+use crate::intent::{Comparison, FilterExpr, Operator};
+use crate::path::FieldPath;
+use crate::row::Row;
+
+type RowPredicate = Box<dyn Fn(usize) -> bool>;
+type Compare<T> = fn(&Option<T>, &Option<T>) -> bool;
+
+/// A filter intent compiled against the columns of a dataset.
+/// Operators are evaluated once per dictionary entry where possible, rows are then a lookup by index.
+pub struct ColumnFilter {
+    predicates: Vec<RowPredicate>
+}
+
+impl ColumnFilter {
+    pub fn compile<R: Row>(table: &ColumnTable, data: &[R], intent: &[FilterExpr], case_sensitive: bool) -> ColumnFilter {
+        ColumnFilter::compile_at(table, data, intent, case_sensitive, date::now())
+    }
+
+    /// Compile with the time the relative date operators work from, see FilterPlan::compile_at.
+    pub fn compile_at<R: Row>(table: &ColumnTable, data: &[R], intent: &[FilterExpr], case_sensitive: bool, now: NaiveDateTime) -> ColumnFilter {
+        let context = FilterContext::new(case_sensitive, now);
+
+        ColumnFilter {
+            predicates: compile_expressions(table, data, intent, &context)
+        }
+    }
+
+    pub fn matches(&self, row: usize) -> bool {
+        self.predicates.iter().all(|predicate| predicate(row))
+    }
+
+    /// Give back the indexes of the rows visible in the filter.
+    pub fn run(&self, length: usize) -> Vec<usize> {
+        (0..length).filter(|row| self.matches(*row)).collect()
+    }
+}
+
+/// Same as processors::filter using the columns of the dataset.
+pub fn filter<R: Row>(table: &ColumnTable, intent: &[FilterExpr], data: &[R], case_sensitive: bool) -> Vec<usize> {
+    ColumnFilter::compile(table, data, intent, case_sensitive).run(data.len())
+}
+
+fn compile_expressions<R: Row>(table: &ColumnTable, data: &[R], expressions: &[FilterExpr], context: &FilterContext) -> Vec<RowPredicate> {
+    expressions.iter().map(|expression| compile_expression(table, data, expression, context)).collect()
+}
+
+fn compile_expression<R: Row>(table: &ColumnTable, data: &[R], expression: &FilterExpr, context: &FilterContext) -> RowPredicate {
+    match expression {
+        FilterExpr::Comparison(comparison) => compile_comparison(table, data, comparison, context),
+        FilterExpr::And(expressions) => {
+            let predicates = compile_expressions(table, data, expressions, context);
+            Box::new(move |row| predicates.iter().all(|predicate| predicate(row)))
+        }
+        FilterExpr::Or(expressions) => {
+            let predicates = compile_expressions(table, data, expressions, context);
+            Box::new(move |row| predicates.iter().any(|predicate| predicate(row)))
+        }
+        FilterExpr::Not(expressions) => {
+            let predicates = compile_expressions(table, data, expressions, context);
+            Box::new(move |row| !predicates.iter().all(|predicate| predicate(row)))
+        }
+        FilterExpr::Quantified(quantified) => {
+            let column = table.column(data, &FieldPath::new(&quantified.field));
+            let predicate = compile_quantified(quantified, context);
+            Box::new(move |row| predicate(&column.value(row)))
+        }
+    }
+}
+
+fn compile_comparison<R: Row>(table: &ColumnTable, data: &[R], comparison: &Comparison, context: &FilterContext) -> RowPredicate {
+    let path = FieldPath::new(&comparison.field);
+    let column = table.column(data, &path);
+
+    if let Some(value_field) = &comparison.value_field {
+        let other = table.column(data, &FieldPath::new(value_field));
+        let predicate = compile_pair(comparison, &path, context);
+        return Box::new(move |row| predicate(&column.value(row), &other.value(row)));
+    }
+
+    // the cells of a projection are arrays, they are tested value by value
+    if path.is_projection() {
+        let predicate = compile_field(comparison, &path, context);
+        return Box::new(move |row| predicate(&column.value(row)));
+    }
+
+    if let Some(predicate) = compile_typed(comparison, context) {
+        let null_result = predicate(&Value::Null);
+
+        return match column.dictionary().map(|dictionary| evaluate_entries(&dictionary.entries, &predicate)) {
+            None => Box::new(move |row| predicate(&column.value(row))),
+            Some(results) => lookup(column, results, null_result)
+        };
+    }
+
+    if folds_case(comparison, context) {
+        let predicate = compile_field(comparison, &path, context);
+
+        // cells that are not strings are compared as an empty string
+        let other = predicate(&Value::Null);
+
+        if let ColumnData::Mixed(_) = column.data {
+            return Box::new(move |row| predicate(&column.value(row)));
+        }
+
+        return match column.dictionary().map(|dictionary| evaluate_entries(&dictionary.entries, &predicate)) {
+            None => Box::new(move |_| other),
+            Some(results) => lookup(column, results, other)
+        };
+    }
+
+    let evaluate = get_evaluator(comparison.operator);
+
+    let value = match comparison.operator {
+        Operator::IsNull | Operator::NotNull | Operator::IsEmpty => Value::Null,
+        _ => comparison.value.clone()
+    };
+
+    let null_result = evaluate(&Value::Null, &value);
+
+    if let Some(results) = column.dictionary().map(|dictionary| evaluate_entries(&dictionary.entries, |entry| evaluate(entry, &value))) {
+        return lookup(column, results, null_result);
+    }
+
+    match column.data {
+        ColumnData::Bool(_) => {
+            let results = vec![evaluate(&Value::from(false), &value), evaluate(&Value::from(true), &value)];
+            return lookup(column, results, null_result);
+        }
+        ColumnData::Int(_) => {
+            if let Some(compare) = typed::<i64>(comparison.operator) {
+                let other = value.as_i64();
+                return Box::new(move |row| match column.is_null(row) {
+                    true => null_result,
+                    false => compare(&column.int(row), &other)
+                });
+            }
+        }
+        ColumnData::Float(_) => {
+            if let Some(compare) = typed::<f64>(comparison.operator) {
+                let other = value.as_f64();
+                return Box::new(move |row| match column.is_null(row) {
+                    true => null_result,
+                    false => compare(&column.float(row), &other)
+                });
+            }
+        }
+        _ => {}
+    }
+
+    Box::new(move |row| evaluate(&column.value(row), &value))
+}
+
+fn evaluate_entries(entries: &[Value], evaluate: impl Fn(&Value) -> bool) -> Vec<bool> {
+    entries.iter().map(evaluate).collect()
+}
+
+/// The result of a cell is the result of its dictionary entry.
+fn lookup(column: Rc<Column>, results: Vec<bool>, null_result: bool) -> RowPredicate {
+    Box::new(move |row| match column.is_null(row) {
+        true => null_result,
+        false => results[column.code(row)]
+    })
+}
+
+/// The comparison operators on numbers without going through json values.
+/// These compare the same way as the evaluators, a missing value on the intent is None.
+fn typed<T: PartialOrd>(operator: Operator) -> Option<Compare<T>> {
+    let result: Compare<T> = match operator {
+        Operator::GreaterThan       => |a, b| a > b,
+        Operator::GreaterOrEqual    => |a, b| a >= b,
+        Operator::LessThan          => |a, b| a < b,
+        Operator::LessOrEqual       => |a, b| a <= b,
+        Operator::Equal             => |a, b| a == b,
+        Operator::NotEqual          => |a, b| a != b,
+        _                           => return None
+    };
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::columnar::{filter, ColumnTable};
+    use crate::intent;
+    use crate::intent::FilterExpr;
+    use crate::processors;
+    use random_data::generate_data;
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "A", "value": 10, "price": 1.5, "isActive": true, "person": {"name": "John"}}));
+        result.push(json!({"id": 1, "code": "b", "value": 10, "price": null, "isActive": false, "person": {"name": "John"}}));
+        result.push(json!({"id": 2, "code": "C", "value": null, "price": 2.5, "isActive": true, "person": {"name": "Jane"}}));
+        result.push(json!({"id": 3, "code": null, "value": 20, "isActive": null, "person": null}));
+        result.push(json!({"id": 4, "code": 4, "value": 5, "price": 0.5, "isActive": false}));
+        result
+    }
+
+    fn assert_same(intent: Value, data: &[Value]) {
+        let intent: Vec<FilterExpr> = intent::parse(&intent, "filter").unwrap();
+        let table = ColumnTable::default();
+
+        for case_sensitive in [true, false] {
+            let expected = processors::filter(&intent, data, case_sensitive);
+            assert_eq!(filter(&table, &intent, data, case_sensitive), expected);
+        }
+    }
+
+    #[test]
+    fn filter_test() {
+        let data = get_data();
+
+        for operator in ["==", "!=", ">", ">=", "<", "<="] {
+            assert_same(json!([{ "field": "value", "operator": operator, "value": 10 }]), &data);
+            assert_same(json!([{ "field": "price", "operator": operator, "value": 1.5 }]), &data);
+            assert_same(json!([{ "field": "code", "operator": operator, "value": "B" }]), &data);
+            assert_same(json!([{ "field": "isActive", "operator": operator, "value": true }]), &data);
+        }
+
+        assert_same(json!([{ "field": "code", "operator": "is_null" }]), &data);
+        assert_same(json!([{ "field": "price", "operator": "not_null" }]), &data);
+        assert_same(json!([{ "field": "person.name", "operator": "like", "value": "J" }]), &data);
+        assert_same(json!([{ "field": "value", "operator": "between", "value": [5, 10] }]), &data);
+        assert_same(json!([{ "field": "code", "operator": "in", "value": ["A", "C", 4] }]), &data);
+        assert_same(json!([
+            { "operator": "or", "expressions": [
+                { "field": "code", "operator": "==", "value": "a" },
+                { "operator": "not", "expressions": [{ "field": "value", "operator": "<", "value": 10 }] }
+            ]}
+        ]), &data);
+    }
+
+    #[test]
+    fn array_test() {
+        let data = vec![
+            json!({"tags": ["Red", "green"], "lines": [{"qty": 1}, {"qty": 5}]}),
+            json!({"tags": ["blue"], "lines": [{"qty": 6}]}),
+            json!({"tags": [], "lines": []}),
+            json!({"tags": "red", "lines": null}),
+            json!({})
+        ];
+
+        assert_same(json!([{ "field": "tags", "operator": "contains", "value": "red" }]), &data);
+        assert_same(json!([{ "field": "tags", "operator": "contains_any", "value": ["red", "blue"] }]), &data);
+        assert_same(json!([{ "field": "tags", "operator": "contains_all", "value": ["red", "GREEN"] }]), &data);
+        assert_same(json!([{ "field": "tags", "operator": "is_empty" }]), &data);
+        assert_same(json!([{ "field": "tags", "operator": ">=", "value": 1, "type": "length" }]), &data);
+        assert_same(json!([{ "field": "lines", "operator": "any", "expressions": [{ "field": "qty", "operator": ">", "value": 4 }] }]), &data);
+        assert_same(json!([{ "field": "lines", "operator": "all", "expressions": [{ "field": "qty", "operator": ">", "value": 4 }] }]), &data);
+        assert_same(json!([{ "field": "tags", "operator": "none", "expressions": [{ "field": "", "operator": "==", "value": "blue" }] }]), &data);
+    }
+
+    #[test]
+    fn field_reference_test() {
+        let data = vec![
+            json!({"actual": 12, "planned": 10.5, "code": "A", "other": "a", "end": "2024/03/01", "due": "2024/02/01"}),
+            json!({"actual": 8, "planned": 10, "code": "B", "other": "B", "end": "2024/01/01", "due": "2024/02/01"}),
+            json!({"actual": null, "planned": 1, "code": null, "other": null, "end": null, "due": "2024/02/01"}),
+            json!({"actual": 3, "planned": 3, "code": 4, "other": 4})
+        ];
+
+        assert_same(json!([{ "field": "actual", "operator": ">", "value_field": "planned" }]), &data);
+        assert_same(json!([{ "field": "actual", "operator": "==", "value": { "$field": "planned" } }]), &data);
+        assert_same(json!([{ "field": "code", "operator": "==", "value_field": "other" }]), &data);
+        assert_same(json!([{ "field": "end", "operator": "before", "value_field": "due" }]), &data);
+        assert_same(json!([{ "field": "end", "operator": "!=", "value_field": "due", "type": "date" }]), &data);
+    }
+
+    #[test]
+    fn nested_path_test() {
+        let data = vec![
+            json!({"lines": [{"qty": 1, "code": "a"}, {"qty": 5, "code": "B"}], "size.cm": 10}),
+            json!({"lines": [{"qty": 6, "code": "b"}], "size.cm": 20}),
+            json!({"lines": []}),
+            json!({"lines": null, "size.cm": null})
+        ];
+
+        assert_same(json!([{ "field": "lines[0].qty", "operator": ">", "value": 2 }]), &data);
+        assert_same(json!([{ "field": "lines[1].code", "operator": "==", "value": "b" }]), &data);
+        assert_same(json!([{ "field": "lines[*].qty", "operator": ">", "value": 4 }]), &data);
+        assert_same(json!([{ "field": "lines[*].qty", "operator": ">", "value": 4, "quantifier": "all" }]), &data);
+        assert_same(json!([{ "field": "lines[*].code", "operator": "==", "value": "b", "quantifier": "all" }]), &data);
+        assert_same(json!([{ "field": "size\\.cm", "operator": "between", "value": [15, 25] }]), &data);
+    }
+
+    #[test]
+    fn random_data_test() {
+        let data = generate_data(1000);
+
+        assert_same(json!([{ "field": "number", "operator": ">", "value": 5 }]), &data);
+        assert_same(json!([{ "field": "duration", "operator": "==", "value": "PT4H30M11S" }]), &data);
+        assert_same(json!([{ "field": "date", "operator": "startswith", "value": "2000" }]), &data);
+        assert_same(json!([{ "field": "code", "operator": "like", "value": "a" }]), &data);
+        assert_same(json!([{ "field": "code", "operator": "like", "value": "a%_" }]), &data);
+        assert_same(json!([{ "field": "code", "operator": "matches", "value": "^[a-m]\\d", "flags": "i" }]), &data);
+        assert_same(json!([{ "field": "date", "operator": "after", "value": "2010-01-01" }]), &data);
+        assert_same(json!([{ "field": "date", "operator": "<=", "value": "2000/06/15", "type": "date" }]), &data);
+        assert_same(json!([{ "field": "date", "operator": "same_month", "value": "2005/06/01" }]), &data);
+        assert_same(json!([{ "field": "duration", "operator": ">", "value": "PT4H", "type": "duration" }]), &data);
+        assert_same(json!([{ "field": "duration", "operator": "between", "value": ["PT1H", "P1D"], "type": "duration" }]), &data);
+    }
+}
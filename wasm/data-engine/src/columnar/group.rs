@@ -0,0 +1,161 @@
+use std::rc::Rc;
+use std::slice;
+use serde_json::Value;
+use crate::columnar::{aggregate_rows, Column, ColumnData, ColumnTable};
+use crate::columnar::aggregate::add_rows;
+use crate::error::DataResult;
+use crate::path::FieldPath;
+use crate::intent::{AggregateSpec, GroupSpec};
+use crate::processors::{group_key, group_result, GroupField, NULL_KEY};
+use crate::utils::flood_indexes;
+use crate::row::Row;
+
+/// The group keys of a field.
+/// Dictionary columns make the key once per entry, other columns once per row.
+enum GroupKeys {
+    Entries(Rc<Column>, Vec<String>),
+    Rows(Vec<String>)
+}
+
+impl GroupKeys {
+    fn new(column: Rc<Column>, spec: &GroupSpec, length: usize) -> GroupKeys {
+        let key = |value: Value| group_key(&spec.key_value(value));
+
+        let entries = match &column.data {
+            ColumnData::Bool(_) => Some(vec![key(Value::Bool(false)), key(Value::Bool(true))]),
+            _ => column.dictionary().map(|dictionary| dictionary.entries.iter().cloned().map(key).collect())
+        };
+
+        match entries {
+            Some(entries) => GroupKeys::Entries(column, entries),
+            None => GroupKeys::Rows((0..length).map(|row| key(column.value(row).into_owned())).collect())
+        }
+    }
+
+    fn get(&self, row: usize) -> &str {
+        match self {
+            GroupKeys::Rows(keys) => &keys[row],
+            GroupKeys::Entries(column, entries) => match column.is_null(row) {
+                true => NULL_KEY,
+                false => &entries[column.code(row)]
+            }
+        }
+    }
+}
+
+/// Same as processors::group using the columns of the dataset.
+pub fn group<R: Row>(table: &ColumnTable, intent: &[GroupSpec], data: &[R], rows: Option<Vec<usize>>, aggregates: Option<&[AggregateSpec]>) -> DataResult<Value> {
+    let columns: Vec<Rc<Column>> = intent.iter().map(|spec| table.column(data, &FieldPath::new(&spec.field))).collect();
+
+    let keys: Vec<GroupKeys> = columns.iter().zip(intent)
+        .map(|(column, spec)| GroupKeys::new(column.clone(), spec, data.len()))
+        .collect();
+
+    let rows = match rows {
+        Some(array) => array,
+        None => flood_indexes(data)
+    };
+
+    let mut root = GroupField::root();
+
+    for row in rows {
+        let row_keys: Vec<&str> = keys.iter().map(|key| key.get(row)).collect();
+        root.process_row(&row_keys, intent, 0, row, &|level| intent[level].key_value(columns[level].value(row).into_owned()));
+    }
+
+    root.calculate_count();
+    root.order(intent, &|spec, rows| aggregate_rows(table, slice::from_ref(spec), data, Some(rows)))?;
+
+    if let Some(aggregates) = aggregates {
+        root.aggregate(aggregates, &|aggregator, rows| add_rows(table, aggregator, aggregates, data, rows))?;
+    }
+
+    Ok(group_result(root))
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::columnar::{group, ColumnTable};
+    use crate::intent;
+    use crate::intent::{Aggregates, GroupSpec};
+    use crate::processors;
+    use random_data::generate_data;
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "A", "value": 10, "price": 1.5, "isActive": true}));
+        result.push(json!({"id": 1, "code": "B", "value": 10, "price": null, "isActive": false}));
+        result.push(json!({"id": 2, "code": null, "value": null, "price": 2.5, "isActive": true}));
+        result.push(json!({"id": 3, "code": "A", "value": 20, "price": 1.5, "isActive": null}));
+        result.push(json!({"id": 4, "code": 4, "value": 5, "isActive": false}));
+        result
+    }
+
+    fn assert_same(fields: &[&str], aggregates: Option<Value>, data: &[Value]) {
+        let intent: Vec<GroupSpec> = fields.iter().map(|field| GroupSpec::from(*field)).collect();
+        let aggregates: Option<Aggregates> = aggregates.map(|aggregates| intent::parse(&aggregates, "aggregates").unwrap());
+        let aggregates = aggregates.as_ref().map(|aggregates| aggregates.0.as_slice());
+        let table = ColumnTable::default();
+
+        let expected = processors::group(&intent, data, None, aggregates).unwrap();
+        assert_eq!(group(&table, &intent, data, None, aggregates).unwrap(), expected);
+
+        let rows = vec![4, 2, 0];
+        let expected = processors::group(&intent, data, Some(rows.clone()), aggregates).unwrap();
+        assert_eq!(group(&table, &intent, data, Some(rows), aggregates).unwrap(), expected);
+    }
+
+    #[test]
+    fn group_test() {
+        let data = get_data();
+
+        assert_same(&["code"], None, &data);
+        assert_same(&["isActive", "value"], None, &data);
+        assert_same(&["price", "nothing"], Some(json!({ "sum": "value", "min": "price" })), &data);
+    }
+
+    #[test]
+    fn ordered_group_test() {
+        let data = get_data();
+        let table = ColumnTable::default();
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([
+            { "field": "code", "order_by": "count", "direction": "desc" },
+            { "field": "value", "order_by": { "sum": "price" } }
+        ]), "group").unwrap();
+
+        let expected = processors::group(&intent, &data, None, None).unwrap();
+        assert_eq!(group(&table, &intent, &data, None, None).unwrap(), expected);
+    }
+
+    #[test]
+    fn typed_key_test() {
+        let mut data = get_data();
+        data.push(json!({"id": 5, "code": "a", "value": "10", "isActive": "true"}));
+        let table = ColumnTable::default();
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([
+            { "field": "code", "case_insensitive": true, "null_label": "none" },
+            "value",
+            "isActive"
+        ]), "group").unwrap();
+
+        let expected = processors::group(&intent, &data, None, None).unwrap();
+        assert_eq!(group(&table, &intent, &data, None, None).unwrap(), expected);
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([
+            { "field": "value", "bucket": { "edges": [10] } },
+            { "field": "price", "bucket": { "width": 1 } }
+        ]), "group").unwrap();
+
+        let expected = processors::group(&intent, &data, None, None).unwrap();
+        assert_eq!(group(&table, &intent, &data, None, None).unwrap(), expected);
+    }
+
+    #[test]
+    fn random_data_test() {
+        let data = generate_data(1000);
+        assert_same(&["externalCode", "number"], Some(json!({ "ave": "number", "max:duration": "duration" })), &data);
+    }
+}
@@ -0,0 +1,273 @@
+use std::cmp::Ordering;
+use std::rc::Rc;
+use iso8601_duration::Duration;
+use crate::columnar::{Column, ColumnData, ColumnTable};
+use crate::collation::Collation;
+use crate::compare::{compare_sort_values, compare_values_with};
+use crate::duration::{duration_ordering, parse_duration};
+use crate::enums::NullOrder;
+use crate::enums::SortDirection::Descending;
+use crate::path::FieldPath;
+use crate::intent::{Page, SortField, SortType};
+use crate::processors::compare_field;
+use crate::utils::{flood_indexes, select_page};
+use crate::row::Row;
+
+/// A sort field read from its column.
+/// Values are replaced by their rank so rows compare as integers, null has no rank.
+struct SortKey {
+    column: Rc<Column>,
+    ranks: Option<Vec<Option<u32>>>,
+    durations: Option<Vec<Option<Duration>>>,
+    descending: bool,
+    nulls: NullOrder,
+    collation: Collation,
+
+    /// Fields with a type other than duration compare the values read as the type.
+    typed: Option<SortField>
+}
+
+impl SortKey {
+    fn new(column: Rc<Column>, field: &SortField, length: usize) -> SortKey {
+        let collation = field.collation.unwrap_or_default();
+        let ranks = get_ranks(&column, &collation);
+
+        let durations = match field.data_type {
+            Some(SortType::Duration) => Some(get_durations(&column, length)),
+            _ => None
+        };
+
+        SortKey {
+            column,
+            ranks,
+            durations,
+            descending: field.direction == Descending,
+            nulls: field.nulls.unwrap_or_default(),
+            collation,
+            typed: match field.data_type {
+                None | Some(SortType::Duration) => None,
+                Some(_) => Some(field.clone())
+            }
+        }
+    }
+
+    /// The same order as processors::sort gives the values of the two rows.
+    fn compare(&self, row1: usize, row2: usize) -> Ordering {
+        if let Some(field) = &self.typed {
+            return compare_field(field, &self.column.value(row1), &self.column.value(row2));
+        }
+
+        if let Some(durations) = &self.durations {
+            return compare_sort_values(durations[row1].as_ref(), durations[row2].as_ref(), self.descending, self.nulls, |a, b| duration_ordering(a, b));
+        }
+
+        match &self.ranks {
+            Some(ranks) => compare_sort_values(ranks[row1], ranks[row2], self.descending, self.nulls, |a, b| a.cmp(b)),
+            None => {
+                let value1 = (!self.column.is_null(row1)).then(|| self.column.value(row1));
+                let value2 = (!self.column.is_null(row2)).then(|| self.column.value(row2));
+                compare_sort_values(value1, value2, self.descending, self.nulls, |a, b| compare_values_with(a, b, &self.collation))
+            }
+        }
+    }
+}
+
+/// Same as processors::sort using the columns of the dataset.
+pub fn sort<R: Row>(table: &ColumnTable, intent: &[SortField], data: &[R], rows: Option<Vec<usize>>) -> Vec<usize> {
+    sort_page(table, intent, data, rows, &Page::default())
+}
+
+/// Same as processors::sort_page using the columns of the dataset.
+pub fn sort_page<R: Row>(table: &ColumnTable, intent: &[SortField], data: &[R], rows: Option<Vec<usize>>, page: &Page) -> Vec<usize> {
+    let keys: Vec<SortKey> = intent.iter()
+        .map(|field| SortKey::new(table.column(data, &FieldPath::new(&field.name)), field, data.len()))
+        .collect();
+
+    let rows = match rows {
+        Some(array) => array,
+        None => flood_indexes(data)
+    };
+
+    select_page(rows, page, |a, b| compare_rows(&keys, *a, *b).then(a.cmp(b)))
+}
+
+/// The order on the first sort key that differs.
+fn compare_rows(keys: &[SortKey], row1: usize, row2: usize) -> Ordering {
+    for key in keys {
+        let ordering = key.compare(row1, row2);
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Columns of one type get a rank per row, mixed columns compare their values.
+/// Strings the collation sees as equal get the same rank.
+fn get_ranks(column: &Column, collation: &Collation) -> Option<Vec<Option<u32>>> {
+    let ranks: Vec<u32> = match &column.data {
+        ColumnData::Int(values) => {
+            let mut sorted = values.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            values.iter().map(|value| sorted.binary_search(value).unwrap_or(0) as u32).collect()
+        }
+        ColumnData::Float(values) => {
+            let mut sorted = values.clone();
+            sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            sorted.dedup();
+            values.iter().map(|value| sorted.partition_point(|item| item < value) as u32).collect()
+        }
+        ColumnData::Bool(values) => values.iter().map(|value| *value as u32).collect(),
+        ColumnData::Text(dictionary) | ColumnData::Date(dictionary, _) | ColumnData::Duration(dictionary, _) => {
+            let text = |index: usize| dictionary.entries[index].as_str().unwrap_or_default();
+            let mut order: Vec<usize> = (0..dictionary.entries.len()).collect();
+            order.sort_unstable_by(|a, b| collation.compare(text(*a), text(*b)));
+
+            let mut entry_ranks = vec![0; order.len()];
+            let mut rank = 0;
+            for (position, index) in order.iter().enumerate() {
+                if position > 0 && collation.compare(text(order[position - 1]), text(*index)) != Ordering::Equal {
+                    rank += 1;
+                }
+
+                entry_ranks[*index] = rank;
+            }
+
+            dictionary.codes.iter().map(|code| entry_ranks[*code as usize]).collect()
+        }
+        ColumnData::Mixed(_) => return None
+    };
+
+    let result = ranks.into_iter()
+        .enumerate()
+        .map(|(row, rank)| match column.is_null(row) {
+            true => None,
+            false => Some(rank)
+        })
+        .collect();
+
+    Some(result)
+}
+
+/// The durations of the rows for a duration sort, null values have no duration and invalid values are a zero duration.
+fn get_durations(column: &Column, length: usize) -> Vec<Option<Duration>> {
+    let parsed = match &column.data {
+        ColumnData::Duration(_, durations) => Some(durations),
+        _ => None
+    };
+
+    (0..length)
+        .map(|row| match parsed {
+            _ if column.is_null(row) => None,
+            Some(durations) => {
+                let duration = &durations[column.code(row)];
+                Some(Duration::new(duration.year, duration.month, duration.day, duration.hour, duration.minute, duration.second))
+            }
+            None => Some(parse_duration(&column.value(row)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::columnar::{sort, sort_page, ColumnTable};
+    use crate::intent;
+    use crate::intent::{Page, SortField};
+    use crate::processors;
+    use random_data::generate_data;
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "B", "value": 10, "price": 1.5, "isActive": true, "duration": "PT1H"}));
+        result.push(json!({"id": 1, "code": "A", "value": 10, "price": null, "isActive": false, "duration": "PT2H"}));
+        result.push(json!({"id": 2, "code": null, "value": null, "price": 2.5, "isActive": true}));
+        result.push(json!({"id": 3, "code": "C", "value": 20, "price": 1.5, "isActive": null, "duration": "P1D"}));
+        result.push(json!({"id": 4, "code": 4, "value": 5, "price": 0.5, "isActive": false, "duration": null}));
+        result.push(json!({"id": 5, "code": "A", "value": 5, "isActive": true, "duration": "PT1H"}));
+        result
+    }
+
+    fn assert_same(intent: Value, data: &[Value]) {
+        let intent: Vec<SortField> = intent::parse(&intent, "sort").unwrap();
+        let table = ColumnTable::default();
+
+        assert_eq!(sort(&table, &intent, data, None), processors::sort(&intent, data, None));
+
+        let rows: Vec<usize> = (0..data.len()).rev().step_by(2).collect();
+        assert_eq!(sort(&table, &intent, data, Some(rows.clone())), processors::sort(&intent, data, Some(rows)));
+
+        let page = Page::new(1, Some(3));
+        assert_eq!(sort_page(&table, &intent, data, None, &page), processors::sort_page(&intent, data, None, &page));
+    }
+
+    #[test]
+    fn sort_test() {
+        let data = get_data();
+
+        for field in ["id", "code", "value", "price", "isActive", "duration", "nothing"] {
+            assert_same(json!([{ "name": field }]), &data);
+            assert_same(json!([{ "name": field, "direction": "desc" }]), &data);
+            assert_same(json!([{ "name": field, "nulls": "first" }]), &data);
+            assert_same(json!([{ "name": field, "direction": "desc", "nulls": "first" }]), &data);
+        }
+
+        assert_same(json!([{ "name": "duration", "type": "duration" }]), &data);
+        assert_same(json!([{ "name": "duration", "type": "duration", "nulls": "first" }]), &data);
+        assert_same(json!([{ "name": "code", "type": "enum", "order": ["C", "A", 4] }, { "name": "id", "direction": "desc" }]), &data);
+        assert_same(json!([{ "name": "isActive", "type": "boolean", "direction": "desc" }, { "name": "price", "type": "number" }]), &data);
+        assert_same(json!([{ "name": "code", "type": "duration", "direction": "desc" }]), &data);
+        assert_same(json!([{ "name": "value", "direction": "desc" }, { "name": "code" }, { "name": "price" }]), &data);
+    }
+
+    #[test]
+    fn collation_test() {
+        let data = vec![
+            json!({"code": "item10", "mixed": "b"}),
+            json!({"code": "Item2", "mixed": 1}),
+            json!({"code": "item2", "mixed": "B"}),
+            json!({"code": "ítem1", "mixed": "a"}),
+            json!({"code": null, "mixed": null})
+        ];
+
+        let collation = json!({ "case_insensitive": true, "natural": true, "ignore_accents": true });
+
+        for field in ["code", "mixed"] {
+            assert_same(json!([{ "name": field, "collation": collation }]), &data);
+            assert_same(json!([{ "name": field, "direction": "desc", "collation": collation }]), &data);
+            assert_same(json!([{ "name": field, "collation": { "case_insensitive": true } }]), &data);
+        }
+
+        let intent: Vec<SortField> = intent::parse(&json!([{ "name": "code", "collation": collation }]), "sort").unwrap();
+        assert_eq!(sort(&ColumnTable::default(), &intent, &data, None), vec![3, 1, 2, 0, 4]);
+    }
+
+    #[test]
+    fn nested_path_test() {
+        let data = vec![
+            json!({"order": {"code": "B"}, "lines": [{"qty": 2}]}),
+            json!({"order": {"code": "A"}, "lines": [{"qty": 1}, {"qty": 3}]}),
+            json!({"order": null, "lines": []}),
+            json!({"order": {"code": "C"}, "lines": [{"qty": 1}]})
+        ];
+
+        assert_same(json!([{ "name": "order.code" }]), &data);
+        assert_same(json!([{ "name": "lines[0].qty", "direction": "desc" }, { "name": "order.code" }]), &data);
+
+        let intent: Vec<SortField> = intent::parse(&json!([{ "name": "order.code", "direction": "desc" }]), "sort").unwrap();
+        assert_eq!(sort(&ColumnTable::default(), &intent, &data, None), vec![3, 0, 1, 2]);
+    }
+
+    #[test]
+    fn random_data_test() {
+        let data = generate_data(1000);
+
+        assert_same(json!([{ "name": "number" }, { "name": "code", "direction": "desc" }]), &data);
+        assert_same(json!([{ "name": "externalCode" }, { "name": "date" }]), &data);
+        assert_same(json!([{ "name": "date", "type": "date", "direction": "desc" }, { "name": "id" }]), &data);
+        assert_same(json!([{ "name": "duration", "type": "duration" }, { "name": "id" }]), &data);
+    }
+}
@@ -0,0 +1,156 @@
+use std::cmp::Ordering;
+use serde_json::{Number, Value};
+use crate::collation::Collation;
+use crate::enums::NullOrder;
+
+/// The order of two json values, a total order over all the json types.
+/// Values of different types order by type: null, booleans, numbers, strings, arrays and then objects.
+/// Numbers compare on their value whether they are integers or floats.
+pub fn compare_values(value1: &Value, value2: &Value) -> Ordering {
+    compare_values_with(value1, value2, &Collation::default())
+}
+
+/// Same as compare_values with the strings compared by the collation.
+pub fn compare_values_with(value1: &Value, value2: &Value, collation: &Collation) -> Ordering {
+    match (value1, value2) {
+        (Value::Bool(value1), Value::Bool(value2)) => value1.cmp(value2),
+        (Value::Number(number1), Value::Number(number2)) => compare_numbers(number1, number2),
+        (Value::String(value1), Value::String(value2)) => collation.compare(value1, value2),
+        (Value::Array(items1), Value::Array(items2)) => {
+            for (item1, item2) in items1.iter().zip(items2) {
+                let ordering = compare_values_with(item1, item2, collation);
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+
+            items1.len().cmp(&items2.len())
+        }
+        (Value::Object(_), Value::Object(_)) => value1.to_string().cmp(&value2.to_string()),
+        _ => type_rank(value1).cmp(&type_rank(value2))
+    }
+}
+
+/// Integers and floats compare exactly, an integer is not rounded to a float
+/// so large integers keep their order with the floats around them.
+fn compare_numbers(number1: &Number, number2: &Number) -> Ordering {
+    match (integer(number1), integer(number2)) {
+        (Some(value1), Some(value2)) => value1.cmp(&value2),
+        (Some(value1), None) => compare_integer_float(value1, number2.as_f64().unwrap_or_default()),
+        (None, Some(value2)) => compare_integer_float(value2, number1.as_f64().unwrap_or_default()).reverse(),
+        (None, None) => {
+            let value1 = number1.as_f64().unwrap_or_default();
+            let value2 = number2.as_f64().unwrap_or_default();
+            value1.partial_cmp(&value2).unwrap_or(Ordering::Equal)
+        }
+    }
+}
+
+fn integer(number: &Number) -> Option<i128> {
+    number.as_i64().map(i128::from).or_else(|| number.as_u64().map(i128::from))
+}
+
+/// The whole part of the float is exact as an i128 within its range, the fraction breaks a tie.
+fn compare_integer_float(value: i128, float: f64) -> Ordering {
+    if float >= i128::MAX as f64 {
+        return Ordering::Less;
+    }
+
+    if float < i128::MIN as f64 {
+        return Ordering::Greater;
+    }
+
+    let whole = float.trunc();
+
+    value.cmp(&(whole as i128)).then_with(|| whole.partial_cmp(&float).unwrap_or(Ordering::Equal))
+}
+
+/// The order of two sort values where None is a null or missing value.
+/// Nulls go where the null order puts them, the other values are compared and reversed when descending.
+pub fn compare_sort_values<T>(value1: Option<T>, value2: Option<T>, descending: bool, nulls: NullOrder, compare: impl Fn(&T, &T) -> Ordering) -> Ordering {
+    let null_first = match nulls {
+        NullOrder::First => Ordering::Less,
+        NullOrder::Last => Ordering::Greater
+    };
+
+    match (value1, value2) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => null_first,
+        (Some(_), None) => null_first.reverse(),
+        (Some(value1), Some(value2)) => match descending {
+            true => compare(&value1, &value2).reverse(),
+            false => compare(&value1, &value2)
+        }
+    }
+}
+
+fn type_rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+    use serde_json::{json, Value};
+    use crate::compare::{compare_sort_values, compare_values};
+    use crate::enums::NullOrder;
+
+    #[test]
+    fn compare_values_test() {
+        assert_eq!(compare_values(&json!(1), &json!(1.5)), Ordering::Less);
+        assert_eq!(compare_values(&json!(2.0), &json!(2)), Ordering::Equal);
+        assert_eq!(compare_values(&json!("B"), &json!("A")), Ordering::Greater);
+        assert_eq!(compare_values(&json!([1, 2]), &json!([1, 2, 0])), Ordering::Less);
+        assert_eq!(compare_values(&json!({"a": 1}), &json!({"a": 1})), Ordering::Equal);
+    }
+
+    #[test]
+    fn large_numbers_test() {
+        let big = 9007199254740993_i64;
+        let float = 9007199254740992.0_f64;
+
+        assert_eq!(compare_values(&json!(big), &json!(float)), Ordering::Greater);
+        assert_eq!(compare_values(&json!(float), &json!(big)), Ordering::Less);
+        assert_eq!(compare_values(&json!(big - 1), &json!(float)), Ordering::Equal);
+        assert_eq!(compare_values(&json!(-big), &json!(-float)), Ordering::Less);
+        assert_eq!(compare_values(&json!(3), &json!(2.5)), Ordering::Greater);
+        assert_eq!(compare_values(&json!(-3), &json!(-2.5)), Ordering::Less);
+        assert_eq!(compare_values(&json!(u64::MAX), &json!(i64::MAX)), Ordering::Greater);
+        assert_eq!(compare_values(&json!(u64::MAX), &json!(1e300)), Ordering::Less);
+
+        // the floats round 2^53 + 1 down, sorting must still give a consistent order
+        let mut values = vec![json!(big), json!(float), json!(big - 1), json!(big + 1), json!(9007199254740994.0)];
+        values.sort_by(compare_values);
+        assert_eq!(values, vec![json!(float), json!(big - 1), json!(big), json!(big + 1), json!(9007199254740994.0)]);
+    }
+
+    #[test]
+    fn mixed_types_test() {
+        let mut values = vec![json!({"a": 1}), json!("A"), json!([1]), json!(10), Value::Null, json!(true), json!(1.5)];
+        values.sort_by(compare_values);
+
+        assert_eq!(values, vec![Value::Null, json!(true), json!(1.5), json!(10), json!("A"), json!([1]), json!({"a": 1})]);
+    }
+
+    #[test]
+    fn nulls_test() {
+        let compare = |value1: Option<i32>, value2: Option<i32>, descending: bool, nulls: NullOrder| {
+            compare_sort_values(value1, value2, descending, nulls, |a, b| a.cmp(b))
+        };
+
+        assert_eq!(compare(None, Some(1), false, NullOrder::Last), Ordering::Greater);
+        assert_eq!(compare(None, Some(1), true, NullOrder::Last), Ordering::Greater);
+        assert_eq!(compare(None, Some(1), true, NullOrder::First), Ordering::Less);
+        assert_eq!(compare(Some(1), None, false, NullOrder::First), Ordering::Greater);
+        assert_eq!(compare(None, None, false, NullOrder::First), Ordering::Equal);
+        assert_eq!(compare(Some(1), Some(2), true, NullOrder::First), Ordering::Greater);
+    }
+}
@@ -0,0 +1,139 @@
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeDelta};
+use chrono::format::{Item, StrftimeItems};
+use iso8601_duration::Duration;
+use serde_json::Value;
+use crate::aggregates::DATE_FORMAT;
+use crate::duration::duration_to_seconds;
+
+const DATE_TIME_FORMATS: [&str; 3] = [DATE_FORMAT, "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S"];
+const DATE_FORMATS: [&str; 2] = ["%Y/%m/%d", "%Y-%m-%d"];
+
+/// The local time the relative date operators use when no time is given.
+pub fn now() -> NaiveDateTime {
+    Local::now().naive_local()
+}
+
+/// Read a date from a json value.
+/// Strings are in the crate date format or ISO 8601, a date without a time is midnight.
+/// Numbers are milliseconds since the epoch in UTC, the same as a javascript date.
+pub fn parse_date(value: &Value) -> Option<NaiveDateTime> {
+    match value {
+        Value::String(text) => parse_date_str(text),
+        Value::Number(number) => number.as_i64()
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|date| date.naive_utc()),
+        _ => None
+    }
+}
+
+/// Read a date with the chrono formats given, for example "%d/%m/%Y".
+/// Formats without a time read as midnight, when none of the formats fit the value is read as parse_date does.
+pub fn parse_date_with(value: &Value, formats: &[String]) -> Option<NaiveDateTime> {
+    if let Some(text) = value.as_str() {
+        for format in formats {
+            if let Ok(date) = NaiveDateTime::parse_from_str(text, format) {
+                return Some(date);
+            }
+
+            if let Ok(date) = NaiveDate::parse_from_str(text, format) {
+                return Some(date.and_time(Default::default()));
+            }
+        }
+    }
+
+    parse_date(value)
+}
+
+/// Is the text a chrono format chrono can read.
+pub fn is_date_format(format: &str) -> bool {
+    !StrftimeItems::new(format).any(|item| item == Item::Error)
+}
+
+/// A date on the filter intent, "now" and "today" are relative to the given time.
+pub fn parse_reference(value: &Value, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    match value.as_str() {
+        Some("now") => Some(now),
+        Some("today") => Some(now.date().and_time(Default::default())),
+        _ => parse_date(value)
+    }
+}
+
+/// An ISO 8601 duration such as "P7D" as a time span, months are 30 days the same as the duration aggregates.
+pub fn parse_time_span(value: &Value) -> Option<TimeDelta> {
+    let duration = Duration::parse(value.as_str()?).ok()?;
+    TimeDelta::try_milliseconds((duration_to_seconds(&duration) as f64 * 1000.0) as i64)
+}
+
+fn parse_date_str(text: &str) -> Option<NaiveDateTime> {
+    for format in DATE_TIME_FORMATS {
+        if let Ok(date) = NaiveDateTime::parse_from_str(text, format) {
+            return Some(date);
+        }
+    }
+
+    for format in DATE_FORMATS {
+        if let Ok(date) = NaiveDate::parse_from_str(text, format) {
+            return Some(date.and_time(Default::default()));
+        }
+    }
+
+    DateTime::parse_from_rfc3339(text).ok().map(|date| date.with_timezone(&Local).naive_local())
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+    use serde_json::json;
+    use crate::date::{is_date_format, parse_date, parse_date_with, parse_reference, parse_time_span};
+
+    fn date(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn parse_date_test() {
+        assert_eq!(parse_date(&json!("2022/03/04 10:30:00")), Some(date(2022, 3, 4, 10, 30)));
+        assert_eq!(parse_date(&json!("2022/03/04")), Some(date(2022, 3, 4, 0, 0)));
+        assert_eq!(parse_date(&json!("2022-03-04")), Some(date(2022, 3, 4, 0, 0)));
+        assert_eq!(parse_date(&json!("2022-03-04T10:30:00")), Some(date(2022, 3, 4, 10, 30)));
+        assert_eq!(parse_date(&json!("2022-03-04T10:30:00.250")), Some(date(2022, 3, 4, 10, 30) + TimeDelta::milliseconds(250)));
+        assert_eq!(parse_date(&json!(1646389800000_i64)), Some(date(2022, 3, 4, 10, 30)));
+        assert!(parse_date(&json!("2022-03-04T10:30:00Z")).is_some());
+
+        assert_eq!(parse_date(&json!("04/03/2022")), None);
+        assert_eq!(parse_date(&json!("A")), None);
+        assert_eq!(parse_date(&json!(null)), None);
+        assert_eq!(parse_date(&json!(true)), None);
+    }
+
+    #[test]
+    fn parse_reference_test() {
+        let now = date(2022, 3, 4, 10, 30);
+
+        assert_eq!(parse_reference(&json!("now"), now), Some(now));
+        assert_eq!(parse_reference(&json!("today"), now), Some(date(2022, 3, 4, 0, 0)));
+        assert_eq!(parse_reference(&json!("2021/01/01"), now), Some(date(2021, 1, 1, 0, 0)));
+    }
+
+    #[test]
+    fn parse_time_span_test() {
+        assert_eq!(parse_time_span(&json!("P7D")), Some(TimeDelta::days(7)));
+        assert_eq!(parse_time_span(&json!("PT1H30M")), Some(TimeDelta::minutes(90)));
+        assert_eq!(parse_time_span(&json!("P1M")), Some(TimeDelta::days(30)));
+        assert_eq!(parse_time_span(&json!("7 days")), None);
+        assert_eq!(parse_time_span(&json!(7)), None);
+    }
+
+    #[test]
+    fn parse_date_with_test() {
+        let formats = vec!["%d/%m/%Y %H:%M".to_string(), "%d/%m/%Y".to_string()];
+
+        assert_eq!(parse_date_with(&json!("04/03/2022 10:30"), &formats), Some(date(2022, 3, 4, 10, 30)));
+        assert_eq!(parse_date_with(&json!("04/03/2022"), &formats), Some(date(2022, 3, 4, 0, 0)));
+        assert_eq!(parse_date_with(&json!("2022/03/04"), &formats), Some(date(2022, 3, 4, 0, 0)));
+        assert_eq!(parse_date_with(&json!("March"), &formats), None);
+
+        assert!(is_date_format("%Y/%m/%d %H:%M:%S"));
+        assert!(!is_date_format("%Y/%m/%d %Q"));
+    }
+}
@@ -1,16 +1,18 @@
+use std::cmp::Ordering;
 use serde_json::Value;
 use iso8601_duration::Duration;
 use crate::enums::Placement;
 
 pub fn iso8601_to_duration_str(date: &Value) -> String {
-    if date == &Value::Null {
-        return "null".to_string();
-    }
+    let value = match date {
+        Value::Null => return "null".to_string(),
+        Value::String(value) => value.as_str(),
+        _ => return date.to_string()
+    };
 
-    let value = date.as_str().unwrap();
     let result = Duration::parse(value);
 
-    return match result {
+    match result {
         Ok(duration) => {
             format!("{}:{}:{}:{}", duration.day, duration.hour, duration.minute, duration.second)
         }
@@ -30,14 +32,14 @@ pub fn iso8601_to_duration_str_batch(durations: Vec<Value>, path: Option<String>
                 result.push(Value::from(duration_str));
             }
             Some(ref path_str) => {
-                let value = &duration.get(&path_str);
+                let value = &duration.get(path_str);
 
                 match value {
                     None => {
                         result.push(duration.clone());
                     }
                     Some(value) => {
-                        let duration_str = iso8601_to_duration_str(&value);
+                        let duration_str = iso8601_to_duration_str(value);
 
                         let mut clone = duration.clone();
                         clone[&path_str] = Value::from(duration_str);
@@ -48,7 +50,7 @@ pub fn iso8601_to_duration_str_batch(durations: Vec<Value>, path: Option<String>
         }
     }
 
-    return result;
+    result
 }
 
 /// check the evaluate value against the reference value
@@ -56,18 +58,13 @@ pub fn iso8601_to_duration_str_batch(durations: Vec<Value>, path: Option<String>
 /// We evaluate these values ascending so we use the reference object as the base.
 /// Does the reference object come before or after the evaluate object
 pub fn iso8601_placement(reference: &Value, evaluate: &Value,) -> Placement {
-    let evds = match evaluate {
-        Value::Null => "PT0S",
-        _ => evaluate.as_str().unwrap()
-    };
-
-    let rfds = match evaluate {
-        Value::Null => "PT0S",
-        _ => reference.as_str().unwrap()
-    };
+    duration_placement(&parse_duration(reference), &parse_duration(evaluate))
+}
 
-    let evd = Duration::parse(evds).unwrap();
-    let rfd = Duration::parse(rfds).unwrap();
+/// Same as iso8601_placement for durations that are already parsed.
+pub fn duration_placement(reference: &Duration, evaluate: &Duration) -> Placement {
+    let evd = evaluate;
+    let rfd = reference;
 
     if evd.year != rfd.year {
         return match evd.year > rfd.year {
@@ -115,7 +112,35 @@ pub fn iso8601_placement(reference: &Value, evaluate: &Value,) -> Placement {
 }
 
 
-pub fn duration_to_seconds(duration: Duration) -> f32 {
+/// The order of two parsed durations, compared part by part from the years down to the seconds.
+pub fn duration_ordering(duration1: &Duration, duration2: &Duration) -> Ordering {
+    let parts1 = [duration1.year, duration1.month, duration1.day, duration1.hour, duration1.minute, duration1.second];
+    let parts2 = [duration2.year, duration2.month, duration2.day, duration2.hour, duration2.minute, duration2.second];
+
+    for (part1, part2) in parts1.iter().zip(&parts2) {
+        match part1.partial_cmp(part2) {
+            None | Some(Ordering::Equal) => continue,
+            Some(ordering) => return ordering
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// The seconds of a duration, None for null or values that are not valid durations.
+pub fn parse_seconds(value: &Value) -> Option<f32> {
+    let duration = Duration::parse(value.as_str()?).ok()?;
+    Some(duration_to_seconds(&duration))
+}
+
+/// Null or values that are not valid durations are treated as a zero duration.
+pub fn parse_duration(value: &Value) -> Duration {
+    value.as_str()
+        .and_then(|value| Duration::parse(value).ok())
+        .unwrap_or_else(|| Duration::new(0., 0., 0., 0., 0., 0.))
+}
+
+pub fn duration_to_seconds(duration: &Duration) -> f32 {
     duration.year * 60. * 60. * 24. * 30. * 12.
         + duration.month * 60. * 60. * 24. * 30.
         + duration.day * 60. * 60. * 24.
@@ -124,28 +149,29 @@ pub fn duration_to_seconds(duration: Duration) -> f32 {
         + duration.second
 }
 
+#[allow(dead_code)]
 pub fn seconds_to_duration(sec: f32) -> Duration {
-    let years;
+    
     let mut months;
     let mut days;
     let mut hours;
     let mut minutes;
-    let seconds;
+    
 
     minutes = (sec / 60.).trunc();
-    seconds = sec - (minutes * 60.);
+    let seconds = sec - (minutes * 60.);
 
     hours = (minutes / 60.).trunc();
-    minutes = minutes - (hours * 60.);
+    minutes -= hours * 60. ;
 
     days = (hours / 24.).trunc();
-    hours = hours - (days * 24.);
+    hours -= days * 24. ;
 
     months = (days / 30.).trunc();
-    days = days - (months * 30.);
+    days -= months * 30. ;
 
-    years = (months / 12.).trunc();
-    months = months - (years * 12.);
+    let years = (months / 12.).trunc();
+    months -= years * 12. ;
 
     Duration::new(years, months, days, hours, minutes, seconds)
 }
@@ -157,7 +183,7 @@ mod test {
     use serde_json::{json, Value};
     use crate::duration::{duration_to_seconds, iso8601_placement, iso8601_to_duration_str, seconds_to_duration};
     use crate::enums::Placement;
-    use crate::iso8601_to_duration_str_batch;
+    use crate::duration::iso8601_to_duration_str_batch;
 
     #[test]
     fn value_to_date_string_test() {
@@ -175,7 +201,7 @@ mod test {
             Placement::Before => false,
             Placement::After => true
         };
-        assert_eq!(is_after, true);
+        assert!(is_after);
     }
 
     #[test]
@@ -185,22 +211,23 @@ mod test {
             Placement::Before => false,
             Placement::After => true
         };
-        assert_eq!(is_after, false);
+        assert!(!is_after);
 
         let result = iso8601_placement(&Value::from("P13DT21H23M45S"), &Value::from("P0DT21H22M45.97096S"));
         let is_after = match result {
             Placement::Before => false,
             Placement::After => true
         };
-        assert_eq!(is_after, true);
+        assert!(is_after);
     }
 
     #[test]
     fn iso8601_to_duration_str_batch_test() {
-        let mut durations: Vec<Value> = Vec::new();
-        durations.push(Value::from("P13DT21H23M45S"));
-        durations.push(Value::from("P0DT21H22M45.97096S"));
-        durations.push(Value::Null);
+        let durations: Vec<Value> = vec![
+            Value::from("P13DT21H23M45S"),
+            Value::from("P0DT21H22M45.97096S"),
+            Value::Null
+        ];
 
         let result = iso8601_to_duration_str_batch(durations, None);
         assert_eq!(result.len(), 3);
@@ -229,9 +256,9 @@ mod test {
 
     fn convert_to_back(year: f32, month: f32, day: f32, hour: f32, min: f32, sec: f32) -> Duration {
         let duration = Duration::new(year, month, day, hour, min, sec);
-        let seconds = duration_to_seconds(duration);
-        let result = seconds_to_duration(seconds);
-        result
+        let seconds = duration_to_seconds(&duration);
+        
+        seconds_to_duration(seconds)
     }
 
     #[test]
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// Where does this value fit into an array.
 /// Smaller values are before larger values.
 /// Thus 1 is before 2 and 2 is after 1
@@ -7,9 +9,13 @@ pub enum Placement {
     After
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub enum SortDirection {
+    #[default]
+    #[serde(rename = "asc")]
     Ascending,
+
+    #[serde(rename = "desc", alias = "dec")]
     Descending
 }
 
@@ -27,4 +33,14 @@ impl PartialEq  for SortDirection {
 
         dec1 == dec2
     }
-}
\ No newline at end of file
+}
+
+/// Where the rows with a null or missing sort value go, the same for both directions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NullOrder {
+    First,
+
+    #[default]
+    Last
+}
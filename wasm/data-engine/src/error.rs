@@ -0,0 +1,177 @@
+use std::fmt;
+use serde_json::{json, Value};
+use serde_path_to_error::Segment;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::JsValue;
+
+/// Category of a failure so the caller can react without parsing the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    InvalidJson,
+    MissingProperty,
+    InvalidType,
+    InvalidValue,
+    UnknownOperator,
+    UnknownAggregate,
+    RowOutOfRange,
+    UnknownDataset
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::InvalidJson      => "invalid_json",
+            ErrorCode::MissingProperty  => "missing_property",
+            ErrorCode::InvalidType      => "invalid_type",
+            ErrorCode::InvalidValue     => "invalid_value",
+            ErrorCode::UnknownOperator  => "unknown_operator",
+            ErrorCode::UnknownAggregate => "unknown_aggregate",
+            ErrorCode::RowOutOfRange    => "row_out_of_range",
+            ErrorCode::UnknownDataset   => "unknown_dataset"
+        }
+    }
+}
+
+/// Error raised while processing an intent or the data it is applied to.
+/// The path points at the offending part of the intent, for example "filter[2].operator".
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataError {
+    pub code: ErrorCode,
+    pub path: String,
+    pub message: String
+}
+
+pub type DataResult<T> = Result<T, DataError>;
+
+impl DataError {
+    pub fn new(code: ErrorCode, path: &str, message: String) -> DataError {
+        DataError {
+            code,
+            path: path.to_string(),
+            message
+        }
+    }
+
+    pub fn invalid_json(path: &str, error: serde_json::Error) -> DataError {
+        DataError::new(ErrorCode::InvalidJson, path, error.to_string())
+    }
+
+    pub fn missing_property(path: &str, property: &str) -> DataError {
+        DataError::new(ErrorCode::MissingProperty, path, format!("'{}' is required", property))
+    }
+
+    pub fn invalid_type(path: &str, expected: &str) -> DataError {
+        DataError::new(ErrorCode::InvalidType, path, format!("expected {}", expected))
+    }
+
+    /// Serde only gives back a message so the well known ones are mapped onto an error code.
+    /// The path is taken from where serde was in the intent when it failed.
+    pub fn from_serde(error: serde_path_to_error::Error<serde_json::Error>) -> DataError {
+        let mut path = String::new();
+
+        for segment in error.path().iter() {
+            match segment {
+                Segment::Seq { index } => path.push_str(&format!("[{}]", index)),
+                Segment::Map { key } | Segment::Enum { variant: key } => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(key);
+                }
+                Segment::Unknown => {}
+            }
+        }
+
+        let message = error.inner().to_string();
+
+        if let Some(property) = message.strip_prefix("missing field `").and_then(|value| value.strip_suffix('`')) {
+            return DataError::missing_property(property, property).at(&path);
+        }
+
+        let code = match message.as_str() {
+            value if value.starts_with("invalid type")      => ErrorCode::InvalidType,
+            value if value.starts_with("unknown operator")  => ErrorCode::UnknownOperator,
+            value if value.starts_with("unknown aggregate") => ErrorCode::UnknownAggregate,
+            _                                               => ErrorCode::InvalidValue
+        };
+
+        DataError::new(code, &path, message)
+    }
+
+    pub fn to_value(&self) -> Value {
+        json!({
+            "code": self.code.as_str(),
+            "path": self.path,
+            "message": self.message
+        })
+    }
+
+    /// Prefix the error path with the segment of the parent that contained the failure.
+    /// Errors bubble up from the innermost expression so the path is built on the way out.
+    pub fn at(mut self, segment: &str) -> DataError {
+        if segment.is_empty() {
+            return self;
+        }
+
+        if self.path.is_empty() {
+            self.path = segment.to_string();
+        }
+        else if self.path.starts_with('[') {
+            self.path = format!("{}{}", segment, self.path);
+        }
+        else {
+            self.path = format!("{}.{}", segment, self.path);
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            return write!(f, "{}: {}", self.code.as_str(), self.message);
+        }
+
+        write!(f, "{} at {}: {}", self.code.as_str(), self.path, self.message)
+    }
+}
+
+/// Errors reach javascript as a normal Error with "code" and "path" properties attached.
+#[cfg(feature = "wasm")]
+impl From<DataError> for JsValue {
+    fn from(error: DataError) -> JsValue {
+        let result = js_sys::Error::new(&error.message);
+        let _ = js_sys::Reflect::set(&result, &JsValue::from("code"), &JsValue::from(error.code.as_str()));
+        let _ = js_sys::Reflect::set(&result, &JsValue::from("path"), &JsValue::from(error.path));
+        JsValue::from(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::{DataError, ErrorCode};
+
+    #[test]
+    fn at_builds_path_test() {
+        let error = DataError::new(ErrorCode::UnknownOperator, "operator", "unknown operator 'betwen'".to_string());
+        let error = error.at("expressions[1]").at("filter[2]");
+
+        assert_eq!(error.path, "filter[2].expressions[1].operator");
+    }
+
+    #[test]
+    fn at_index_path_test() {
+        let error = DataError::invalid_type("[1]", "a string").at("group");
+        assert_eq!(error.path, "group[1]");
+
+        let error = DataError::invalid_type("", "an array").at("sort");
+        assert_eq!(error.path, "sort");
+    }
+
+    #[test]
+    fn display_test() {
+        let error = DataError::new(ErrorCode::UnknownOperator, "filter[0].operator", "unknown operator 'betwen'".to_string());
+        assert_eq!(error.to_string(), "unknown_operator at filter[0].operator: unknown operator 'betwen'");
+    }
+}
@@ -0,0 +1,50 @@
+mod greater_than;
+mod less_than;
+mod greater_or_equal;
+mod less_or_equal;
+mod equal;
+mod not_equal;
+mod object_evaluator;
+mod filter_plan;
+mod is_null;
+mod is_not_null;
+mod like;
+mod not_like;
+mod one_of;
+mod between;
+mod starts_with;
+mod ends_with;
+mod contains;
+mod contains_any;
+mod contains_all;
+mod is_empty;
+mod date;
+mod duration;
+mod length;
+mod pattern;
+
+pub use greater_than::GreaterThan;
+pub use less_than::LessThan;
+pub use greater_or_equal::GreaterOrEqual;
+pub use less_or_equal::LessOrEqual;
+pub use equal::Equal;
+pub use not_equal::NotEqual;
+pub use is_null::IsNull;
+pub use is_not_null::IsNotNull;
+pub use like::Like;
+pub use not_like::NotLike;
+pub use one_of::OneOf;
+pub use between::Between;
+pub use starts_with::StartsWith;
+pub use ends_with::EndsWith;
+pub use contains::Contains;
+pub use contains_any::ContainsAny;
+pub use contains_all::ContainsAll;
+pub use is_empty::IsEmpty;
+
+pub use object_evaluator::evaluate_object;
+pub use filter_plan::{FilterPlan, FilterContext, PairPredicate, get_evaluator, compile_typed, compile_field, compile_pair, compile_quantified, folds_case};
+pub use date::{compile_date, compile_date_pair, ValuePredicate};
+pub use duration::{compile_duration, compile_duration_pair};
+pub use length::{compile_length, compile_length_pair, length};
+pub use pattern::{compile_pattern, build_regex, PatternCache, REGEX_FLAGS};
\ No newline at end of file
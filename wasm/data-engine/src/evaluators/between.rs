@@ -0,0 +1,89 @@
+use serde_json::Value;
+use crate::traits::Eval;
+
+
+pub struct Between {}
+
+impl Eval for Between {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool {
+        if !obj2.is_array() {
+            return false;
+        }
+
+        let values = obj2.as_array().unwrap();
+
+        if values.len() != 2 {
+            return false;
+        }
+
+        if obj1.is_i64() && values[0].is_i64() && values[1].is_i64() {
+            let value = obj1.as_i64().unwrap();
+            let value1 = values[0].as_i64().unwrap();
+            let value2 = values[1].as_i64().unwrap();
+
+            return value1 <= value && value <= value2;
+        }
+
+        if obj1.is_number() {
+            return match (obj1.as_f64(), values[0].as_f64(), values[1].as_f64()) {
+                (Some(value), Some(value1), Some(value2)) => value1 <= value && value <= value2,
+                _ => false
+            }
+        }
+
+        if obj1.is_string() {
+            return match (obj1.as_str(), values[0].as_str(), values[1].as_str()) {
+                (Some(value), Some(value1), Some(value2)) => value1 <= value && value <= value2,
+                _ => false
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::traits::Eval;
+    use crate::evaluators::Between;
+
+    #[test]
+    fn is_null_int_test() {
+        let values = json!([10, 20]);
+        assert!(Between::evaluate(&Value::from(10), &values));
+        assert!(Between::evaluate(&Value::from(20), &values));
+        assert!(Between::evaluate(&Value::from(15), &values));
+        assert!(!Between::evaluate(&Value::from(5), &values));
+        assert!(!Between::evaluate(&Value::from(30), &values));
+    }
+
+    #[test]
+    fn is_null_float_test() {
+        let values = json!([10., 20.]);
+        assert!(Between::evaluate(&Value::from(10.), &values));
+        assert!(Between::evaluate(&Value::from(20.), &values));
+        assert!(Between::evaluate(&Value::from(15.), &values));
+        assert!(!Between::evaluate(&Value::from(5.), &values));
+        assert!(!Between::evaluate(&Value::from(30.), &values));
+    }
+
+    #[test]
+    fn is_null_string_test() {
+        let values = json!(["d", "f"]);
+        assert!(Between::evaluate(&Value::from("d"), &values));
+        assert!(Between::evaluate(&Value::from("f"), &values));
+        assert!(Between::evaluate(&Value::from("e"), &values));
+        assert!(!Between::evaluate(&Value::from("a"), &values));
+        assert!(!Between::evaluate(&Value::from("g"), &values));
+    }
+
+    #[test]
+    fn mixed_number_test() {
+        let values = json!([10, 20.5]);
+        assert!(Between::evaluate(&Value::from(10), &values));
+        assert!(Between::evaluate(&Value::from(20.5), &values));
+        assert!(!Between::evaluate(&Value::from(21), &values));
+        assert!(!Between::evaluate(&Value::from(15), &json!(["a", 20])));
+    }
+}
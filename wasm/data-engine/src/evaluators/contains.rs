@@ -0,0 +1,46 @@
+use std::cmp::Ordering;
+use serde_json::Value;
+use crate::compare::compare_values;
+use crate::traits::Eval;
+
+pub struct Contains {}
+
+impl Eval for Contains {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool {
+        match (obj1, obj2.as_str()) {
+            (Value::Array(items), _) => has_item(items, obj2),
+            (Value::String(s1), Some(s2)) => s1.contains(s2),
+            _ => false
+        }
+    }
+}
+
+/// Is the value one of the items, numbers are the same whether they are integers or floats.
+pub fn has_item(items: &[Value], value: &Value) -> bool {
+    items.iter().any(|item| compare_values(item, value) == Ordering::Equal)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::traits::Eval;
+    use crate::evaluators::Contains;
+
+    #[test]
+    fn contains_test() {
+        let values = Value::from("Hello World");
+        assert!(Contains::evaluate(&values, &Value::from("Hello")));
+        assert!(Contains::evaluate(&values, &Value::from("o W")));
+        assert!(!Contains::evaluate(&values, &Value::from("Not")));
+        assert!(!Contains::evaluate(&Value::from(10), &Value::from("1")));
+    }
+
+    #[test]
+    fn array_test() {
+        let values = json!(["red", "green", 2]);
+        assert!(Contains::evaluate(&values, &Value::from("green")));
+        assert!(Contains::evaluate(&values, &Value::from(2.0)));
+        assert!(!Contains::evaluate(&values, &Value::from("gr")));
+        assert!(!Contains::evaluate(&json!([]), &Value::from("red")));
+    }
+}
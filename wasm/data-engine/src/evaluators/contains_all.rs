@@ -0,0 +1,38 @@
+use serde_json::Value;
+use crate::evaluators::contains::has_item;
+use crate::traits::Eval;
+
+/// The array on the row has every one of the values, a row value that is not an array is an array of one.
+pub struct ContainsAll {}
+
+impl Eval for ContainsAll {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool {
+        let items = match obj1 {
+            Value::Null => &[],
+            Value::Array(items) => items.as_slice(),
+            _ => std::slice::from_ref(obj1)
+        };
+
+        match obj2.as_array() {
+            Some(values) => values.iter().all(|value| has_item(items, value)),
+            None => false
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::traits::Eval;
+    use crate::evaluators::ContainsAll;
+
+    #[test]
+    fn contains_all_test() {
+        let values = json!(["red", "green", 1]);
+        assert!(ContainsAll::evaluate(&values, &json!(["green", "red"])));
+        assert!(ContainsAll::evaluate(&values, &json!([1.0])));
+        assert!(!ContainsAll::evaluate(&values, &json!(["red", "blue"])));
+        assert!(ContainsAll::evaluate(&values, &json!([])));
+        assert!(!ContainsAll::evaluate(&Value::Null, &json!(["red"])));
+    }
+}
@@ -0,0 +1,38 @@
+use serde_json::Value;
+use crate::evaluators::contains::has_item;
+use crate::traits::Eval;
+
+/// The array on the row has at least one of the values, a row value that is not an array is an array of one.
+pub struct ContainsAny {}
+
+impl Eval for ContainsAny {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool {
+        let items = match obj1 {
+            Value::Null => return false,
+            Value::Array(items) => items.as_slice(),
+            _ => std::slice::from_ref(obj1)
+        };
+
+        match obj2.as_array() {
+            Some(values) => values.iter().any(|value| has_item(items, value)),
+            None => false
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::traits::Eval;
+    use crate::evaluators::ContainsAny;
+
+    #[test]
+    fn contains_any_test() {
+        let values = json!(["red", "green"]);
+        assert!(ContainsAny::evaluate(&values, &json!(["blue", "green"])));
+        assert!(!ContainsAny::evaluate(&values, &json!(["blue"])));
+        assert!(!ContainsAny::evaluate(&values, &json!([])));
+        assert!(ContainsAny::evaluate(&Value::from("red"), &json!(["red", "blue"])));
+        assert!(!ContainsAny::evaluate(&Value::Null, &json!([null])));
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::{Datelike, NaiveDateTime};
+use serde_json::Value;
+use crate::date::{parse_date, parse_reference, parse_time_span};
+use crate::evaluators::{get_evaluator, PairPredicate};
+use crate::intent::{Comparison, Operator};
+
+pub type ValuePredicate = Box<dyn Fn(&Value) -> bool>;
+
+type DateTest = fn(&NaiveDateTime, &NaiveDateTime) -> bool;
+
+/// A comparison on dates with the intent value parsed once.
+/// Now is the time the relative operators work from, field values that are not a date never match.
+pub fn compile_date(comparison: &Comparison, now: NaiveDateTime) -> ValuePredicate {
+    let value = &comparison.value;
+
+    match comparison.operator {
+        Operator::IsNull | Operator::NotNull => {
+            let evaluate = get_evaluator(comparison.operator);
+            Box::new(move |cell| evaluate(cell, &Value::Null))
+        }
+        Operator::GreaterThan | Operator::After     => compare(parse_reference(value, now), |a, b| a > b),
+        Operator::GreaterOrEqual                    => compare(parse_reference(value, now), |a, b| a >= b),
+        Operator::LessThan | Operator::Before       => compare(parse_reference(value, now), |a, b| a < b),
+        Operator::LessOrEqual                       => compare(parse_reference(value, now), |a, b| a <= b),
+        Operator::Equal                             => compare(parse_reference(value, now), |a, b| a == b),
+        Operator::NotEqual                          => compare(parse_reference(value, now), |a, b| a != b),
+        Operator::On                                => compare(parse_reference(value, now), |a, b| a.date() == b.date()),
+        Operator::SameWeek                          => compare(relative_to(value, now), |a, b| a.iso_week() == b.iso_week()),
+        Operator::SameMonth                         => compare(relative_to(value, now), |a, b| a.year() == b.year() && a.month() == b.month()),
+        Operator::SameYear                          => compare(relative_to(value, now), |a, b| a.year() == b.year()),
+        Operator::WithinLast                        => within(parse_time_span(value).map(|span| (now - span, now))),
+        Operator::WithinNext                        => within(parse_time_span(value).map(|span| (now, now + span))),
+        Operator::Between => {
+            let range = match value.as_array().map(Vec::as_slice) {
+                Some([start, end]) => parse_reference(start, now).zip(parse_reference(end, now)),
+                _ => None
+            };
+
+            within(range)
+        }
+        Operator::In => {
+            let dates: Vec<NaiveDateTime> = value.as_array()
+                .map(|values| values.iter().filter_map(|value| parse_reference(value, now)).collect())
+                .unwrap_or_default();
+
+            Box::new(move |cell| parse_date(cell).is_some_and(|date| dates.contains(&date)))
+        }
+        _ => Box::new(|_| false)
+    }
+}
+
+/// A comparison of the date in the field with the date in another field of the row, the operator is worked out once.
+/// A missing date in the other field never matches, the same week, month or year operators don't read it as now.
+pub fn compile_date_pair(operator: Operator, now: NaiveDateTime) -> PairPredicate {
+    let test: DateTest = match operator {
+        Operator::GreaterThan | Operator::After     => |a, b| a > b,
+        Operator::GreaterOrEqual                    => |a, b| a >= b,
+        Operator::LessThan | Operator::Before       => |a, b| a < b,
+        Operator::LessOrEqual                       => |a, b| a <= b,
+        Operator::Equal                             => |a, b| a == b,
+        Operator::NotEqual                          => |a, b| a != b,
+        Operator::On                                => |a, b| a.date() == b.date(),
+        Operator::SameWeek                          => |a, b| a.iso_week() == b.iso_week(),
+        Operator::SameMonth                         => |a, b| a.year() == b.year() && a.month() == b.month(),
+        Operator::SameYear                          => |a, b| a.year() == b.year(),
+        Operator::WithinLast => {
+            return Box::new(move |cell, other| parse_time_span(other).is_some_and(|span| in_range(cell, now - span, now)));
+        }
+        Operator::WithinNext => {
+            return Box::new(move |cell, other| parse_time_span(other).is_some_and(|span| in_range(cell, now, now + span)));
+        }
+        _ => return Box::new(|_, _| false)
+    };
+
+    Box::new(move |cell, other| match parse_date(cell).zip(parse_reference(other, now)) {
+        Some((date, reference)) => test(&date, &reference),
+        None => false
+    })
+}
+
+fn in_range(cell: &Value, start: NaiveDateTime, end: NaiveDateTime) -> bool {
+    parse_date(cell).is_some_and(|date| start <= date && date <= end)
+}
+
+/// The same week, month or year operators use now when the intent has no date.
+fn relative_to(value: &Value, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    match value {
+        Value::Null => Some(now),
+        _ => parse_reference(value, now)
+    }
+}
+
+fn compare(reference: Option<NaiveDateTime>, test: DateTest) -> ValuePredicate {
+    match reference {
+        None => Box::new(|_| false),
+        Some(reference) => Box::new(move |cell| parse_date(cell).is_some_and(|date| test(&date, &reference)))
+    }
+}
+
+/// The start and end of the range are included.
+fn within(range: Option<(NaiveDateTime, NaiveDateTime)>) -> ValuePredicate {
+    match range {
+        None => Box::new(|_| false),
+        Some((start, end)) => Box::new(move |cell| in_range(cell, start, end))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use serde_json::{json, Value};
+    use crate::evaluators::date::compile_date;
+    use crate::intent;
+    use crate::intent::FilterExpr;
+
+    fn now() -> NaiveDateTime {
+        // a wednesday
+        NaiveDate::from_ymd_opt(2022, 3, 16).unwrap().and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    fn get_data() -> Vec<Value> {
+        vec![
+            json!("2022/03/16 08:00:00"),
+            json!("2022/03/14"),
+            json!("2022-03-13T23:59:59"),
+            json!("2022/03/20 10:00:00"),
+            json!("2022/02/28"),
+            json!("2021/03/16"),
+            json!(null),
+            json!("not a date")
+        ]
+    }
+
+    fn run(intent: Value) -> Vec<usize> {
+        let intent: FilterExpr = intent::parse(&intent, "filter").unwrap();
+
+        let comparison = match intent {
+            FilterExpr::Comparison(comparison) => comparison,
+            _ => panic!("expected a comparison")
+        };
+
+        let predicate = compile_date(&comparison, now());
+        get_data().iter().enumerate().filter(|(_, cell)| predicate(cell)).map(|(index, _)| index).collect()
+    }
+
+    #[test]
+    fn compare_test() {
+        assert_eq!(run(json!({ "field": "date", "operator": "before", "value": "2022/03/14" })), vec![2, 4, 5]);
+        assert_eq!(run(json!({ "field": "date", "operator": "after", "value": "today" })), vec![0, 3]);
+        assert_eq!(run(json!({ "field": "date", "operator": "on", "value": "2022-03-16" })), vec![0]);
+        assert_eq!(run(json!({ "field": "date", "operator": ">=", "value": "2022/03/14", "type": "date" })), vec![0, 1, 3]);
+        assert_eq!(run(json!({ "field": "date", "operator": "<", "value": "now", "type": "date" })), vec![0, 1, 2, 4, 5]);
+        assert_eq!(run(json!({ "field": "date", "operator": "==", "value": "2022-03-14T00:00:00", "type": "date" })), vec![1]);
+        assert_eq!(run(json!({ "field": "date", "operator": "!=", "value": "2022/03/14", "type": "date" })), vec![0, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn range_test() {
+        assert_eq!(run(json!({ "field": "date", "operator": "between", "value": ["2022/03/01", "2022/03/16"], "type": "date" })), vec![1, 2]);
+        assert_eq!(run(json!({ "field": "date", "operator": "in", "value": ["2022/03/14", "2021/03/16"], "type": "date" })), vec![1, 5]);
+        assert_eq!(run(json!({ "field": "date", "operator": "within_last", "value": "P3D" })), vec![0, 1, 2]);
+        assert_eq!(run(json!({ "field": "date", "operator": "within_next", "value": "P7D" })), vec![3]);
+    }
+
+    #[test]
+    fn same_period_test() {
+        assert_eq!(run(json!({ "field": "date", "operator": "same_week" })), vec![0, 1, 3]);
+        assert_eq!(run(json!({ "field": "date", "operator": "same_month" })), vec![0, 1, 2, 3]);
+        assert_eq!(run(json!({ "field": "date", "operator": "same_year" })), vec![0, 1, 2, 3, 4]);
+        assert_eq!(run(json!({ "field": "date", "operator": "same_month", "value": "2022/02/01" })), vec![4]);
+    }
+
+    #[test]
+    fn null_test() {
+        assert_eq!(run(json!({ "field": "date", "operator": "is_null", "type": "date" })), vec![6]);
+        assert_eq!(run(json!({ "field": "date", "operator": "not_null", "type": "date" })), vec![0, 1, 2, 3, 4, 5, 7]);
+    }
+}
@@ -0,0 +1,130 @@
+use serde_json::Value;
+use crate::duration::parse_seconds;
+use crate::evaluators::{get_evaluator, PairPredicate, ValuePredicate};
+use crate::intent::{Comparison, Operator};
+
+type SecondsTest = fn(f32, f32) -> bool;
+
+/// A comparison on ISO 8601 durations by their length in seconds, so "PT10H" is more than "PT9H".
+/// Field values that are not a duration never match.
+pub fn compile_duration(comparison: &Comparison) -> ValuePredicate {
+    let value = &comparison.value;
+
+    match comparison.operator {
+        Operator::IsNull | Operator::NotNull => {
+            let evaluate = get_evaluator(comparison.operator);
+            Box::new(move |cell| evaluate(cell, &Value::Null))
+        }
+        Operator::GreaterThan       => compare(parse_seconds(value), |a, b| a > b),
+        Operator::GreaterOrEqual    => compare(parse_seconds(value), |a, b| a >= b),
+        Operator::LessThan          => compare(parse_seconds(value), |a, b| a < b),
+        Operator::LessOrEqual       => compare(parse_seconds(value), |a, b| a <= b),
+        Operator::Equal             => compare(parse_seconds(value), |a, b| a == b),
+        Operator::NotEqual          => compare(parse_seconds(value), |a, b| a != b),
+        Operator::Between => {
+            let range = match value.as_array().map(Vec::as_slice) {
+                Some([start, end]) => parse_seconds(start).zip(parse_seconds(end)),
+                _ => None
+            };
+
+            match range {
+                None => Box::new(|_| false),
+                Some((start, end)) => Box::new(move |cell| parse_seconds(cell).is_some_and(|seconds| start <= seconds && seconds <= end))
+            }
+        }
+        Operator::In => {
+            let options: Vec<f32> = value.as_array()
+                .map(|values| values.iter().filter_map(parse_seconds).collect())
+                .unwrap_or_default();
+
+            Box::new(move |cell| parse_seconds(cell).is_some_and(|seconds| options.contains(&seconds)))
+        }
+        _ => Box::new(|_| false)
+    }
+}
+
+/// A comparison of the duration in the field with the duration in another field of the row.
+pub fn compile_duration_pair(operator: Operator) -> PairPredicate {
+    let test: SecondsTest = match operator {
+        Operator::GreaterThan       => |a, b| a > b,
+        Operator::GreaterOrEqual    => |a, b| a >= b,
+        Operator::LessThan          => |a, b| a < b,
+        Operator::LessOrEqual       => |a, b| a <= b,
+        Operator::Equal             => |a, b| a == b,
+        Operator::NotEqual          => |a, b| a != b,
+        _ => return Box::new(|_, _| false)
+    };
+
+    Box::new(move |cell, other| match parse_seconds(cell).zip(parse_seconds(other)) {
+        Some((seconds, reference)) => test(seconds, reference),
+        None => false
+    })
+}
+
+fn compare(reference: Option<f32>, test: SecondsTest) -> ValuePredicate {
+    match reference {
+        None => Box::new(|_| false),
+        Some(reference) => Box::new(move |cell| parse_seconds(cell).is_some_and(|seconds| test(seconds, reference)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::evaluators::duration::compile_duration;
+    use crate::evaluators::evaluate_object;
+    use crate::intent;
+    use crate::intent::FilterExpr;
+
+    fn get_data() -> Vec<Value> {
+        vec![
+            json!("PT9H"),
+            json!("PT10H"),
+            json!("PT4H"),
+            json!("PT240M"),
+            json!("P1D"),
+            json!(null),
+            json!("ten hours")
+        ]
+    }
+
+    fn run(intent: Value) -> Vec<usize> {
+        let comparison = match intent::parse(&intent, "filter").unwrap() {
+            FilterExpr::Comparison(comparison) => comparison,
+            _ => panic!("expected a comparison")
+        };
+
+        let predicate = compile_duration(&comparison);
+        get_data().iter().enumerate().filter(|(_, cell)| predicate(cell)).map(|(index, _)| index).collect()
+    }
+
+    #[test]
+    fn compare_test() {
+        assert_eq!(run(json!({ "field": "downtime", "operator": ">", "value": "PT4H", "type": "duration" })), vec![0, 1, 4]);
+        assert_eq!(run(json!({ "field": "downtime", "operator": ">=", "value": "PT4H", "type": "duration" })), vec![0, 1, 2, 3, 4]);
+        assert_eq!(run(json!({ "field": "downtime", "operator": "<", "value": "PT10H", "type": "duration" })), vec![0, 2, 3]);
+        assert_eq!(run(json!({ "field": "downtime", "operator": "<=", "value": "PT9H", "type": "duration" })), vec![0, 2, 3]);
+        assert_eq!(run(json!({ "field": "downtime", "operator": "==", "value": "PT4H", "type": "duration" })), vec![2, 3]);
+        assert_eq!(run(json!({ "field": "downtime", "operator": "!=", "value": "PT4H", "type": "duration" })), vec![0, 1, 4]);
+    }
+
+    #[test]
+    fn range_test() {
+        assert_eq!(run(json!({ "field": "downtime", "operator": "between", "value": ["PT5H", "PT10H"], "type": "duration" })), vec![0, 1]);
+        assert_eq!(run(json!({ "field": "downtime", "operator": "in", "value": ["PT4H", "PT24H"], "type": "duration" })), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn null_test() {
+        assert_eq!(run(json!({ "field": "downtime", "operator": "is_null", "type": "duration" })), vec![5]);
+        assert_eq!(run(json!({ "field": "downtime", "operator": "not_null", "type": "duration" })), vec![0, 1, 2, 3, 4, 6]);
+    }
+
+    #[test]
+    fn evaluate_object_test() {
+        let intent = json!({ "field": "downtime", "operator": ">", "value": "PT9H", "type": "duration" });
+
+        assert!(evaluate_object(&intent, &json!({ "downtime": "PT10H" }), false).unwrap());
+        assert!(!evaluate_object(&intent, &json!({ "downtime": "PT8H59M" }), false).unwrap());
+    }
+}
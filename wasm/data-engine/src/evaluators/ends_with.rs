@@ -0,0 +1,28 @@
+use serde_json::Value;
+use crate::traits::Eval;
+
+pub struct EndsWith {}
+
+impl Eval for EndsWith {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool {
+        match (obj1.as_str(), obj2.as_str()) {
+            (Some(s1), Some(s2)) => s1.ends_with(s2),
+            _ => false
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{Value};
+    use crate::traits::Eval;
+    use crate::evaluators::EndsWith;
+
+    #[test]
+    fn starts_with_test() {
+        let values = Value::from("Hello World");
+        assert!(!EndsWith::evaluate(&values, &Value::from("Hello")));
+        assert!(EndsWith::evaluate(&values, &Value::from("World")));
+        assert!(!EndsWith::evaluate(&values, &Value::from("Not")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use serde_json::Value;
+use crate::eval;
+use crate::traits::Eval;
+
+pub struct Equal {}
+
+impl Eval for Equal {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool {
+        eval!(obj1, ==, obj2)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::evaluators::Equal;
+    use serde_json::Value;
+    use crate::traits::Eval;
+
+    #[test]
+    fn test_positive() {
+        assert!(Equal::evaluate(&Value::from(20), &Value::from(20)));
+        assert!(Equal::evaluate(&Value::from(20.0), &Value::from(20.0)));
+        assert!(Equal::evaluate(&Value::from(true), &Value::from(true)));
+        assert!(Equal::evaluate(&Value::from("B"), &Value::from("B")));
+        assert!(Equal::evaluate(&Value::from("100"), &Value::from("100")));
+    }
+
+    #[test]
+    fn test_negative() {
+        assert!(!Equal::evaluate(&Value::from(10), &Value::from(20)));
+        assert!(!Equal::evaluate(&Value::from(10.0), &Value::from(20.0)));
+        assert!(!Equal::evaluate(&Value::from(false), &Value::from(true)));
+        assert!(!Equal::evaluate(&Value::from("A"), &Value::from("B"), ));
+        assert!(!Equal::evaluate(&Value::from("001"), &Value::from("100")));
+    }
+}
\ No newline at end of file
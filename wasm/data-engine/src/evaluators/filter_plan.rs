@@ -0,0 +1,437 @@
+use chrono::NaiveDateTime;
+use serde_json::Value;
+use crate::date;
+use crate::row::Row;
+use crate::traits::Eval;
+use crate::intent::{Comparison, FilterExpr, Operator, Quantified};
+use crate::path::FieldPath;
+
+use crate::evaluators::{Between, GreaterThan};
+use crate::evaluators::GreaterOrEqual;
+use crate::evaluators::LessThan;
+use crate::evaluators::LessOrEqual;
+use crate::evaluators::Equal;
+use crate::evaluators::NotEqual;
+use crate::evaluators::IsNull;
+use crate::evaluators::IsNotNull;
+use crate::evaluators::Like;
+use crate::evaluators::NotLike;
+use crate::evaluators::OneOf;
+use crate::evaluators::StartsWith;
+use crate::evaluators::EndsWith;
+use crate::evaluators::Contains;
+use crate::evaluators::ContainsAny;
+use crate::evaluators::ContainsAll;
+use crate::evaluators::IsEmpty;
+use crate::evaluators::{compile_date, compile_date_pair, compile_duration, compile_duration_pair, compile_length, compile_length_pair, compile_pattern, PatternCache, ValuePredicate};
+
+type Predicate = Box<dyn Fn(&dyn Row) -> bool>;
+
+/// A test on the value of the field and the value of the value field.
+pub type PairPredicate = Box<dyn Fn(&Value, &Value) -> bool>;
+type Evaluate = fn(&Value, &Value) -> bool;
+
+/// A filter intent compiled into a tree of closures.
+/// Operators, field paths and case folded literals are worked out once when compiling,
+/// evaluating a row only reads the row.
+pub struct FilterPlan {
+    predicates: Vec<Predicate>
+}
+
+impl FilterPlan {
+    /// All the expressions in the intent must pass for a row to be in the filter.
+    pub fn compile(intent: &[FilterExpr], case_sensitive: bool) -> FilterPlan {
+        FilterPlan::compile_at(intent, case_sensitive, date::now())
+    }
+
+    /// Compile with the time the relative date operators such as within_last work from.
+    pub fn compile_at(intent: &[FilterExpr], case_sensitive: bool, now: NaiveDateTime) -> FilterPlan {
+        let context = FilterContext::new(case_sensitive, now);
+
+        FilterPlan {
+            predicates: compile_expressions(intent, &context)
+        }
+    }
+
+    pub fn matches(&self, row: &dyn Row) -> bool {
+        self.predicates.iter().all(|predicate| predicate(row))
+    }
+
+    /// Give back the indexes of the rows visible in the filter.
+    pub fn run<R: Row>(&self, data: &[R]) -> Vec<usize> {
+        let mut result = Vec::new();
+
+        for (index, row) in data.iter().enumerate() {
+            if self.matches(row) {
+                result.push(index);
+            }
+        }
+
+        result
+    }
+}
+
+/// What compiling a filter needs besides the intent, shared by all the expressions in the intent.
+pub struct FilterContext {
+    pub case_sensitive: bool,
+    pub now: NaiveDateTime,
+    pub patterns: PatternCache
+}
+
+impl FilterContext {
+    pub fn new(case_sensitive: bool, now: NaiveDateTime) -> FilterContext {
+        FilterContext {
+            case_sensitive,
+            now,
+            patterns: PatternCache::default()
+        }
+    }
+}
+
+fn compile_expressions(expressions: &[FilterExpr], context: &FilterContext) -> Vec<Predicate> {
+    expressions.iter().map(|expression| compile_expression(expression, context)).collect()
+}
+
+fn compile_expression(expression: &FilterExpr, context: &FilterContext) -> Predicate {
+    match expression {
+        FilterExpr::Comparison(comparison) => compile_comparison(comparison, context),
+        FilterExpr::And(expressions) => {
+            // as soon as a expression is false, the row fails and we stop the process
+            let predicates = compile_expressions(expressions, context);
+            Box::new(move |row| predicates.iter().all(|predicate| predicate(row)))
+        }
+        FilterExpr::Or(expressions) => {
+            // as soon as the expression passes, stop and the row succeeds
+            let predicates = compile_expressions(expressions, context);
+            Box::new(move |row| predicates.iter().any(|predicate| predicate(row)))
+        }
+        FilterExpr::Not(expressions) => {
+            let predicates = compile_expressions(expressions, context);
+            Box::new(move |row| !predicates.iter().all(|predicate| predicate(row)))
+        }
+        FilterExpr::Quantified(quantified) => {
+            let path = FieldPath::new(&quantified.field);
+            let predicate = compile_quantified(quantified, context);
+            Box::new(move |row| predicate(&row.value(&path)))
+        }
+    }
+}
+
+/// The test on the array in the field, each item is read as a row by the child expressions.
+/// A field that is not an array has no items.
+pub fn compile_quantified(quantified: &Quantified, context: &FilterContext) -> ValuePredicate {
+    let predicates = compile_expressions(&quantified.expressions, context);
+    let quantifier = quantified.quantifier;
+
+    Box::new(move |value| {
+        let items = value.as_array().map(Vec::as_slice).unwrap_or_default();
+        quantifier.test(items, |item| predicates.iter().all(|predicate| predicate(item)))
+    })
+}
+
+fn compile_comparison(comparison: &Comparison, context: &FilterContext) -> Predicate {
+    let path = FieldPath::new(&comparison.field);
+
+    if let Some(value_field) = &comparison.value_field {
+        let other = FieldPath::new(value_field);
+        let predicate = compile_pair(comparison, &path, context);
+        return Box::new(move |row| predicate(&row.value(&path), &row.value(&other)));
+    }
+
+    let predicate = compile_field(comparison, &path, context);
+    Box::new(move |row| predicate(&row.value(&path)))
+}
+
+/// The test on the field against the value of the value field on the same row.
+/// The operator is worked out once, the row only hands over the two values.
+/// A projection passes when any of its values pass against the other value, or as the quantifier says.
+pub fn compile_pair(comparison: &Comparison, path: &FieldPath, context: &FilterContext) -> PairPredicate {
+    let predicate = compile_pair_values(comparison, context);
+
+    if !path.is_projection() {
+        return predicate;
+    }
+
+    let quantifier = comparison.quantifier.unwrap_or_default();
+
+    Box::new(move |value, other| match value.as_array() {
+        None => predicate(value, other),
+        Some(items) => quantifier.test(items, |item| predicate(item, other))
+    })
+}
+
+fn compile_pair_values(comparison: &Comparison, context: &FilterContext) -> PairPredicate {
+    if comparison.is_duration() {
+        return compile_duration_pair(comparison.operator);
+    }
+
+    if comparison.is_length() {
+        return compile_length_pair(comparison.operator);
+    }
+
+    if comparison.is_date() {
+        return compile_date_pair(comparison.operator, context.now);
+    }
+
+    let evaluate = get_evaluator(comparison.operator);
+
+    match context.case_sensitive {
+        true => Box::new(evaluate),
+        // only string values are compared without case, the same as a string in the intent
+        false => Box::new(move |value, other| match other {
+            Value::String(_) => evaluate(&fold_row(value), &fold_case(other)),
+            _ => evaluate(value, other)
+        })
+    }
+}
+
+/// The test on the value of the field.
+/// A projection such as "lines[*].qty" passes when any of the values pass, or as the quantifier of the comparison says.
+pub fn compile_field(comparison: &Comparison, path: &FieldPath, context: &FilterContext) -> ValuePredicate {
+    let predicate = compile_value(comparison, context);
+
+    if !path.is_projection() {
+        return predicate;
+    }
+
+    let quantifier = comparison.quantifier.unwrap_or_default();
+
+    Box::new(move |value| match value.as_array() {
+        None => predicate(value),
+        Some(items) => quantifier.test(items, &predicate)
+    })
+}
+
+fn compile_value(comparison: &Comparison, context: &FilterContext) -> ValuePredicate {
+    if let Some(predicate) = compile_typed(comparison, context) {
+        return predicate;
+    }
+
+    let evaluate = get_evaluator(comparison.operator);
+
+    if folds_case(comparison, context) {
+        let value = fold_case(&comparison.value);
+        return Box::new(move |row_value| evaluate(&fold_row(row_value), &value));
+    }
+
+    let value = match comparison.operator {
+        Operator::IsNull | Operator::NotNull | Operator::IsEmpty => Value::Null,
+        _ => comparison.value.clone()
+    };
+
+    Box::new(move |row_value| evaluate(row_value, &value))
+}
+
+/// Case insensitive comparisons on strings lowercase the value and the field before comparing.
+pub fn folds_case(comparison: &Comparison, context: &FilterContext) -> bool {
+    if context.case_sensitive {
+        return false;
+    }
+
+    match comparison.operator {
+        Operator::ContainsAny | Operator::ContainsAll => comparison.value.is_array(),
+        _ => comparison.value.is_string()
+    }
+}
+
+/// Strings in lowercase, also the strings in an array.
+fn fold_case(value: &Value) -> Value {
+    match value {
+        Value::String(value) => Value::from(value.to_lowercase()),
+        Value::Array(items) => Value::Array(items.iter().map(fold_case).collect()),
+        _ => value.clone()
+    }
+}
+
+/// Arrays on the row keep their items, other values that are not strings compare as an empty string.
+fn fold_row(value: &Value) -> Value {
+    match value {
+        Value::Array(_) => fold_case(value),
+        _ => Value::from(value.as_str().unwrap_or("").to_lowercase())
+    }
+}
+
+/// Comparisons on dates, durations or lengths compare parsed values and like or matches run a regex.
+/// None when the comparison is on the json values.
+pub fn compile_typed(comparison: &Comparison, context: &FilterContext) -> Option<ValuePredicate> {
+    if comparison.is_duration() {
+        return Some(compile_duration(comparison));
+    }
+
+    if comparison.is_length() {
+        return Some(compile_length(comparison));
+    }
+
+    if comparison.is_date() {
+        return Some(compile_date(comparison, context.now));
+    }
+
+    compile_pattern(comparison, context.case_sensitive, &context.patterns)
+}
+
+pub fn get_evaluator(operator: Operator) -> Evaluate {
+    match operator {
+        Operator::GreaterThan       => GreaterThan::evaluate,
+        Operator::GreaterOrEqual    => GreaterOrEqual::evaluate,
+        Operator::LessThan          => LessThan::evaluate,
+        Operator::LessOrEqual       => LessOrEqual::evaluate,
+        Operator::Equal             => Equal::evaluate,
+        Operator::NotEqual          => NotEqual::evaluate,
+        Operator::IsNull            => IsNull::evaluate,
+        Operator::NotNull           => IsNotNull::evaluate,
+        Operator::Like              => Like::evaluate,
+        Operator::NotLike           => NotLike::evaluate,
+
+        // regex operators are compiled by compile_pattern
+        Operator::Matches | Operator::NotMatches => |_, _| false,
+        Operator::In                => OneOf::evaluate,
+        Operator::Between           => Between::evaluate,
+        Operator::StartsWith        => StartsWith::evaluate,
+        Operator::EndsWith          => EndsWith::evaluate,
+        Operator::Contains          => Contains::evaluate,
+        Operator::ContainsAny       => ContainsAny::evaluate,
+        Operator::ContainsAll       => ContainsAll::evaluate,
+        Operator::IsEmpty           => IsEmpty::evaluate,
+
+        // date operators are compiled by compile_date, they don't have an evaluator on json values
+        Operator::Before | Operator::After | Operator::On |
+        Operator::WithinLast | Operator::WithinNext |
+        Operator::SameWeek | Operator::SameMonth | Operator::SameYear => |_, _| false,
+
+        // logical operators and quantifiers never end up in a comparison, the model builds them as expressions
+        Operator::And | Operator::Or | Operator::Not |
+        Operator::Any | Operator::All | Operator::None => |_, _| false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+    use serde_json::{json, Value};
+    use crate::evaluators::filter_plan::FilterPlan;
+    use crate::intent;
+    use crate::intent::FilterExpr;
+
+    fn compile(intent: Value, case_sensitive: bool) -> FilterPlan {
+        let intent: Vec<FilterExpr> = intent::parse(&intent, "filter").unwrap();
+        FilterPlan::compile(&intent, case_sensitive)
+    }
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "A", "value": 10, "isActive": true, "person": {"name": "John"}}));
+        result.push(json!({"id": 1, "code": "B", "value": 10, "isActive": false, "person": {"name": "John"}}));
+        result.push(json!({"id": 2, "code": "C", "value": 20, "isActive": true, "person": {"name": "Jane"}}));
+        result.push(json!({"id": 3, "code": "D", "value": 20, "isActive": true, "person": null}));
+        result.push(json!({"id": 4, "code": "E", "value": 5, "isActive": false}));
+        result
+    }
+
+    #[test]
+    fn nested_path_test() {
+        let data = vec![
+            json!({"order": {"code": "A"}, "lines": [{"qty": 1}, {"qty": 5}], "size.cm": 10}),
+            json!({"order": {"code": "B"}, "lines": [{"qty": 6}, {"qty": 7}], "size.cm": 20}),
+            json!({"order": {"code": "C"}, "lines": [], "size.cm": 30}),
+            json!({"order": {"code": "D"}})
+        ];
+
+        let plan = compile(json!([{ "field": "lines[0].qty", "operator": ">", "value": 2 }]), true);
+        assert_eq!(plan.run(&data), vec![1]);
+
+        let plan = compile(json!([{ "field": "lines[*].qty", "operator": ">", "value": 4 }]), true);
+        assert_eq!(plan.run(&data), vec![0, 1]);
+
+        let plan = compile(json!([{ "field": "lines[*].qty", "operator": ">", "value": 4, "quantifier": "all" }]), true);
+        assert_eq!(plan.run(&data), vec![1, 2]);
+
+        let plan = compile(json!([{ "field": "size\\.cm", "operator": ">=", "value": 20 }]), true);
+        assert_eq!(plan.run(&data), vec![1, 2]);
+
+        let plan = compile(json!([{ "field": "order.code", "operator": "==", "value": "d" }]), false);
+        assert_eq!(plan.run(&data), vec![3]);
+    }
+
+    #[test]
+    fn run_test() {
+        let plan = compile(json!([
+            { "operator": "or", "expressions": [
+                { "field": "code", "operator": "in", "value": ["A", "C"] },
+                { "field": "value", "operator": "<", "value": 10 }
+            ]},
+            { "operator": "not", "expressions": [{ "field": "person.name", "operator": "==", "value": "Jane" }] }
+        ]), true);
+
+        assert_eq!(plan.run(&get_data()), vec![0, 4]);
+    }
+
+    #[test]
+    fn case_insensitive_test() {
+        let plan = compile(json!([{ "field": "person.name", "operator": "==", "value": "JOHN" }]), false);
+        assert_eq!(plan.run(&get_data()), vec![0, 1]);
+
+        let plan = compile(json!([{ "field": "person.name", "operator": "==", "value": "JOHN" }]), true);
+        assert!(plan.run(&get_data()).is_empty());
+    }
+
+    #[test]
+    fn null_test() {
+        let plan = compile(json!([{ "field": "person.name", "operator": "is_null" }]), false);
+        assert_eq!(plan.run(&get_data()), vec![3, 4]);
+    }
+
+    #[test]
+    fn reuse_plan_test() {
+        let plan = compile(json!([{ "field": "value", "operator": "between", "value": [10, 20] }]), true);
+        let data = get_data();
+
+        assert_eq!(plan.run(&data), vec![0, 1, 2, 3]);
+        assert_eq!(plan.run(&data[2..]), vec![0, 1]);
+        assert!(!plan.matches(&data[4]));
+    }
+
+    #[test]
+    fn date_test() {
+        let now = NaiveDate::from_ymd_opt(2022, 3, 16).unwrap().and_hms_opt(12, 0, 0).unwrap();
+
+        let data = vec![
+            json!({"person": {"birthday": "2022/03/10"}}),
+            json!({"person": {"birthday": "2022-03-16T09:00:00"}}),
+            json!({"person": {"birthday": "2021/12/31"}}),
+            json!({"person": null})
+        ];
+
+        let intent: Vec<FilterExpr> = intent::parse(&json!([{ "field": "person.birthday", "operator": "within_last", "value": "P7D" }]), "filter").unwrap();
+        assert_eq!(FilterPlan::compile_at(&intent, false, now).run(&data), vec![0, 1]);
+
+        // case insensitive filters don't lowercase dates
+        let intent: Vec<FilterExpr> = intent::parse(&json!([{ "field": "person.birthday", "operator": ">", "value": "today", "type": "date" }]), "filter").unwrap();
+        assert_eq!(FilterPlan::compile_at(&intent, false, now).run(&data), vec![1]);
+    }
+
+    #[test]
+    fn field_pair_test() {
+        let now = NaiveDate::from_ymd_opt(2022, 3, 16).unwrap().and_hms_opt(12, 0, 0).unwrap();
+
+        let data = vec![
+            json!({"code": "WO-1", "prefix": "wo", "end": "2022/03/10", "span": "P7D", "due": "2022/03/12", "downtime": "PT90M", "target": "PT2H", "tags": ["a", "b"], "limit": 1, "qty": [1, 5], "max": 4}),
+            json!({"code": "PM-2", "prefix": "WO", "end": "2022/03/01", "span": "P1D", "due": "2022/02/28", "downtime": "PT3H", "target": "PT2H", "tags": [], "limit": 1, "qty": [1, 2], "max": 4}),
+            json!({"code": null, "prefix": null, "end": null, "span": null, "due": null, "downtime": null, "target": null, "tags": null, "limit": null, "qty": null, "max": null})
+        ];
+
+        let run = |intent: Value, case_sensitive: bool| {
+            let intent: Vec<FilterExpr> = intent::parse(&intent, "filter").unwrap();
+            FilterPlan::compile_at(&intent, case_sensitive, now).run(&data)
+        };
+
+        assert_eq!(run(json!([{ "field": "code", "operator": "starts_with", "value_field": "prefix" }]), false), vec![0]);
+        assert!(run(json!([{ "field": "code", "operator": "starts_with", "value_field": "prefix" }]), true).is_empty());
+        assert_eq!(run(json!([{ "field": "end", "operator": "within_last", "value_field": "span" }]), true), vec![0]);
+        assert_eq!(run(json!([{ "field": "end", "operator": "before", "value_field": "due" }]), true), vec![0]);
+        assert_eq!(run(json!([{ "field": "end", "operator": "same_month", "value_field": "due" }]), true), vec![0]);
+        assert_eq!(run(json!([{ "field": "downtime", "operator": ">", "value_field": "target", "type": "duration" }]), true), vec![1]);
+        assert_eq!(run(json!([{ "field": "tags", "operator": ">", "value_field": "limit", "type": "length" }]), true), vec![0]);
+        assert_eq!(run(json!([{ "field": "qty[*]", "operator": ">", "value_field": "max" }]), true), vec![0]);
+        assert_eq!(run(json!([{ "field": "qty[*]", "operator": "<", "value_field": "max", "quantifier": "all" }]), true), vec![1]);
+    }
+}
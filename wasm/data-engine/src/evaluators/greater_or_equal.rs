@@ -0,0 +1,41 @@
+use serde_json::Value;
+use crate::eval;
+use crate::traits::Eval;
+
+pub struct GreaterOrEqual {}
+
+impl Eval for GreaterOrEqual {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool {
+        eval!(obj1, >=, obj2)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::evaluators::GreaterOrEqual;
+    use serde_json::Value;
+    use crate::traits::Eval;
+
+    #[test]
+    fn test_positive() {
+        assert!(GreaterOrEqual::evaluate(&Value::from(20), &Value::from(10)));
+        assert!(GreaterOrEqual::evaluate(&Value::from(20.0), &Value::from(10.0)));
+        assert!(GreaterOrEqual::evaluate(&Value::from(true), &Value::from(false)));
+        assert!(GreaterOrEqual::evaluate(&Value::from("B"), &Value::from("A")));
+        assert!(GreaterOrEqual::evaluate(&Value::from("100"), &Value::from("001")));
+    }
+
+    #[test]
+    fn test_negative() {
+        assert!(!GreaterOrEqual::evaluate(&Value::from(10), &Value::from(20)));
+        assert!(!GreaterOrEqual::evaluate(&Value::from(10.0), &Value::from(20.0)));
+        assert!(!GreaterOrEqual::evaluate(&Value::from(false), &Value::from(true)));
+        assert!(!GreaterOrEqual::evaluate(&Value::from("A"), &Value::from("B"), ));
+        assert!(!GreaterOrEqual::evaluate(&Value::from("001"), &Value::from("100")));
+    }
+
+    #[test]
+    fn test_equals() {
+        assert!(GreaterOrEqual::evaluate(&Value::from(10), &Value::from(10)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use serde_json::Value;
+use crate::traits::Eval;
+
+/// Null, an empty string, an empty array and an empty object are all empty.
+pub struct IsEmpty {}
+
+impl Eval for IsEmpty {
+    fn evaluate(obj1: &Value, _obj2: &Value) -> bool {
+        match obj1 {
+            Value::Null => true,
+            Value::String(value) => value.is_empty(),
+            Value::Array(items) => items.is_empty(),
+            Value::Object(properties) => properties.is_empty(),
+            _ => false
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use serde_json::Value::Null;
+    use crate::traits::Eval;
+    use crate::evaluators::IsEmpty;
+
+    #[test]
+    fn is_empty_test() {
+        assert!(IsEmpty::evaluate(&Null, &Null));
+        assert!(IsEmpty::evaluate(&json!(""), &Null));
+        assert!(IsEmpty::evaluate(&json!([]), &Null));
+        assert!(IsEmpty::evaluate(&json!({}), &Null));
+        assert!(!IsEmpty::evaluate(&json!([null]), &Null));
+        assert!(!IsEmpty::evaluate(&Value::from(0), &Null));
+    }
+}
@@ -5,7 +5,7 @@ pub struct IsNotNull {}
 
 impl Eval for IsNotNull {
     fn evaluate(obj1: &Value, _obj2: &Value) -> bool {
-        obj1.is_null() == false
+        !obj1.is_null()
     }
 }
 
@@ -18,7 +18,7 @@ mod test {
 
     #[test]
     fn is_null_test() {
-        assert_eq!(IsNotNull::evaluate(&Null, &Null), false);
-        assert_eq!(IsNotNull::evaluate(&Value::from(10), &Null), true);
+        assert!(!IsNotNull::evaluate(&Null, &Null));
+        assert!(IsNotNull::evaluate(&Value::from(10), &Null));
     }
 }
@@ -5,7 +5,7 @@ pub struct IsNull {}
 
 impl Eval for IsNull {
     fn evaluate(obj1: &Value, _obj2: &Value) -> bool {
-        obj1.is_null() == true
+        obj1.is_null()
     }
 }
 
@@ -18,7 +18,7 @@ mod test {
 
     #[test]
     fn is_null_test() {
-        assert_eq!(IsNull::evaluate(&Null, &Null), true);
-        assert_eq!(IsNull::evaluate(&Value::from(10), &Null), false);
+        assert!(IsNull::evaluate(&Null, &Null));
+        assert!(!IsNull::evaluate(&Value::from(10), &Null));
     }
 }
@@ -0,0 +1,86 @@
+use serde_json::Value;
+use crate::evaluators::{get_evaluator, PairPredicate, ValuePredicate};
+use crate::intent::{Comparison, Operator};
+
+/// A comparison on the length of the field, so "tags" with three items is more than 2.
+/// Fields that don't have a length such as numbers never match.
+pub fn compile_length(comparison: &Comparison) -> ValuePredicate {
+    let evaluate = get_evaluator(comparison.operator);
+    let value = comparison.value.clone();
+
+    Box::new(move |cell| match length(cell) {
+        Some(length) => evaluate(&Value::from(length), &value),
+        None => false
+    })
+}
+
+/// A comparison of the length of the field with the number in another field of the row.
+/// The other field must hold a number, the same as the value of a length comparison.
+pub fn compile_length_pair(operator: Operator) -> PairPredicate {
+    let evaluate = get_evaluator(operator);
+
+    Box::new(move |cell, other| match length(cell) {
+        Some(length) if other.is_number() => evaluate(&Value::from(length), other),
+        _ => false
+    })
+}
+
+/// The number of items in an array, characters in a string or properties in an object.
+/// A null or missing field has no items.
+pub fn length(value: &Value) -> Option<usize> {
+    match value {
+        Value::Null => Some(0),
+        Value::String(value) => Some(value.chars().count()),
+        Value::Array(items) => Some(items.len()),
+        Value::Object(properties) => Some(properties.len()),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::evaluators::length::compile_length;
+    use crate::evaluators::evaluate_object;
+    use crate::intent;
+    use crate::intent::FilterExpr;
+
+    fn get_data() -> Vec<Value> {
+        vec![
+            json!(["a", "b", "c"]),
+            json!([]),
+            json!("héllo"),
+            json!(null),
+            json!({"a": 1}),
+            json!(10)
+        ]
+    }
+
+    fn run(intent: Value) -> Vec<usize> {
+        let comparison = match intent::parse(&intent, "filter").unwrap() {
+            FilterExpr::Comparison(comparison) => comparison,
+            _ => panic!("expected a comparison")
+        };
+
+        let predicate = compile_length(&comparison);
+        get_data().iter().enumerate().filter(|(_, cell)| predicate(cell)).map(|(index, _)| index).collect()
+    }
+
+    #[test]
+    fn compare_test() {
+        assert_eq!(run(json!({ "field": "tags", "operator": ">", "value": 1, "type": "length" })), vec![0, 2]);
+        assert_eq!(run(json!({ "field": "tags", "operator": "==", "value": 0, "type": "length" })), vec![1, 3]);
+        assert_eq!(run(json!({ "field": "tags", "operator": "!=", "value": 0, "type": "length" })), vec![0, 2, 4]);
+        assert_eq!(run(json!({ "field": "tags", "operator": "between", "value": [1, 3], "type": "length" })), vec![0, 4]);
+        assert_eq!(run(json!({ "field": "tags", "operator": "in", "value": [5], "type": "length" })), vec![2]);
+    }
+
+    #[test]
+    fn evaluate_object_test() {
+        let intent = json!({ "field": "tags", "operator": ">=", "value": 2, "type": "length" });
+
+        assert!(evaluate_object(&intent, &json!({ "tags": ["a", "b"] }), false).unwrap());
+        assert!(!evaluate_object(&intent, &json!({ "tags": ["a"] }), false).unwrap());
+        assert!(!evaluate_object(&intent, &json!({}), false).unwrap());
+    }
+}
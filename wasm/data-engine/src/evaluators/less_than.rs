@@ -0,0 +1,36 @@
+use serde_json::Value;
+use crate::eval;
+use crate::traits::Eval;
+
+pub struct LessThan {}
+
+impl Eval for LessThan {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool {
+        eval!(obj1, <, obj2)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::evaluators::LessThan;
+    use serde_json::Value;
+    use crate::traits::Eval;
+
+    #[test]
+    fn test_positive() {
+        assert!(!LessThan::evaluate(&Value::from(20), &Value::from(10)));
+        assert!(!LessThan::evaluate(&Value::from(20.0), &Value::from(10.0)));
+        assert!(!LessThan::evaluate(&Value::from(true), &Value::from(false)));
+        assert!(!LessThan::evaluate(&Value::from("B"), &Value::from("A")));
+        assert!(!LessThan::evaluate(&Value::from("100"), &Value::from("001")));
+    }
+
+    #[test]
+    fn test_negative() {
+        assert!(LessThan::evaluate(&Value::from(10), &Value::from(20)));
+        assert!(LessThan::evaluate(&Value::from(10.0), &Value::from(20.0)));
+        assert!(LessThan::evaluate(&Value::from(false), &Value::from(true)));
+        assert!(LessThan::evaluate(&Value::from("A"), &Value::from("B"), ));
+        assert!(LessThan::evaluate(&Value::from("001"), &Value::from("100")));
+    }
+}
\ No newline at end of file
@@ -7,7 +7,10 @@ impl Eval for Like {
     fn evaluate(obj1: &Value, obj2: &Value) -> bool {
         if obj1.is_string() {
             let s1 = obj1.as_str().unwrap();
-            let s2 = obj2.as_str().unwrap();
+            let s2 = match obj2.as_str() {
+                None => return false,
+                Some(value) => value
+            };
             let result = s1.find(s2);
 
             return match result {
@@ -28,7 +31,7 @@ mod test {
 
     #[test]
     fn like_test() {
-        assert_eq!(Like::evaluate(&Value::from("Hello World"), &Value::from("World")), true);
-        assert_eq!(Like::evaluate(&Value::from("Hello World"), &Value::from("Test")), false);
+        assert!(Like::evaluate(&Value::from("Hello World"), &Value::from("World")));
+        assert!(!Like::evaluate(&Value::from("Hello World"), &Value::from("Test")));
     }
 }
\ No newline at end of file
@@ -0,0 +1,36 @@
+use serde_json::Value;
+use crate::eval;
+use crate::traits::Eval;
+
+pub struct NotEqual {}
+
+impl Eval for NotEqual {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool {
+        eval!(obj1, !=, obj2)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::evaluators::NotEqual;
+    use serde_json::Value;
+    use crate::traits::Eval;
+
+    #[test]
+    fn test_positive() {
+        assert!(!NotEqual::evaluate(&Value::from(20), &Value::from(20)));
+        assert!(!NotEqual::evaluate(&Value::from(20.0), &Value::from(20.0)));
+        assert!(!NotEqual::evaluate(&Value::from(true), &Value::from(true)));
+        assert!(!NotEqual::evaluate(&Value::from("B"), &Value::from("B")));
+        assert!(!NotEqual::evaluate(&Value::from("100"), &Value::from("100")));
+    }
+
+    #[test]
+    fn test_negative() {
+        assert!(NotEqual::evaluate(&Value::from(10), &Value::from(20)));
+        assert!(NotEqual::evaluate(&Value::from(10.0), &Value::from(20.0)));
+        assert!(NotEqual::evaluate(&Value::from(false), &Value::from(true)));
+        assert!(NotEqual::evaluate(&Value::from("A"), &Value::from("B"), ));
+        assert!(NotEqual::evaluate(&Value::from("001"), &Value::from("100")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,406 @@
+use serde_json::Value;
+use crate::error::DataResult;
+use crate::evaluators::FilterPlan;
+use crate::intent;
+use crate::intent::FilterExpr;
+
+/**
+ * Evaluates an object based on the specified operator and returns a boolean indicating
+ * whether the evaluation is true or false.
+ *
+ * # Parameters
+ * - `intent`: A reference to a Value object that contains the operator and expressions to be evaluated.
+ * - `row`: A reference to a Value object that contains the data to be evaluated against.
+ * - `case_sensitive`: A boolean flag indicating whether the evaluation should be case-sensitive or not.
+ *
+ * # Returns
+ * A boolean indicating whether the evaluation is true or false.
+ * If the intent is malformed an error is returned with the path relative to the intent.
+ */
+pub fn evaluate_object(intent: &Value, row: &Value, case_sensitive: bool) -> DataResult<bool> {
+    let expression: FilterExpr = intent::parse(intent, "")?;
+    let plan = FilterPlan::compile(&[expression], case_sensitive);
+    Ok(plan.matches(row))
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use serde_json::Value::Null;
+    use crate::evaluators::evaluate_object;
+    use crate::path::FieldPath;
+
+    fn create_filter(field: &str, operator: &str, value: Value) -> Value {
+        json!({
+            "field": field,
+            "operator": operator,
+            "value": value
+        })
+    }
+
+    #[test]
+    fn evaluate_equal_test() {
+        let filter = create_filter("value", "=", Value::from(10));
+        let row = json!({"value": 10});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+    }
+
+    #[test]
+    fn evaluate_notequal_test() {
+        let filter = create_filter("value", "<>", Value::from(20));
+        let row = json!({"value": 10});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+    }
+
+    #[test]
+    fn evaluate_greater_than_test() {
+        let filter = create_filter("value", ">", Value::from(10));
+        let row = json!({"value": 20});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+    }
+
+    #[test]
+    fn evaluate_greater_or_equal_test() {
+        let filter = create_filter("value", ">=", Value::from(10));
+        let row = json!({"value": 10});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": 11});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": 9});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_less_than_test() {
+        let filter = create_filter("value", "<", Value::from(20));
+        let row = json!({"value": 10});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+    }
+
+    #[test]
+    fn evaluate_less_or_equal_test() {
+        let filter = create_filter("value", "<=", Value::from(20));
+        let row = json!({"value": 20});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": 19});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": 21});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_is_null_test() {
+        let filter = create_filter("value", "is_null", Null);
+        assert_eq!(evaluate_object(&filter, &Null, true), Ok(true));
+
+        let row = json!({"value": 21});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_is_not_null_test() {
+        let filter = create_filter("value", "not_null", Null);
+        let row = json!({"value": 21});
+
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+        assert_eq!(evaluate_object(&filter, &Null, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_is_like_test() {
+        let filter = create_filter("value", "like", Value::from("hello"));
+        let row = json!({"value": "hello world"});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": "test string"});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_not_like_test() {
+        let filter = create_filter("value", "not_like", Value::from("test"));
+        let row = json!({"value": "hello world"});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": "test string"});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_in_test() {
+        let filter = create_filter("value", "in", json!([1, 2, 3]));
+        let row = json!({"value": 1});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": 5});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_between_test() {
+        let filter = create_filter("value", "between", json!([1, 3]));
+        let row = json!({"value": 1});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": 5});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_simple_not_test() {
+        // !(value == 1)
+        let filter = json!({
+            "operator": "not",
+            "expressions": [
+                create_filter("value", "eq", Value::from(1)),
+            ]
+        });
+
+        let row = json!({ "value": 1});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+
+        let row = json!({ "value": 2});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+    }
+
+    #[test]
+    fn evaluate_simple_and_test() {
+        // value == 1 && value2 == 2
+        let filter = json!({
+            "operator": "and",
+            "expressions": [
+                create_filter("value", "eq", Value::from(1)),
+                create_filter("value2", "eq", Value::from(2)),
+            ]
+        });
+
+        let row = json!({ "value": 1, "value2": 2 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({ "value": 1, "value2": 3 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_simple_or_test() {
+        // value == 1 || value2 == 2
+        let filter = json!({
+            "operator": "or",
+            "expressions": [
+                create_filter("value", "eq", Value::from(1)),
+                create_filter("value2", "eq", Value::from(2)),
+            ]
+        });
+
+        let row = json!({ "value": 1, "value2": 3 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({ "value": 3, "value2": 2 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({ "value": 3, "value2": 3 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_composite_and_or_test() {
+        // (value == 1 or value == 2) and (value2 == 3)
+        let filter = json!({
+            "operator": "and",
+            "expressions": [
+                {
+                    "operator": "or",
+                    "expressions": [
+                        create_filter("value", "eq", Value::from(1)),
+                        create_filter("value", "eq", Value::from(2)),
+                    ]
+                },
+                create_filter("value2", "eq", Value::from(3)),
+            ]
+        });
+
+        let row = json!({ "value": 1, "value2": 3 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({ "value": 2, "value2": 3 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({ "value": 3, "value2": 3 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+
+        let row = json!({ "value": 1, "value2": 1 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+
+        let row = json!({ "value": 2, "value2": 1 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_composite_not_and_or_test() {
+        // !((value == 1 or value == 2) and (value2 == 3))
+        let filter = json!(
+            {
+                "operator": "not",
+                "expressions": [
+                    {
+                        "operator": "and",
+                        "expressions": [
+                            {
+                                "operator": "or",
+                                "expressions": [
+                                    create_filter("value", "eq", Value::from(1)),
+                                    create_filter("value", "eq", Value::from(2)),
+                                ]
+                            },
+                            create_filter("value2", "eq", Value::from(3)),
+                        ]
+                    }
+                ]
+            }
+        );
+
+        let row = json!({ "value": 1, "value2": 3 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+
+        let row = json!({ "value": 2, "value2": 3 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+
+        let row = json!({ "value": 3, "value2": 3 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({ "value": 1, "value2": 1 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({ "value": 2, "value2": 1 });
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+    }
+
+    #[test]
+    fn evaluate_startswith_test() {
+        let filter = create_filter("value", "startswith", Value::from("Hello"));
+        let row = json!({"value": "Hello World"});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": "Not Hello World"});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_contains_test() {
+        let filter = create_filter("value", "contains", Value::from("lo wo"));
+        let row = json!({"value": "Hello World"});
+        assert_eq!(evaluate_object(&filter, &row, false), Ok(true));
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_endswith_test() {
+        let filter = create_filter("value", "endswith", Value::from("World"));
+        let row = json!({"value": "Hello World"});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+
+        let row = json!({"value": "Hello World Not"});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn evaluate_case_sensitive_test() {
+        let filter = create_filter("value", "eq", Value::from("a"));
+        let row = json!({"value": "A"});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+    }
+
+    #[test]
+    fn filter_on_path_test() {
+        let row = json!({"value": "A", "person": {"name": "john"}});
+        let v = FieldPath::new("person.name").get(&row);
+        assert_eq!(v.as_ref(), "john");
+    }
+
+    #[test]
+    fn get_value_on_path_test() {
+        let filter = create_filter("person.name", "eq", Value::from("john"));
+        let row = json!({"value": "A", "person": {"name": "john"}});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(true));
+    }
+
+    #[test]
+    fn evaluate_array_test() {
+        let row = json!({"tags": ["Red", "green"], "users": [1, 2], "notes": [], "code": "A"});
+
+        assert_eq!(evaluate_object(&create_filter("tags", "contains", Value::from("green")), &row, true), Ok(true));
+        assert_eq!(evaluate_object(&create_filter("tags", "contains", Value::from("red")), &row, true), Ok(false));
+        assert_eq!(evaluate_object(&create_filter("tags", "contains", Value::from("red")), &row, false), Ok(true));
+        assert_eq!(evaluate_object(&create_filter("users", "contains", Value::from(2)), &row, true), Ok(true));
+
+        assert_eq!(evaluate_object(&create_filter("users", "contains_any", json!([3, 1])), &row, true), Ok(true));
+        assert_eq!(evaluate_object(&create_filter("users", "contains_all", json!([3, 1])), &row, true), Ok(false));
+        assert_eq!(evaluate_object(&create_filter("tags", "contains_all", json!(["GREEN", "red"])), &row, false), Ok(true));
+        assert_eq!(evaluate_object(&create_filter("code", "contains_any", json!(["A", "B"])), &row, true), Ok(true));
+
+        assert_eq!(evaluate_object(&json!({"field": "notes", "operator": "is_empty"}), &row, true), Ok(true));
+        assert_eq!(evaluate_object(&json!({"field": "missing", "operator": "is_empty"}), &row, true), Ok(true));
+        assert_eq!(evaluate_object(&json!({"field": "tags", "operator": "is_empty"}), &row, true), Ok(false));
+        assert_eq!(evaluate_object(&json!({"field": "tags", "operator": "==", "value": 2, "type": "length"}), &row, true), Ok(true));
+    }
+
+    #[test]
+    fn evaluate_quantified_test() {
+        let row = json!({"lines": [{"qty": 2, "code": "A"}, {"qty": 8, "code": "B"}], "tags": ["a", "b"]});
+
+        let filter = |operator: &str, expressions: Value| json!({"field": "lines", "operator": operator, "expressions": expressions});
+        let large = json!([{"field": "qty", "operator": ">", "value": 5}]);
+        let positive = json!([{"field": "qty", "operator": ">", "value": 0}]);
+
+        assert_eq!(evaluate_object(&filter("any", large.clone()), &row, true), Ok(true));
+        assert_eq!(evaluate_object(&filter("all", large.clone()), &row, true), Ok(false));
+        assert_eq!(evaluate_object(&filter("all", positive.clone()), &row, true), Ok(true));
+        assert_eq!(evaluate_object(&filter("none", large), &row, true), Ok(false));
+        assert_eq!(evaluate_object(&filter("none", positive), &row, true), Ok(false));
+
+        // all the expressions must pass on the same item
+        let same_item = json!([{"field": "qty", "operator": ">", "value": 5}, {"field": "code", "operator": "==", "value": "A"}]);
+        assert_eq!(evaluate_object(&filter("any", same_item), &row, true), Ok(false));
+
+        // an empty field is the item itself
+        let intent = json!({"field": "tags", "operator": "any", "expressions": [{"field": "", "operator": "==", "value": "B"}]});
+        assert_eq!(evaluate_object(&intent, &row, false), Ok(true));
+        assert_eq!(evaluate_object(&intent, &row, true), Ok(false));
+
+        let intent = json!({"field": "missing", "operator": "none", "expressions": [{"field": "", "operator": "is_null"}]});
+        assert_eq!(evaluate_object(&intent, &row, true), Ok(true));
+    }
+
+    #[test]
+    fn evaluate_field_reference_test() {
+        let row = json!({
+            "actual_hours": 12, "planned_hours": 10.5,
+            "end_date": "2024/03/01", "due_date": "2024-02-28T10:00:00",
+            "downtime": "PT90M", "target": {"downtime": "PT2H"},
+            "code": "wo-1", "expected": "WO-1",
+            "tags": ["a", "b"], "tag": "b"
+        });
+
+        let compare = |field: &str, operator: &str, value_field: &str| json!({"field": field, "operator": operator, "value_field": value_field});
+
+        assert_eq!(evaluate_object(&compare("actual_hours", ">", "planned_hours"), &row, true), Ok(true));
+        assert_eq!(evaluate_object(&compare("planned_hours", ">=", "actual_hours"), &row, true), Ok(false));
+        assert_eq!(evaluate_object(&compare("end_date", "after", "due_date"), &row, true), Ok(true));
+        assert_eq!(evaluate_object(&json!({"field": "end_date", "operator": "<", "value_field": "due_date", "type": "date"}), &row, true), Ok(false));
+        assert_eq!(evaluate_object(&json!({"field": "downtime", "operator": "<", "value_field": "target.downtime", "type": "duration"}), &row, true), Ok(true));
+        assert_eq!(evaluate_object(&compare("code", "==", "expected"), &row, true), Ok(false));
+        assert_eq!(evaluate_object(&compare("code", "==", "expected"), &row, false), Ok(true));
+        assert_eq!(evaluate_object(&compare("tags", "contains", "tag"), &row, true), Ok(true));
+
+        let filter = json!({"field": "actual_hours", "operator": "<", "value": {"$field": "planned_hours"}});
+        assert_eq!(evaluate_object(&filter, &row, true), Ok(false));
+
+        // a missing field is null, relative date operators don't read it as now
+        assert_eq!(evaluate_object(&compare("end_date", "same_year", "missing"), &row, true), Ok(false));
+        assert_eq!(evaluate_object(&compare("missing", "==", "other"), &row, true), Ok(false));
+    }
+}
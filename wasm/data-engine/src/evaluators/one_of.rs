@@ -5,7 +5,7 @@ pub struct OneOf {}
 
 impl Eval for OneOf {
     fn evaluate(obj1: &Value, obj2: &Value) -> bool {
-        if obj2.is_array() == false {
+        if !obj2.is_array() {
             return false;
         }
 
@@ -15,7 +15,7 @@ impl Eval for OneOf {
             }
         }
 
-        return false
+        false
     }
 }
 
@@ -28,7 +28,7 @@ mod test {
     #[test]
     fn is_null_test() {
         let values = json!(["abc", "def", "ghi"]);
-        assert_eq!(OneOf::evaluate(&Value::from("abc"), &values), true);
-        assert_eq!(OneOf::evaluate(&Value::from("not"), &values), false);
+        assert!(OneOf::evaluate(&Value::from("abc"), &values));
+        assert!(!OneOf::evaluate(&Value::from("not"), &values));
     }
 }
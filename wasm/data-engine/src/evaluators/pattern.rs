@@ -0,0 +1,183 @@
+use std::cell::RefCell;
+use hashbrown::HashMap;
+use regex::{Regex, RegexBuilder};
+use serde_json::Value;
+use crate::evaluators::ValuePredicate;
+use crate::intent::{Comparison, Operator};
+
+pub const REGEX_FLAGS: [char; 4] = ['i', 'm', 's', 'x'];
+
+/// Patterns compiled while compiling a filter intent.
+/// Comparisons in the intent that use the same pattern share the compiled regex.
+#[derive(Default)]
+pub struct PatternCache {
+    patterns: RefCell<HashMap<(String, String, bool), Option<Regex>>>
+}
+
+impl PatternCache {
+    /// The regex for the pattern, None when the pattern or flags are not valid.
+    pub fn get(&self, pattern: &str, flags: &str, case_sensitive: bool) -> Option<Regex> {
+        let key = (pattern.to_string(), flags.to_string(), case_sensitive);
+
+        self.patterns.borrow_mut()
+            .entry(key)
+            .or_insert_with(|| build_regex(pattern, flags, case_sensitive).ok())
+            .clone()
+    }
+}
+
+/// Like and matches comparisons run a regex on the field, None for the other operators.
+/// Values that are not strings never match.
+pub fn compile_pattern(comparison: &Comparison, case_sensitive: bool, cache: &PatternCache) -> Option<ValuePredicate> {
+    let pattern = comparison.value.as_str().unwrap_or_default();
+    let flags = comparison.flags.as_deref().unwrap_or_default();
+
+    let (regex, expected) = match comparison.operator {
+        Operator::Like          => (cache.get(&like_to_regex(pattern), "", case_sensitive), true),
+        Operator::NotLike       => (cache.get(&like_to_regex(pattern), "", case_sensitive), false),
+        Operator::Matches       => (cache.get(pattern, flags, case_sensitive), true),
+        Operator::NotMatches    => (cache.get(pattern, flags, case_sensitive), false),
+        _ => return None
+    };
+
+    let result: ValuePredicate = match regex {
+        None => Box::new(|_| false),
+        Some(regex) => Box::new(move |cell| match cell {
+            Value::String(text) => regex.is_match(text) == expected,
+            _ => false
+        })
+    };
+
+    Some(result)
+}
+
+/// Build the regex for a matches comparison.
+/// Flags are the letters i, m, s and x, the same as the inline regex flags.
+pub fn build_regex(pattern: &str, flags: &str, case_sensitive: bool) -> Result<Regex, String> {
+    let mut builder = RegexBuilder::new(pattern);
+    builder.case_insensitive(!case_sensitive);
+
+    for flag in flags.chars() {
+        match flag {
+            'i' => builder.case_insensitive(true),
+            'm' => builder.multi_line(true),
+            's' => builder.dot_matches_new_line(true),
+            'x' => builder.ignore_whitespace(true),
+            _ => return Err(format!("unknown regex flag '{}', expected one of i, m, s, x", flag))
+        };
+    }
+
+    builder.build().map_err(|error| error.to_string())
+}
+
+/// Turn a SQL like pattern into a regex, % is any run of characters and _ is a single character.
+/// A backslash escapes the next character so "\%" matches a percent sign.
+/// A pattern without wildcards matches anywhere in the value, the way like has always worked.
+pub fn like_to_regex(pattern: &str) -> String {
+    let mut result = String::new();
+    let mut wildcards = false;
+    let mut chars = pattern.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '%' => {
+                wildcards = true;
+                result.push_str(".*");
+            }
+            '_' => {
+                wildcards = true;
+                result.push('.');
+            }
+            '\\' => {
+                let escaped = chars.next().unwrap_or('\\');
+                result.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4])));
+            }
+            _ => result.push_str(&regex::escape(char.encode_utf8(&mut [0; 4])))
+        }
+    }
+
+    match wildcards {
+        true => format!("(?s)^{}$", result),
+        false => result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::evaluators::pattern::{build_regex, compile_pattern, like_to_regex, PatternCache};
+    use crate::intent;
+    use crate::intent::FilterExpr;
+
+    fn get_data() -> Vec<Value> {
+        vec![
+            json!("WO-1001-2024"),
+            json!("wo-17-2024"),
+            json!("WO-1001-2023"),
+            json!("PO-1001-2024"),
+            json!("50% off"),
+            json!("WO_2024"),
+            json!(2024),
+            json!(null)
+        ]
+    }
+
+    fn run(intent: Value, case_sensitive: bool) -> Vec<usize> {
+        let comparison = match intent::parse(&intent, "filter").unwrap() {
+            FilterExpr::Comparison(comparison) => comparison,
+            _ => panic!("expected a comparison")
+        };
+
+        let predicate = compile_pattern(&comparison, case_sensitive, &PatternCache::default()).unwrap();
+        get_data().iter().enumerate().filter(|(_, cell)| predicate(cell)).map(|(index, _)| index).collect()
+    }
+
+    #[test]
+    fn like_to_regex_test() {
+        assert_eq!(like_to_regex("WO-%-2024"), "(?s)^WO\\-.*\\-2024$");
+        assert_eq!(like_to_regex("A_C"), "(?s)^A.C$");
+        assert_eq!(like_to_regex("50\\%"), "50%");
+        assert_eq!(like_to_regex("a.b"), "a\\.b");
+        assert_eq!(like_to_regex("end\\"), "end\\\\");
+    }
+
+    #[test]
+    fn like_test() {
+        assert_eq!(run(json!({ "field": "code", "operator": "like", "value": "WO-%-2024" }), true), vec![0]);
+        assert_eq!(run(json!({ "field": "code", "operator": "like", "value": "WO-%-2024" }), false), vec![0, 1]);
+        assert_eq!(run(json!({ "field": "code", "operator": "like", "value": "_O-1001-%" }), true), vec![0, 2, 3]);
+        assert_eq!(run(json!({ "field": "code", "operator": "like", "value": "%\\%%" }), true), vec![4]);
+        assert_eq!(run(json!({ "field": "code", "operator": "like", "value": "WO\\_%" }), true), vec![5]);
+        assert_eq!(run(json!({ "field": "code", "operator": "like", "value": "1001" }), true), vec![0, 2, 3]);
+        assert_eq!(run(json!({ "field": "code", "operator": "not_like", "value": "%-2024" }), true), vec![2, 4, 5]);
+    }
+
+    #[test]
+    fn matches_test() {
+        assert_eq!(run(json!({ "field": "code", "operator": "matches", "value": "^WO-\\d+-2024$" }), true), vec![0]);
+        assert_eq!(run(json!({ "field": "code", "operator": "matches", "value": "^WO-\\d+-2024$", "flags": "i" }), true), vec![0, 1]);
+        assert_eq!(run(json!({ "field": "code", "operator": "matches", "value": "^WO-\\d+-2024$" }), false), vec![0, 1]);
+        assert_eq!(run(json!({ "field": "code", "operator": "not_matches", "value": "\\d{4}$" }), true), vec![4]);
+    }
+
+    #[test]
+    fn cache_test() {
+        let cache = PatternCache::default();
+
+        assert!(cache.get("^a+$", "", true).is_some());
+        assert!(cache.get("^a+$", "i", true).is_some());
+        assert!(cache.get("(a", "", true).is_none());
+        assert_eq!(cache.patterns.borrow().len(), 3);
+
+        cache.get("^a+$", "", true);
+        assert_eq!(cache.patterns.borrow().len(), 3);
+    }
+
+    #[test]
+    fn build_regex_test() {
+        assert!(build_regex("^a.b$", "s", true).unwrap().is_match("a\nb"));
+        assert!(build_regex("^b$", "m", true).unwrap().is_match("a\nb"));
+        assert_eq!(build_regex("a", "g", true).unwrap_err(), "unknown regex flag 'g', expected one of i, m, s, x");
+        assert!(build_regex("(a", "", true).is_err());
+    }
+}
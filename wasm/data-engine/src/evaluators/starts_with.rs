@@ -7,7 +7,10 @@ impl Eval for StartsWith {
     fn evaluate(obj1: &Value, obj2: &Value) -> bool {
         if obj1.is_string() {
             let s1 = obj1.as_str().unwrap();
-            let s2 = obj2.as_str().unwrap();
+            let s2 = match obj2.as_str() {
+                None => return false,
+                Some(value) => value
+            };
             let result = s1.find(s2);
 
             return match result {
@@ -21,7 +24,7 @@ impl Eval for StartsWith {
             }
         }
 
-        return false;
+        false
     }
 }
 
@@ -34,8 +37,8 @@ mod test {
     #[test]
     fn starts_with_test() {
         let values = Value::from("Hello World");
-        assert_eq!(StartsWith::evaluate(&values, &Value::from("Hello")), true);
-        assert_eq!(StartsWith::evaluate(&values, &Value::from("World")), false);
-        assert_eq!(StartsWith::evaluate(&values, &Value::from("Not")), false);
+        assert!(StartsWith::evaluate(&values, &Value::from("Hello")));
+        assert!(!StartsWith::evaluate(&values, &Value::from("World")));
+        assert!(!StartsWith::evaluate(&values, &Value::from("Not")));
     }
 }
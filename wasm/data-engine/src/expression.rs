@@ -0,0 +1,216 @@
+mod parser;
+mod functions;
+mod computed;
+
+use std::cmp::Ordering;
+use serde_json::Value;
+use crate::compare::compare_values;
+use crate::error::DataResult;
+use crate::path::FieldPath;
+use crate::row::Row;
+
+pub use functions::Function;
+pub use computed::{Computed, ComputedRow};
+
+/// A value derived from the fields of a row, for example "qty * price" or "concat(first, ' ', last)".
+/// The text is parsed once, evaluating it on a row only walks the tree.
+///
+/// Fields are written as field paths, names with spaces go between backticks: `unit price`.
+/// Text is quoted with single or double quotes, true, false and null are literals.
+/// The operators are + - * / % for numbers, == != < <= > >= to compare and and, or, not (or && || !) for logic.
+///
+/// Expressions never fail on the data: arithmetic on null or text that is not a number gives null,
+/// so does dividing by zero. Numbers in strings are read as numbers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    root: Node
+}
+
+impl Expression {
+    /// Unknown functions, wrong argument counts and syntax errors are reported with their position.
+    pub fn parse(text: &str) -> DataResult<Expression> {
+        Ok(Expression {
+            root: parser::parse(text)?
+        })
+    }
+
+    pub fn evaluate<R: Row + ?Sized>(&self, row: &R) -> Value {
+        self.root.evaluate(row)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    And,
+    Or
+}
+
+/// The parsed expression tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Literal(Value),
+    Field(FieldPath),
+    Negate(Box<Node>),
+    Not(Box<Node>),
+    Binary(BinaryOperator, Box<Node>, Box<Node>),
+    Call(Function, Vec<Node>)
+}
+
+impl Node {
+    fn evaluate<R: Row + ?Sized>(&self, row: &R) -> Value {
+        match self {
+            Node::Literal(value) => value.clone(),
+            Node::Field(path) => row.value(path).into_owned(),
+            Node::Negate(node) => number(&node.evaluate(row)).map_or(Value::Null, |value| to_number(-value)),
+            Node::Not(node) => Value::Bool(!is_truthy(&node.evaluate(row))),
+            Node::Binary(BinaryOperator::And, left, right) => {
+                Value::Bool(is_truthy(&left.evaluate(row)) && is_truthy(&right.evaluate(row)))
+            }
+            Node::Binary(BinaryOperator::Or, left, right) => {
+                Value::Bool(is_truthy(&left.evaluate(row)) || is_truthy(&right.evaluate(row)))
+            }
+            Node::Binary(operator, left, right) => binary(*operator, &left.evaluate(row), &right.evaluate(row)),
+            Node::Call(function, arguments) => {
+                let values: Vec<Value> = arguments.iter().map(|argument| argument.evaluate(row)).collect();
+                function.call(&values)
+            }
+        }
+    }
+}
+
+fn binary(operator: BinaryOperator, left: &Value, right: &Value) -> Value {
+    let ordering = || match left.is_null() || right.is_null() {
+        true => None,
+        false => Some(compare_values(left, right))
+    };
+
+    match operator {
+        BinaryOperator::Equal           => Value::Bool(compare_values(left, right) == Ordering::Equal),
+        BinaryOperator::NotEqual        => Value::Bool(compare_values(left, right) != Ordering::Equal),
+        BinaryOperator::Less            => Value::Bool(ordering() == Some(Ordering::Less)),
+        BinaryOperator::LessOrEqual     => Value::Bool(ordering().is_some_and(Ordering::is_le)),
+        BinaryOperator::Greater         => Value::Bool(ordering() == Some(Ordering::Greater)),
+        BinaryOperator::GreaterOrEqual  => Value::Bool(ordering().is_some_and(Ordering::is_ge)),
+        _ => {
+            let (left, right) = match (number(left), number(right)) {
+                (Some(left), Some(right)) => (left, right),
+                _ => return Value::Null
+            };
+
+            let result = match operator {
+                BinaryOperator::Add         => left + right,
+                BinaryOperator::Subtract    => left - right,
+                BinaryOperator::Multiply    => left * right,
+                BinaryOperator::Divide      => left / right,
+                _                           => left % right
+            };
+
+            to_number(result)
+        }
+    }
+}
+
+/// The number in the value, text is read as a number when it is one.
+pub fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse::<f64>().ok().filter(|number| number.is_finite()),
+        _ => None
+    }
+}
+
+/// Whole numbers are given back as integers so "qty * 2" stays an integer,
+/// results that are not a number such as a division by zero are null.
+pub fn to_number(value: f64) -> Value {
+    const LARGEST_INTEGER: f64 = 9007199254740992.0;
+
+    if !value.is_finite() {
+        return Value::Null;
+    }
+
+    match value.fract() == 0.0 && value.abs() <= LARGEST_INTEGER {
+        true => Value::from(value as i64),
+        false => Value::from(value)
+    }
+}
+
+/// The value as text for concat, null is empty.
+pub fn to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        _ => value.to_string()
+    }
+}
+
+/// Null, false, zero, empty text and empty arrays are false, everything else is true.
+pub fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(value) => *value,
+        Value::Number(number) => number.as_f64().is_some_and(|number| number != 0.0),
+        Value::String(text) => !text.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::expression::Expression;
+
+    fn evaluate(text: &str, row: &Value) -> Value {
+        Expression::parse(text).unwrap().evaluate(row)
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let row = json!({"qty": 3, "price": 2.5, "discount": null, "code": "A", "text": "4"});
+
+        assert_eq!(evaluate("qty * price", &row), json!(7.5));
+        assert_eq!(evaluate("qty * 2 + 1", &row), json!(7));
+        assert_eq!(evaluate("qty / 2", &row), json!(1.5));
+        assert_eq!(evaluate("qty % 2", &row), json!(1));
+        assert_eq!(evaluate("-qty", &row), json!(-3));
+        assert_eq!(evaluate("text * 2", &row), json!(8));
+        assert_eq!(evaluate("qty - discount", &row), Value::Null);
+        assert_eq!(evaluate("qty / 0", &row), Value::Null);
+        assert_eq!(evaluate("code + 1", &row), Value::Null);
+        assert_eq!(evaluate("qty * coalesce(discount, 1)", &row), json!(3));
+    }
+
+    #[test]
+    fn logic_test() {
+        let row = json!({"qty": 3, "code": "A", "active": false, "tags": []});
+
+        assert_eq!(evaluate("qty > 2 and code == 'A'", &row), json!(true));
+        assert_eq!(evaluate("qty >= 4 || active", &row), json!(false));
+        assert_eq!(evaluate("!active && not tags", &row), json!(true));
+        assert_eq!(evaluate("missing < 1", &row), json!(false));
+        assert_eq!(evaluate("missing == null", &row), json!(true));
+        assert_eq!(evaluate("code != 'B'", &row), json!(true));
+        assert_eq!(evaluate("if(qty > 2, 'many', 'few')", &row), json!("many"));
+    }
+
+    #[test]
+    fn field_test() {
+        let row = json!({"person": {"first": "John", "last": "Doe"}, "unit price": 2, "lines": [{"qty": 1}, {"qty": 2}]});
+
+        assert_eq!(evaluate("concat(person.first, ' ', person.last)", &row), json!("John Doe"));
+        assert_eq!(evaluate("`unit price` * lines[1].qty", &row), json!(4));
+        assert_eq!(evaluate("length(lines[*].qty)", &row), json!(2));
+        assert_eq!(evaluate("concat()", &row), json!(""));
+    }
+}
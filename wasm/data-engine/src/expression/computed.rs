@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+use serde_json::Value;
+use crate::error::DataResult;
+use crate::expression::Expression;
+use crate::intent::ComputedField;
+use crate::path::{resolve, FieldPath, Segment};
+use crate::row::Row;
+
+/// The computed fields of a perspective, parsed once for all the rows.
+/// A computed field can use the computed fields defined before it.
+pub struct Computed {
+    names: Vec<String>,
+    expressions: Vec<Expression>
+}
+
+impl Computed {
+    pub fn compile(fields: &[ComputedField]) -> DataResult<Computed> {
+        let mut expressions = Vec::with_capacity(fields.len());
+
+        for (index, field) in fields.iter().enumerate() {
+            let expression = Expression::parse(&field.expression)
+                .map_err(|error| error.at("expression").at(&format!("[{}]", index)))?;
+
+            expressions.push(expression);
+        }
+
+        Ok(Computed {
+            names: fields.iter().map(|field| field.name.clone()).collect(),
+            expressions
+        })
+    }
+
+    /// The rows with their computed values, the processors read them like any other field.
+    pub fn apply<'a, R: Row>(&'a self, data: &'a [R]) -> Vec<ComputedRow<'a, R>> {
+        data.iter().map(|row| self.row(row)).collect()
+    }
+
+    pub fn row<'a, R: Row>(&'a self, row: &'a R) -> ComputedRow<'a, R> {
+        let mut result = ComputedRow {
+            row,
+            names: &self.names,
+            values: Vec::with_capacity(self.names.len())
+        };
+
+        for expression in &self.expressions {
+            let value = expression.evaluate(&result);
+            result.values.push(value);
+        }
+
+        result
+    }
+
+    /// Write the computed values onto the json row, for rows that are kept such as the live perspective.
+    pub fn extend(&self, row: &mut Value) {
+        let values = self.row(&*row).values;
+
+        if let Value::Object(properties) = row {
+            for (name, value) in self.names.iter().zip(values) {
+                properties.insert(name.clone(), value);
+            }
+        }
+    }
+}
+
+/// A row with computed values, fields that are not computed are read from the row.
+/// A path can go on into a computed value, "totals.net" reads net on the computed totals.
+pub struct ComputedRow<'a, R> {
+    row: &'a R,
+    names: &'a [String],
+    values: Vec<Value>
+}
+
+impl<R: Row> Row for ComputedRow<'_, R> {
+    fn field(&self, path: &FieldPath) -> Option<Cow<'_, Value>> {
+        let segments = path.segments();
+
+        if let Some(Segment::Key(key)) = segments.first() {
+            let value = self.names.iter().position(|name| name == key).and_then(|index| self.values.get(index));
+
+            if let Some(value) = value {
+                return resolve(value, &segments[1..]);
+            }
+        }
+
+        self.row.field(path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::expression::Computed;
+    use crate::intent::ComputedField;
+    use crate::path::FieldPath;
+    use crate::row::Row;
+
+    fn get_computed() -> Computed {
+        Computed::compile(&[
+            ComputedField::new("total", "qty * price"),
+            ComputedField::new("name", "concat(first, ' ', last)"),
+            ComputedField::new("double", "total * 2")
+        ]).unwrap()
+    }
+
+    #[test]
+    fn row_test() {
+        let computed = get_computed();
+        let row = json!({"qty": 2, "price": 5, "first": "John", "last": "Doe", "name": "john"});
+        let computed_row = computed.row(&row);
+
+        assert_eq!(computed_row.value(&FieldPath::new("total")).as_ref(), &json!(10));
+        assert_eq!(computed_row.value(&FieldPath::new("double")).as_ref(), &json!(20));
+        assert_eq!(computed_row.value(&FieldPath::new("name")).as_ref(), &json!("John Doe"));
+        assert_eq!(computed_row.value(&FieldPath::new("qty")).as_ref(), &json!(2));
+        assert_eq!(computed_row.field(&FieldPath::new("missing")), None);
+    }
+
+    #[test]
+    fn extend_test() {
+        let computed = get_computed();
+        let mut row = json!({"qty": 2, "price": 5, "first": "John", "last": "Doe"});
+        computed.extend(&mut row);
+
+        assert_eq!(row["total"], json!(10));
+        assert_eq!(row["double"], json!(20));
+        assert_eq!(row["name"], json!("John Doe"));
+
+        let mut deleted = Value::Null;
+        computed.extend(&mut deleted);
+        assert_eq!(deleted, Value::Null);
+    }
+
+    #[test]
+    fn error_test() {
+        let error = Computed::compile(&[ComputedField::new("a", "1"), ComputedField::new("b", "max(")]).err().unwrap();
+        assert_eq!(error.path, "[1].expression");
+    }
+}
@@ -0,0 +1,194 @@
+use chrono::Datelike;
+use serde_json::Value;
+use crate::compare::compare_values;
+use crate::date::parse_date;
+use crate::duration::parse_seconds;
+use crate::evaluators::length;
+use crate::expression::{number, to_number, to_text, is_truthy};
+
+/// The functions an expression can call, the name is checked when the expression is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Function {
+    Abs,
+    Round,
+    Floor,
+    Ceil,
+    Sqrt,
+    Pow,
+    Min,
+    Max,
+    Concat,
+    Lower,
+    Upper,
+    Trim,
+    Length,
+    Coalesce,
+    If,
+    Year,
+    Month,
+    Day,
+    DaysBetween,
+    Seconds
+}
+
+impl Function {
+    pub fn find(name: &str) -> Option<Function> {
+        let function = match name {
+            "abs"           => Function::Abs,
+            "round"         => Function::Round,
+            "floor"         => Function::Floor,
+            "ceil"          => Function::Ceil,
+            "sqrt"          => Function::Sqrt,
+            "pow"           => Function::Pow,
+            "min"           => Function::Min,
+            "max"           => Function::Max,
+            "concat"        => Function::Concat,
+            "lower"         => Function::Lower,
+            "upper"         => Function::Upper,
+            "trim"          => Function::Trim,
+            "length"        => Function::Length,
+            "coalesce"      => Function::Coalesce,
+            "if"            => Function::If,
+            "year"          => Function::Year,
+            "month"         => Function::Month,
+            "day"           => Function::Day,
+            "days_between"  => Function::DaysBetween,
+            "seconds"       => Function::Seconds,
+            _ => return None
+        };
+
+        Some(function)
+    }
+
+    /// The least and most arguments the function takes, None when there is no limit.
+    pub fn arity(&self) -> (usize, Option<usize>) {
+        match self {
+            Function::Round                                     => (1, Some(2)),
+            Function::Pow | Function::DaysBetween               => (2, Some(2)),
+            Function::If                                        => (3, Some(3)),
+            Function::Min | Function::Max | Function::Coalesce  => (1, None),
+            Function::Concat                                    => (0, None),
+            _                                                   => (1, Some(1))
+        }
+    }
+
+    /// The arity is checked when parsing so the arguments are there, concat can have none.
+    pub fn call(&self, arguments: &[Value]) -> Value {
+        let first = arguments.first().unwrap_or(&Value::Null);
+
+        match self {
+            Function::Abs   => math(first, f64::abs),
+            Function::Floor => math(first, f64::floor),
+            Function::Ceil  => math(first, f64::ceil),
+            Function::Sqrt  => math(first, f64::sqrt),
+            Function::Round => {
+                let digits = arguments.get(1).and_then(number).unwrap_or(0.0) as i32;
+                let scale = 10f64.powi(digits);
+                math(first, |value| (value * scale).round() / scale)
+            }
+            Function::Pow => match (number(first), number(&arguments[1])) {
+                (Some(value), Some(power)) => to_number(value.powf(power)),
+                _ => Value::Null
+            },
+            Function::Min => extreme(arguments, std::cmp::Ordering::Less),
+            Function::Max => extreme(arguments, std::cmp::Ordering::Greater),
+            Function::Concat => Value::from(arguments.iter().map(to_text).collect::<String>()),
+            Function::Lower => text(first, str::to_lowercase),
+            Function::Upper => text(first, str::to_uppercase),
+            Function::Trim  => text(first, |value| value.trim().to_string()),
+            Function::Length => length(first).map_or(Value::Null, Value::from),
+            Function::Coalesce => arguments.iter().find(|value| !value.is_null()).cloned().unwrap_or(Value::Null),
+            Function::If => match is_truthy(first) {
+                true => arguments[1].clone(),
+                false => arguments[2].clone()
+            },
+            Function::Year  => parse_date(first).map_or(Value::Null, |date| Value::from(date.year())),
+            Function::Month => parse_date(first).map_or(Value::Null, |date| Value::from(date.month())),
+            Function::Day   => parse_date(first).map_or(Value::Null, |date| Value::from(date.day())),
+            Function::DaysBetween => match (parse_date(first), parse_date(&arguments[1])) {
+                (Some(start), Some(end)) => Value::from((end.date() - start.date()).num_days()),
+                _ => Value::Null
+            },
+            Function::Seconds => parse_seconds(first).map_or(Value::Null, |seconds| to_number(seconds as f64))
+        }
+    }
+}
+
+fn math(value: &Value, operation: impl Fn(f64) -> f64) -> Value {
+    number(value).map_or(Value::Null, |value| to_number(operation(value)))
+}
+
+/// Only strings are changed, other values give null.
+fn text(value: &Value, operation: impl Fn(&str) -> String) -> Value {
+    value.as_str().map_or(Value::Null, |value| Value::from(operation(value)))
+}
+
+/// The smallest or largest of the values, nulls are left out.
+fn extreme(arguments: &[Value], wanted: std::cmp::Ordering) -> Value {
+    arguments.iter()
+        .filter(|value| !value.is_null())
+        .fold(None, |result: Option<&Value>, value| match result {
+            Some(result) if compare_values(value, result) != wanted => Some(result),
+            _ => Some(value)
+        })
+        .cloned()
+        .unwrap_or(Value::Null)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::expression::functions::Function;
+
+    fn call(name: &str, arguments: Vec<Value>) -> Value {
+        Function::find(name).unwrap().call(&arguments)
+    }
+
+    #[test]
+    fn math_test() {
+        assert_eq!(call("abs", vec![json!(-2)]), json!(2));
+        assert_eq!(call("round", vec![json!(2.456), json!(2)]), json!(2.46));
+        assert_eq!(call("round", vec![json!(2.5)]), json!(3));
+        assert_eq!(call("floor", vec![json!("2.7")]), json!(2));
+        assert_eq!(call("ceil", vec![json!(2.1)]), json!(3));
+        assert_eq!(call("sqrt", vec![json!(-1)]), Value::Null);
+        assert_eq!(call("pow", vec![json!(2), json!(10)]), json!(1024));
+        assert_eq!(call("abs", vec![json!("abc")]), Value::Null);
+        assert_eq!(call("min", vec![json!(3), Value::Null, json!(1)]), json!(1));
+        assert_eq!(call("max", vec![json!("b"), json!("a")]), json!("b"));
+        assert_eq!(call("max", vec![Value::Null]), Value::Null);
+    }
+
+    #[test]
+    fn text_test() {
+        assert_eq!(call("concat", vec![json!("John"), json!(" "), json!("Doe")]), json!("John Doe"));
+        assert_eq!(call("concat", vec![json!("A"), Value::Null, json!(1), json!(true)]), json!("A1true"));
+        assert_eq!(call("concat", vec![]), json!(""));
+        assert_eq!(call("lower", vec![json!("ABC")]), json!("abc"));
+        assert_eq!(call("upper", vec![json!(1)]), Value::Null);
+        assert_eq!(call("trim", vec![json!("  a ")]), json!("a"));
+        assert_eq!(call("length", vec![json!("abc")]), json!(3));
+        assert_eq!(call("length", vec![json!([1, 2])]), json!(2));
+        assert_eq!(call("length", vec![json!(10)]), Value::Null);
+    }
+
+    #[test]
+    fn logic_test() {
+        assert_eq!(call("coalesce", vec![Value::Null, json!(0), json!(1)]), json!(0));
+        assert_eq!(call("coalesce", vec![Value::Null]), Value::Null);
+        assert_eq!(call("if", vec![json!(1), json!("yes"), json!("no")]), json!("yes"));
+        assert_eq!(call("if", vec![json!(""), json!("yes"), json!("no")]), json!("no"));
+    }
+
+    #[test]
+    fn date_test() {
+        assert_eq!(call("year", vec![json!("2024-03-15")]), json!(2024));
+        assert_eq!(call("month", vec![json!("2024-03-15T10:00:00")]), json!(3));
+        assert_eq!(call("day", vec![json!("2024-03-15")]), json!(15));
+        assert_eq!(call("year", vec![json!("soon")]), Value::Null);
+        assert_eq!(call("days_between", vec![json!("2024-02-28"), json!("2024-03-01T23:00:00")]), json!(2));
+        assert_eq!(call("days_between", vec![json!("2024-03-01"), json!("2024-02-28")]), json!(-2));
+        assert_eq!(call("days_between", vec![Value::Null, json!("2024-02-28")]), Value::Null);
+        assert_eq!(call("seconds", vec![json!("PT1M30S")]), json!(90));
+    }
+}
@@ -0,0 +1,373 @@
+use serde_json::Value;
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::expression::{BinaryOperator, Node};
+use crate::expression::functions::Function;
+use crate::path::FieldPath;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Literal(Value),
+    Name(String),
+    Field(String),
+    Operator(&'static str),
+    Open,
+    Close,
+    Comma
+}
+
+const OPERATORS: [&str; 16] = ["==", "!=", "<>", "<=", ">=", "&&", "||", "<", ">", "+", "-", "*", "/", "%", "!", "="];
+
+/// Parse the text of an expression into its tree.
+/// Errors point at the position in the text so the caller can show where the expression is wrong.
+pub fn parse(text: &str) -> DataResult<Node> {
+    let tokens = tokenize(text)?;
+    let mut parser = Parser { tokens, position: 0 };
+
+    let node = parser.expression(0)?;
+
+    match parser.tokens.get(parser.position) {
+        None => Ok(node),
+        Some((_, position)) => Err(error(*position, "unexpected text after the expression".to_string()))
+    }
+}
+
+fn error(position: usize, message: String) -> DataError {
+    DataError::new(ErrorCode::InvalidValue, "", format!("{} at position {}", message, position))
+}
+
+fn tokenize(text: &str) -> DataResult<Vec<(Token, usize)>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while position < chars.len() {
+        let start = position;
+        let char = chars[position];
+
+        if char.is_whitespace() {
+            position += 1;
+            continue;
+        }
+
+        let token = match char {
+            '(' => { position += 1; Token::Open }
+            ')' => { position += 1; Token::Close }
+            ',' => { position += 1; Token::Comma }
+            '\'' | '"' => {
+                let mut value = String::new();
+                position += 1;
+
+                loop {
+                    match chars.get(position) {
+                        None => return Err(error(start, "the text is not closed".to_string())),
+                        Some('\\') => {
+                            value.extend(chars.get(position + 1));
+                            position += 2;
+                        }
+                        Some(next) if *next == char => {
+                            position += 1;
+                            break;
+                        }
+                        Some(next) => {
+                            value.push(*next);
+                            position += 1;
+                        }
+                    }
+                }
+
+                Token::Literal(Value::from(value))
+            }
+            '`' => {
+                // a field with spaces or operators in its name, dots still separate the properties
+                let end = chars[position + 1..].iter().position(|next| *next == '`')
+                    .ok_or_else(|| error(start, "the field is not closed".to_string()))?;
+
+                let field: String = chars[position + 1..position + 1 + end].iter().collect();
+                position += end + 2;
+                Token::Field(field)
+            }
+            _ if char.is_ascii_digit() || (char == '.' && chars.get(position + 1).is_some_and(char::is_ascii_digit)) => {
+                while position < chars.len() && (chars[position].is_ascii_digit() || chars[position] == '.') {
+                    position += 1;
+                }
+
+                let number: String = chars[start..position].iter().collect();
+
+                let value = match number.parse::<i64>() {
+                    Ok(number) => Value::from(number),
+                    Err(_) => number.parse::<f64>().ok().map(Value::from)
+                        .ok_or_else(|| error(start, format!("'{}' is not a number", number)))?
+                };
+
+                Token::Literal(value)
+            }
+            _ if char.is_alphabetic() || char == '_' || char == '$' => {
+                let mut name = String::new();
+
+                while let Some(next) = chars.get(position) {
+                    match next {
+                        '\\' => {
+                            // keep the escape, the field path reads it
+                            name.push('\\');
+                            name.extend(chars.get(position + 1));
+                            position += 2;
+                        }
+                        '[' => {
+                            let end = chars[position..].iter().position(|next| *next == ']')
+                                .ok_or_else(|| error(position, "the index is not closed".to_string()))?;
+
+                            name.extend(&chars[position..=position + end]);
+                            position += end + 1;
+                        }
+                        _ if next.is_alphanumeric() || *next == '_' || *next == '$' || *next == '.' => {
+                            name.push(*next);
+                            position += 1;
+                        }
+                        _ => break
+                    }
+                }
+
+                Token::Name(name)
+            }
+            _ => {
+                let rest: String = chars[position..chars.len().min(position + 2)].iter().collect();
+
+                let operator = OPERATORS.iter().find(|operator| rest.starts_with(*operator))
+                    .ok_or_else(|| error(start, format!("unexpected character '{}'", char)))?;
+
+                position += operator.len();
+                Token::Operator(operator)
+            }
+        };
+
+        tokens.push((token, start));
+    }
+
+    Ok(tokens)
+}
+
+/// The binary operator of the token and how strongly it binds, higher binds first.
+fn binary_operator(token: &Token) -> Option<(BinaryOperator, u8)> {
+    let operator = match token {
+        Token::Operator(operator) => *operator,
+        Token::Name(name) => match name.as_str() {
+            "or" => "||",
+            "and" => "&&",
+            _ => return None
+        },
+        _ => return None
+    };
+
+    let result = match operator {
+        "||"            => (BinaryOperator::Or, 1),
+        "&&"            => (BinaryOperator::And, 2),
+        "==" | "="      => (BinaryOperator::Equal, 3),
+        "!=" | "<>"     => (BinaryOperator::NotEqual, 3),
+        "<"             => (BinaryOperator::Less, 4),
+        "<="            => (BinaryOperator::LessOrEqual, 4),
+        ">"             => (BinaryOperator::Greater, 4),
+        ">="            => (BinaryOperator::GreaterOrEqual, 4),
+        "+"             => (BinaryOperator::Add, 5),
+        "-"             => (BinaryOperator::Subtract, 5),
+        "*"             => (BinaryOperator::Multiply, 6),
+        "/"             => (BinaryOperator::Divide, 6),
+        "%"             => (BinaryOperator::Remainder, 6),
+        _ => return None
+    };
+
+    Some(result)
+}
+
+const UNARY_BINDING: u8 = 7;
+
+/// The keyword takes the comparison after it, "not a > 1" is "!(a > 1)".
+const NOT_BINDING: u8 = 2;
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<(Token, usize)> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    /// The position of the next token, or the end of the text for errors at the end.
+    fn here(&self) -> usize {
+        self.tokens.get(self.position).or(self.tokens.last()).map_or(0, |(_, position)| *position)
+    }
+
+    /// Operators that bind less than the minimum are left for the caller.
+    fn expression(&mut self, minimum: u8) -> DataResult<Node> {
+        let mut left = self.operand()?;
+
+        while let Some((operator, binding)) = self.peek().and_then(binary_operator) {
+            if binding <= minimum {
+                break;
+            }
+
+            self.position += 1;
+            let right = self.expression(binding)?;
+            left = Node::Binary(operator, Box::new(left), Box::new(right));
+        }
+
+        Ok(left)
+    }
+
+    fn operand(&mut self) -> DataResult<Node> {
+        let here = self.here();
+
+        let (token, position) = match self.next() {
+            None => return Err(error(here, "expected a value".to_string())),
+            Some(token) => token
+        };
+
+        match token {
+            Token::Literal(value) => Ok(Node::Literal(value)),
+            Token::Field(field) => Ok(Node::Field(FieldPath::new(&field))),
+            Token::Operator("-") => Ok(Node::Negate(Box::new(self.expression(UNARY_BINDING)?))),
+            Token::Operator("!") => Ok(Node::Not(Box::new(self.expression(UNARY_BINDING)?))),
+            Token::Open => {
+                let node = self.expression(0)?;
+                self.expect(Token::Close, "')'")?;
+                Ok(node)
+            }
+            Token::Name(name) => match name.as_str() {
+                "true"  => Ok(Node::Literal(Value::Bool(true))),
+                "false" => Ok(Node::Literal(Value::Bool(false))),
+                "null"  => Ok(Node::Literal(Value::Null)),
+                "not"   => Ok(Node::Not(Box::new(self.expression(NOT_BINDING)?))),
+                _ if self.peek() == Some(&Token::Open) => self.call(&name, position),
+                _ => Ok(Node::Field(FieldPath::new(&name)))
+            },
+            _ => Err(error(position, "expected a value".to_string()))
+        }
+    }
+
+    fn call(&mut self, name: &str, position: usize) -> DataResult<Node> {
+        let function = Function::find(name)
+            .ok_or_else(|| error(position, format!("unknown function '{}'", name)))?;
+
+        self.position += 1;
+        let mut arguments = Vec::new();
+
+        if self.peek() == Some(&Token::Close) {
+            self.position += 1;
+        }
+        else {
+            loop {
+                arguments.push(self.expression(0)?);
+
+                match self.next() {
+                    Some((Token::Comma, _)) => {}
+                    Some((Token::Close, _)) => break,
+                    Some((_, position)) => return Err(error(position, "expected ',' or ')'".to_string())),
+                    None => return Err(error(self.here(), "expected ')'".to_string()))
+                }
+            }
+        }
+
+        let (minimum, maximum) = function.arity();
+
+        if arguments.len() < minimum || maximum.is_some_and(|maximum| arguments.len() > maximum) {
+            let expected = match maximum {
+                Some(maximum) if maximum == minimum => format!("{}", minimum),
+                Some(maximum) => format!("{} to {}", minimum, maximum),
+                None => format!("at least {}", minimum)
+            };
+
+            let message = format!("{} takes {} arguments, not {}", name, expected, arguments.len());
+            return Err(error(position, message));
+        }
+
+        Ok(Node::Call(function, arguments))
+    }
+
+    fn expect(&mut self, expected: Token, text: &str) -> DataResult<()> {
+        let here = self.here();
+
+        match self.next() {
+            Some((token, _)) if token == expected => Ok(()),
+            Some((_, position)) => Err(error(position, format!("expected {}", text))),
+            None => Err(error(here, format!("expected {}", text)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+    use crate::error::ErrorCode;
+    use crate::expression::{BinaryOperator, Node};
+    use crate::expression::functions::Function;
+    use crate::expression::parser::parse;
+    use crate::path::FieldPath;
+
+    fn field(name: &str) -> Box<Node> {
+        Box::new(Node::Field(FieldPath::new(name)))
+    }
+
+    fn literal(value: serde_json::Value) -> Box<Node> {
+        Box::new(Node::Literal(value))
+    }
+
+    #[test]
+    fn precedence_test() {
+        let node = parse("qty * price + 1").unwrap();
+        let product = Node::Binary(BinaryOperator::Multiply, field("qty"), field("price"));
+        assert_eq!(node, Node::Binary(BinaryOperator::Add, Box::new(product), literal(json!(1))));
+
+        let node = parse("qty * (price + 1)").unwrap();
+        let sum = Node::Binary(BinaryOperator::Add, field("price"), literal(json!(1)));
+        assert_eq!(node, Node::Binary(BinaryOperator::Multiply, field("qty"), Box::new(sum)));
+
+        let node = parse("a - b - c").unwrap();
+        let left = Node::Binary(BinaryOperator::Subtract, field("a"), field("b"));
+        assert_eq!(node, Node::Binary(BinaryOperator::Subtract, Box::new(left), field("c")));
+
+        let node = parse("not a > 1 or b").unwrap();
+        let compare = Node::Binary(BinaryOperator::Greater, field("a"), literal(json!(1)));
+        assert_eq!(node, Node::Binary(BinaryOperator::Or, Box::new(Node::Not(Box::new(compare))), field("b")));
+
+        let node = parse("!a > 1").unwrap();
+        assert_eq!(node, Node::Binary(BinaryOperator::Greater, Box::new(Node::Not(field("a"))), literal(json!(1))));
+
+        assert_eq!(parse("-2.5").unwrap(), Node::Negate(literal(json!(2.5))));
+    }
+
+    #[test]
+    fn operand_test() {
+        assert_eq!(parse("'it\\'s'").unwrap(), Node::Literal(json!("it's")));
+        assert_eq!(parse("\"text\"").unwrap(), Node::Literal(json!("text")));
+        assert_eq!(parse("null").unwrap(), Node::Literal(json!(null)));
+        assert_eq!(parse("true").unwrap(), Node::Literal(json!(true)));
+        assert_eq!(parse("person.name").unwrap(), *field("person.name"));
+        assert_eq!(parse("lines[*].qty").unwrap(), *field("lines[*].qty"));
+        assert_eq!(parse("size\\.cm").unwrap(), *field("size\\.cm"));
+        assert_eq!(parse("`unit price`").unwrap(), *field("unit price"));
+        assert_eq!(parse("coalesce(a, 0)").unwrap(), Node::Call(Function::Coalesce, vec![*field("a"), *literal(json!(0))]));
+    }
+
+    #[test]
+    fn error_test() {
+        let error = parse("qty * ").unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.message, "expected a value at position 4");
+
+        assert_eq!(parse("sqrtt(a)").unwrap_err().message, "unknown function 'sqrtt' at position 0");
+        assert_eq!(parse("if(a, b)").unwrap_err().message, "if takes 3 arguments, not 2 at position 0");
+        assert_eq!(parse("round(a, 1, 2)").unwrap_err().message, "round takes 1 to 2 arguments, not 3 at position 0");
+        assert_eq!(parse("(a + b").unwrap_err().message, "expected ')' at position 5");
+        assert_eq!(parse("a b").unwrap_err().message, "unexpected text after the expression at position 2");
+        assert_eq!(parse("'open").unwrap_err().message, "the text is not closed at position 0");
+        assert_eq!(parse("a # b").unwrap_err().message, "unexpected character '#' at position 2");
+        assert!(parse("").is_err());
+    }
+}
@@ -0,0 +1,321 @@
+mod filter;
+mod sort;
+mod group;
+mod bucket;
+mod aggregate;
+mod unique;
+mod perspective;
+mod page;
+mod delta;
+mod computed;
+mod pivot;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use crate::error::{DataError, DataResult};
+
+pub use filter::{FilterExpr, Comparison, Quantified, Operator, FilterType, Quantifier, FIELD_REFERENCE};
+pub use sort::{SortField, SortType};
+pub use group::{GroupSpec, GroupOrder, NULL_LABEL};
+pub use bucket::{Bucket, BucketKind, DatePart};
+pub use aggregate::{AggregateSpec, AggregateKind, Aggregates};
+pub use unique::{UniqueField, ValueType};
+pub use perspective::Perspective;
+pub use page::Page;
+pub use delta::{RowDelta, RowTarget};
+pub use computed::ComputedField;
+pub use pivot::Pivot;
+pub use crate::enums::{NullOrder, SortDirection};
+pub use crate::collation::Collation;
+
+/// A part of the intent model.
+/// Serde takes care of the structure, check is for the rules serde can't express such as
+/// the shape of a filter value depending on the operator.
+pub trait Intent: DeserializeOwned {
+    fn check(&self) -> DataResult<()> {
+        Ok(())
+    }
+}
+
+impl<T: Intent> Intent for Vec<T> {
+    fn check(&self) -> DataResult<()> {
+        for (index, item) in self.iter().enumerate() {
+            item.check().map_err(|error| error.at(&format!("[{}]", index)))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse the json intent into the model.
+/// The root is where the intent sits in the request, for example "filter", and starts the error path.
+pub fn parse<T: Intent>(intent: &Value, root: &str) -> DataResult<T> {
+    let result: T = serde_path_to_error::deserialize(intent)
+        .map_err(|error| DataError::from_serde(error).at(root))?;
+
+    result.check().map_err(|error| error.at(root))?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+    use crate::error::ErrorCode;
+    use crate::intent::{parse, Perspective, FilterExpr, FilterType, Quantifier, Quantified, Comparison, Operator, SortField, SortType, SortDirection, GroupSpec, GroupOrder, Aggregates, AggregateSpec, AggregateKind};
+
+    #[test]
+    fn parse_perspective_test() {
+        let intent = json!({
+            "filter": [
+                { "field": "value", "operator": "<", "value": 20 },
+                { "operator": "or", "expressions": [
+                    { "field": "code", "operator": "in", "value": ["A", "B"] },
+                    { "field": "person.name", "operator": "is_null" }
+                ]}
+            ],
+            "sort": [{"name": "code", "direction": "dec"}],
+            "group": ["value"],
+            "aggregates": { "sum": "value" }
+        });
+
+        let perspective: Perspective = parse(&intent, "perspective").unwrap();
+
+        assert_eq!(perspective.filter.as_ref().unwrap().len(), 2);
+        assert_eq!(perspective.sort.as_ref().unwrap()[0].name, "code");
+        assert_eq!(perspective.group.as_ref().unwrap()[0].field, "value");
+        assert_eq!(perspective.aggregates.as_ref().unwrap().0[0].field, "value");
+        assert!(!perspective.case_sensitive);
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let intent = json!({
+            "filter": [
+                { "field": "value", "operator": "between", "value": [1, 10] },
+                { "operator": "not", "expressions": [{ "field": "code", "operator": "==", "value": "A" }] }
+            ],
+            "case_sensitive": true,
+            "sort": [{"name": "duration", "type": "duration", "direction": "desc"}],
+            "group": ["value", "isActive"],
+            "aggregates": { "ave": "value", "max:date": "date" }
+        });
+
+        let perspective: Perspective = parse(&intent, "perspective").unwrap();
+        let json = serde_json::to_value(&perspective).unwrap();
+
+        assert_eq!(json, intent);
+    }
+
+    #[test]
+    fn build_in_code_test() {
+        let perspective = Perspective {
+            filter: Some(vec![FilterExpr::Or(vec![
+                FilterExpr::Comparison(Comparison::new("code", Operator::Equal, json!("A"))),
+                FilterExpr::Comparison(Comparison::new("value", Operator::GreaterThan, json!(10)))
+            ])]),
+            sort: Some(vec![SortField::new("code", None, SortDirection::Descending)]),
+            group: Some(vec![GroupSpec::from("isActive")]),
+            aggregates: Some(Aggregates(vec![AggregateSpec::new(AggregateKind::Sum, "value")])),
+            ..Default::default()
+        };
+
+        let expected = json!({
+            "filter": [{ "operator": "or", "expressions": [
+                { "field": "code", "operator": "==", "value": "A" },
+                { "field": "value", "operator": ">", "value": 10 }
+            ]}],
+            "case_sensitive": false,
+            "sort": [{"name": "code", "direction": "desc"}],
+            "group": ["isActive"],
+            "aggregates": { "sum": "value" }
+        });
+
+        assert_eq!(serde_json::to_value(&perspective).unwrap(), expected);
+    }
+
+    #[test]
+    fn group_spec_test() {
+        let intent = json!([
+            "site",
+            { "field": "value", "type": "number", "direction": "desc" },
+            { "field": "code", "order_by": "count", "direction": "desc" },
+            { "field": "isActive", "order_by": { "sum": "value" } },
+            { "field": "person.name", "case_insensitive": true, "null_label": "Unknown" }
+        ]);
+
+        let group: Vec<GroupSpec> = parse(&intent, "group").unwrap();
+        assert_eq!(group[0], GroupSpec::from("site"));
+        assert_eq!(group[1].sort, Some(SortField::new("value", Some(SortType::Number), SortDirection::Descending)));
+        assert_eq!(group[2].order_by, GroupOrder::Count);
+        assert_eq!(group[3].order_by, GroupOrder::Aggregate(AggregateSpec::new(AggregateKind::Sum, "value")));
+        assert_eq!(group[4], GroupSpec::from("person.name").with_case_insensitive().with_null_label("Unknown"));
+        assert_eq!(serde_json::to_value(&group).unwrap(), intent);
+
+        assert_eq!(group[4].key_value(json!("Doe")), json!("doe"));
+        assert_eq!(group[4].label(&json!(null)), "Unknown");
+        assert_eq!(group[0].label(&json!(null)), "null");
+        assert_eq!(group[0].label(&json!(10)), "10");
+
+        let sort = vec![SortField::new("site", None, SortDirection::Descending)];
+        assert_eq!(group[0].follow_sort(&sort).sort, Some(sort[0].clone()));
+        assert_eq!(group[2].follow_sort(&sort), group[2]);
+
+        let error = parse::<Vec<GroupSpec>>(&json!(["site", { "field": "code", "order_by": "size" }]), "group").unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "group[1].order_by");
+
+        let error = parse::<Vec<GroupSpec>>(&json!([{ "field": "code", "order_by": { "avg": "value" } }]), "group").unwrap_err();
+        assert_eq!(error.code, ErrorCode::UnknownAggregate);
+        assert_eq!(error.path, "group[0].order_by.avg");
+
+        let error = parse::<Vec<GroupSpec>>(&json!([{ "field": "code", "type": "enum" }]), "group").unwrap_err();
+        assert_eq!(error.code, ErrorCode::MissingProperty);
+        assert_eq!(error.path, "group[0].order");
+
+        let error = parse::<Perspective>(&json!({ "group": ["site", { "field": "code", "type": "enum" }] }), "").unwrap_err();
+        assert_eq!(error.code, ErrorCode::MissingProperty);
+        assert_eq!(error.path, "group[1].order");
+
+        let error = parse::<Perspective>(&json!({ "group": [{ "field": "value", "bucket": { "width": 0 } }] }), "").unwrap_err();
+        assert_eq!(error.path, "group[0].bucket.width");
+    }
+
+    #[test]
+    fn aliases_test() {
+        let filter: Vec<FilterExpr> = parse(&json!([{ "field": "value", "operator": "gt", "value": 1 }]), "filter").unwrap();
+        let json = serde_json::to_value(&filter).unwrap();
+        assert_eq!(json[0]["operator"], ">");
+
+        let filter: Vec<FilterExpr> = parse(&json!([
+            { "field": "code", "operator": "starts_with", "value": "A" },
+            { "field": "code", "operator": "ends_with", "value": "A" },
+            { "field": "code", "operator": "neq", "value": "A" }
+        ]), "filter").unwrap();
+        let json = serde_json::to_value(&filter).unwrap();
+        assert_eq!(json[0]["operator"], "startswith");
+        assert_eq!(json[1]["operator"], "endswith");
+        assert_eq!(json[2]["operator"], "!=");
+
+        let sort: Vec<SortField> = parse(&json!([{ "name": "code", "direction": "dec" }]), "sort").unwrap();
+        let json = serde_json::to_value(&sort).unwrap();
+        assert_eq!(json[0]["direction"], "desc");
+    }
+
+    #[test]
+    fn error_path_test() {
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "value", "operator": "<", "value": 1 }, { "field": 1, "operator": "<", "value": 1 }]), "filter").unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidType);
+        assert_eq!(error.path, "filter[1].field");
+
+        let error = parse::<Vec<SortField>>(&json!([{ "name": "code", "direction": "up" }]), "sort").unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "sort[0].direction");
+
+        let error = parse::<Vec<GroupSpec>>(&json!("value"), "group").unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidType);
+        assert_eq!(error.path, "group");
+
+        let error = parse::<Aggregates>(&json!({ "sum": "value", "max": 1 }), "aggregates").unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidType);
+        assert_eq!(error.path, "aggregates.max");
+    }
+
+    #[test]
+    fn date_filter_test() {
+        let filter: Vec<FilterExpr> = parse(&json!([
+            { "field": "date", "operator": ">", "value": "2022/01/01", "type": "date" },
+            { "field": "date", "operator": "within_last", "value": "P7D" },
+            { "field": "date", "operator": "same_month" }
+        ]), "filter").unwrap();
+
+        assert_eq!(filter[0], FilterExpr::Comparison(Comparison::new("date", Operator::GreaterThan, json!("2022/01/01")).with_type(FilterType::Date)));
+        assert_eq!(serde_json::to_value(&filter[0]).unwrap()["type"], "date");
+        assert_eq!(filter[2], FilterExpr::Comparison(Comparison::new("date", Operator::SameMonth, json!(null))));
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "date", "operator": "before", "value": "yesterday" }]), "filter").unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "filter[0].value");
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "date", "operator": "between", "value": ["2022/01/01", 1.5], "type": "date" }]), "filter").unwrap_err();
+        assert_eq!(error.path, "filter[0].value[1]");
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "date", "operator": "within_next", "value": "7 days" }]), "filter").unwrap_err();
+        assert_eq!(error.path, "filter[0].value");
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "date", "operator": "like", "value": "2022", "type": "date" }]), "filter").unwrap_err();
+        assert_eq!(error.path, "filter[0].operator");
+    }
+
+    #[test]
+    fn pattern_filter_test() {
+        let filter: Vec<FilterExpr> = parse(&json!([{ "field": "code", "operator": "matches", "value": "^wo-\\d+$", "flags": "i" }]), "filter").unwrap();
+
+        assert_eq!(filter[0], FilterExpr::Comparison(Comparison::new("code", Operator::Matches, json!("^wo-\\d+$")).with_flags("i")));
+        assert_eq!(serde_json::to_value(&filter[0]).unwrap()["flags"], "i");
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "code", "operator": "matches", "value": "[a-" }]), "filter").unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "filter[0].value");
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "code", "operator": "not_matches", "value": "a", "flags": "ig" }]), "filter").unwrap_err();
+        assert_eq!(error.path, "filter[0].flags");
+    }
+
+    #[test]
+    fn quantifier_test() {
+        let filter: Vec<FilterExpr> = parse(&json!([{ "field": "lines[*].qty", "operator": ">", "value": 1, "quantifier": "all" }]), "filter").unwrap();
+
+        assert_eq!(filter[0], FilterExpr::Comparison(Comparison::new("lines[*].qty", Operator::GreaterThan, json!(1)).with_quantifier(Quantifier::All)));
+        assert_eq!(serde_json::to_value(&filter[0]).unwrap()["quantifier"], "all");
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "lines[*].qty", "operator": ">", "value": 1, "quantifier": "most" }]), "filter").unwrap_err();
+        assert_eq!(error.path, "filter[0].quantifier");
+    }
+
+    #[test]
+    fn quantified_test() {
+        let intent = json!([{ "operator": "all", "field": "lines", "expressions": [{ "field": "qty", "operator": ">", "value": 0 }] }]);
+        let filter: Vec<FilterExpr> = parse(&intent, "filter").unwrap();
+
+        let expressions = vec![FilterExpr::Comparison(Comparison::new("qty", Operator::GreaterThan, json!(0)))];
+        assert_eq!(filter[0], FilterExpr::Quantified(Quantified::new("lines", Quantifier::All, expressions)));
+        assert_eq!(serde_json::to_value(&filter).unwrap(), intent);
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "operator": "any", "field": "lines" }]), "filter").unwrap_err();
+        assert_eq!(error.code, ErrorCode::MissingProperty);
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "operator": "any", "field": "lines", "expressions": [{ "field": "qty", "operator": "between", "value": 1 }] }]), "filter").unwrap_err();
+        assert_eq!(error.path, "filter[0].expressions[0].value");
+    }
+
+    #[test]
+    fn field_reference_test() {
+        let expected = FilterExpr::Comparison(Comparison::with_field("actual_hours", Operator::GreaterThan, "planned_hours"));
+
+        let filter: Vec<FilterExpr> = parse(&json!([{ "field": "actual_hours", "operator": ">", "value_field": "planned_hours" }]), "filter").unwrap();
+        assert_eq!(filter[0], expected);
+        assert_eq!(serde_json::to_value(&filter[0]).unwrap(), json!({ "field": "actual_hours", "operator": ">", "value_field": "planned_hours" }));
+
+        let filter: Vec<FilterExpr> = parse(&json!([{ "field": "actual_hours", "operator": ">", "value": { "$field": "planned_hours" } }]), "filter").unwrap();
+        assert_eq!(filter[0], expected);
+
+        let filter: Vec<FilterExpr> = parse(&json!([{ "field": "end", "operator": "within_last", "value_field": "span" }]), "filter").unwrap();
+        assert_eq!(filter[0], FilterExpr::Comparison(Comparison::with_field("end", Operator::WithinLast, "span")));
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "a", "operator": ">", "value": 1, "value_field": "b" }]), "filter").unwrap_err();
+        assert_eq!(error.path, "filter[0].value_field");
+
+        for operator in ["between", "in", "like", "matches", "contains_any", "is_null", "is_empty"] {
+            let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "a", "operator": operator, "value_field": "b" }]), "filter").unwrap_err();
+            assert_eq!(error.path, "filter[0].operator");
+        }
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "a", "operator": "starts_with", "value_field": "b", "type": "date" }]), "filter").unwrap_err();
+        assert_eq!(error.path, "filter[0].operator");
+
+        let error = parse::<Vec<FilterExpr>>(&json!([{ "field": "a", "operator": "before", "value_field": "b", "type": "duration" }]), "filter").unwrap_err();
+        assert_eq!(error.path, "filter[0].operator");
+
+        assert!(parse::<Vec<FilterExpr>>(&json!([{ "field": "a", "operator": ">", "value": { "$field": 1 } }]), "filter").is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{DeserializeSeed, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use crate::intent::Intent;
+
+/// The calculations available on a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateKind {
+    Ave,
+    Min,
+    Max,
+    Sum,
+    Count,
+    DurationAve,
+    DurationMin,
+    DurationMax,
+    DurationSum,
+    DateMax,
+    DateMin
+}
+
+impl AggregateKind {
+    pub fn parse(value: &str) -> Option<AggregateKind> {
+        let result = match value {
+            "ave"           => AggregateKind::Ave,
+            "min"           => AggregateKind::Min,
+            "max"           => AggregateKind::Max,
+            "sum"           => AggregateKind::Sum,
+            "count"         => AggregateKind::Count,
+            "ave:duration"  => AggregateKind::DurationAve,
+            "min:duration"  => AggregateKind::DurationMin,
+            "max:duration"  => AggregateKind::DurationMax,
+            "sum:duration"  => AggregateKind::DurationSum,
+            "max:date"      => AggregateKind::DateMax,
+            "min:date"      => AggregateKind::DateMin,
+            _               => return None
+        };
+
+        Some(result)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AggregateKind::Ave          => "ave",
+            AggregateKind::Min          => "min",
+            AggregateKind::Max          => "max",
+            AggregateKind::Sum          => "sum",
+            AggregateKind::Count        => "count",
+            AggregateKind::DurationAve  => "ave:duration",
+            AggregateKind::DurationMin  => "min:duration",
+            AggregateKind::DurationMax  => "max:duration",
+            AggregateKind::DurationSum  => "sum:duration",
+            AggregateKind::DateMax      => "max:date",
+            AggregateKind::DateMin      => "min:date"
+        }
+    }
+}
+
+/// Calculate the aggregate over the values of the field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateSpec {
+    pub aggregate: AggregateKind,
+    pub field: String
+}
+
+impl AggregateSpec {
+    pub fn new(aggregate: AggregateKind, field: &str) -> AggregateSpec {
+        AggregateSpec {
+            aggregate,
+            field: field.to_string()
+        }
+    }
+}
+
+/// On its own an aggregate is an object of the aggregate name and the field, {"agg": "sum", "field": "value"},
+/// the same as the aggregate results.
+#[derive(Serialize, Deserialize)]
+struct AggregateObject {
+    agg: AggregateKind,
+    field: String
+}
+
+impl Serialize for AggregateKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for AggregateKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        AggregateKind::parse(&value).ok_or_else(|| de::Error::custom(format!("unknown aggregate '{}'", value)))
+    }
+}
+
+impl Serialize for AggregateSpec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let object = AggregateObject {
+            agg: self.aggregate,
+            field: self.field.clone()
+        };
+
+        object.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AggregateSpec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let object = AggregateObject::deserialize(deserializer)?;
+        Ok(AggregateSpec::new(object.agg, &object.field))
+    }
+}
+
+impl Intent for AggregateSpec {}
+
+/// The aggregate intent is an object of aggregate name and field, for example {"sum:duration": "downtime"}.
+/// The specs keep the order of the object they were read from.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Aggregates(pub Vec<AggregateSpec>);
+
+impl Intent for Aggregates {}
+
+impl Serialize for Aggregates {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+
+        for spec in &self.0 {
+            map.serialize_entry(spec.aggregate.as_str(), &spec.field)?;
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Aggregates {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(AggregatesVisitor)
+    }
+}
+
+struct AggregatesVisitor;
+
+impl<'de> Visitor<'de> for AggregatesVisitor {
+    type Value = Aggregates;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object of aggregate name and field")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut result = Vec::new();
+
+        while let Some(name) = map.next_key::<String>()? {
+            result.push(map.next_value_seed(AggregateSeed(name))?);
+        }
+
+        Ok(Aggregates(result))
+    }
+}
+
+/// Reads the field of an aggregate.
+/// The name is checked here so that an unknown aggregate is reported on its own entry.
+struct AggregateSeed(String);
+
+impl<'de> DeserializeSeed<'de> for AggregateSeed {
+    type Value = AggregateSpec;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let field = String::deserialize(deserializer)?;
+
+        match AggregateKind::parse(&self.0) {
+            None => Err(de::Error::custom(format!("unknown aggregate '{}'", self.0))),
+            Some(aggregate) => Ok(AggregateSpec { aggregate, field })
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use chrono::{Datelike, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::date::parse_date_with;
+use crate::duration::parse_seconds;
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::expression::{number, to_number};
+use crate::intent::Intent;
+
+/// The part of a date the rows of a date bucket have in common.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DatePart {
+    Year,
+    Quarter,
+    Month,
+
+    /// The ISO 8601 week, the year of the key is the year the week belongs to.
+    Week,
+    Day
+}
+
+/// How the values of a group field are put in buckets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BucketKind {
+    /// {"date": "month"}, the key is the year as a number or text that sorts in date order:
+    /// "2024-Q1", "2024-03", "2024-W05" and "2024-03-05".
+    Date(DatePart),
+
+    /// {"width": 10}, ranges of the same width counted from zero, the key is the start of the range.
+    Width(f64),
+
+    /// {"edges": [0, 10, 50]}, the ranges below, between and after the edges.
+    /// The key is the position of the range, 0 is below the first edge.
+    Edges(Vec<f64>),
+
+    /// {"durations": ["PT1H", "PT4H"]}, the same as edges for ISO 8601 durations.
+    Durations(Vec<String>)
+}
+
+/// A group field that groups on ranges of its values instead of the values.
+/// Values that can't be read as a date, number or duration are in the null group.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawBucket", into = "RawBucket")]
+pub struct Bucket {
+    pub kind: BucketKind,
+
+    /// The labels of the ranges of edges or durations, there is one more label than edges.
+    pub labels: Option<Vec<String>>
+}
+
+/// The json shape of a bucket, only used while reading and writing the intent.
+/// Each kind is its own property so a problem with the value is reported on that property.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawBucket {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    date: Option<DatePart>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    edges: Option<Vec<f64>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    durations: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    labels: Option<Vec<String>>
+}
+
+impl TryFrom<RawBucket> for Bucket {
+    type Error = String;
+
+    fn try_from(raw: RawBucket) -> Result<Self, Self::Error> {
+        let mut kinds = Vec::new();
+        kinds.extend(raw.date.map(BucketKind::Date));
+        kinds.extend(raw.width.map(BucketKind::Width));
+        kinds.extend(raw.edges.map(BucketKind::Edges));
+        kinds.extend(raw.durations.map(BucketKind::Durations));
+
+        match kinds.len() {
+            1 => Ok(Bucket { kind: kinds.remove(0), labels: raw.labels }),
+            _ => Err("a bucket has one of date, width, edges, durations".to_string())
+        }
+    }
+}
+
+impl From<Bucket> for RawBucket {
+    fn from(bucket: Bucket) -> Self {
+        let mut raw = RawBucket { date: None, width: None, edges: None, durations: None, labels: bucket.labels };
+
+        match bucket.kind {
+            BucketKind::Date(part) => raw.date = Some(part),
+            BucketKind::Width(width) => raw.width = Some(width),
+            BucketKind::Edges(edges) => raw.edges = Some(edges),
+            BucketKind::Durations(durations) => raw.durations = Some(durations)
+        }
+
+        raw
+    }
+}
+
+impl Bucket {
+    pub fn new(kind: BucketKind) -> Bucket {
+        Bucket {
+            kind,
+            labels: None
+        }
+    }
+
+    pub fn with_labels(mut self, labels: &[&str]) -> Bucket {
+        self.labels = Some(labels.iter().map(|label| label.to_string()).collect());
+        self
+    }
+
+    /// The key of the bucket the value is in, dates are read with the formats given.
+    pub fn key(&self, value: &Value, formats: &[String]) -> Value {
+        let key = match &self.kind {
+            BucketKind::Date(part) => parse_date_with(value, formats).map(|date| date_key(*part, &date)),
+            BucketKind::Width(width) => number(value).map(|number| to_number((number / width).floor() * width)),
+            BucketKind::Edges(edges) => number(value).map(|number| range_key(edges, number)),
+            BucketKind::Durations(durations) => parse_seconds(value).map(|seconds| range_key(&duration_edges(durations), seconds as f64))
+        };
+
+        key.unwrap_or(Value::Null)
+    }
+
+    /// The text shown for the bucket with the key, "10–20", "< 1h" or "50+" for ranges.
+    pub fn label(&self, key: &Value) -> String {
+        let index = key.as_u64().unwrap_or_default() as usize;
+
+        match &self.kind {
+            BucketKind::Date(_) => text(key),
+            BucketKind::Width(width) => {
+                let start = key.as_f64().unwrap_or_default();
+                format!("{}–{}", text(key), text(&to_number(start + width)))
+            }
+            _ if self.labels.is_some() => self.labels.iter().flatten().nth(index).cloned().unwrap_or_default(),
+            BucketKind::Edges(edges) => {
+                let edges: Vec<String> = edges.iter().map(|edge| text(&to_number(*edge))).collect();
+                range_label(&edges, index)
+            }
+            BucketKind::Durations(durations) => {
+                let edges: Vec<String> = duration_edges(durations).into_iter().map(duration_text).collect();
+                range_label(&edges, index)
+            }
+        }
+    }
+
+    fn edge_count(&self) -> Option<usize> {
+        match &self.kind {
+            BucketKind::Edges(edges) => Some(edges.len()),
+            BucketKind::Durations(durations) => Some(durations.len()),
+            _ => None
+        }
+    }
+}
+
+impl Intent for Bucket {
+    fn check(&self) -> DataResult<()> {
+        match &self.kind {
+            BucketKind::Date(_) => {}
+            BucketKind::Width(width) => {
+                if !width.is_finite() || *width <= 0.0 {
+                    return Err(DataError::new(ErrorCode::InvalidValue, "width", "the width of a bucket must be more than zero".to_string()));
+                }
+            }
+            BucketKind::Edges(edges) => check_edges(edges, "edges")?,
+            BucketKind::Durations(durations) => {
+                if let Some(index) = durations.iter().position(|duration| parse_seconds(&Value::from(duration.as_str())).is_none()) {
+                    let message = format!("'{}' is not a valid duration", durations[index]);
+                    return Err(DataError::new(ErrorCode::InvalidValue, &format!("durations[{}]", index), message));
+                }
+
+                check_edges(&duration_edges(durations), "durations")?;
+            }
+        }
+
+        match (&self.labels, self.edge_count()) {
+            (None, _) => Ok(()),
+            (Some(_), None) => Err(DataError::new(ErrorCode::InvalidValue, "labels", "labels are only used with edges or durations".to_string())),
+            (Some(labels), Some(count)) if labels.len() != count + 1 => {
+                let message = format!("there must be {} labels, one more than the edges", count + 1);
+                Err(DataError::new(ErrorCode::InvalidValue, "labels", message))
+            }
+            _ => Ok(())
+        }
+    }
+}
+
+/// Edges must be given from low to high so every value is in one range.
+fn check_edges(edges: &[f64], property: &str) -> DataResult<()> {
+    if edges.is_empty() {
+        return Err(DataError::new(ErrorCode::InvalidValue, property, "at least one edge is required".to_string()));
+    }
+
+    match edges.windows(2).position(|pair| pair[0] >= pair[1]) {
+        None => Ok(()),
+        Some(index) => {
+            let message = format!("the {} must be in ascending order", property);
+            Err(DataError::new(ErrorCode::InvalidValue, &format!("{}[{}]", property, index + 1), message))
+        }
+    }
+}
+
+fn date_key(part: DatePart, date: &NaiveDateTime) -> Value {
+    match part {
+        DatePart::Year => Value::from(date.year()),
+        DatePart::Quarter => Value::from(format!("{}-Q{}", date.year(), date.month0() / 3 + 1)),
+        DatePart::Month => Value::from(date.format("%Y-%m").to_string()),
+        DatePart::Week => {
+            let week = date.iso_week();
+            Value::from(format!("{}-W{:02}", week.year(), week.week()))
+        }
+        DatePart::Day => Value::from(date.format("%Y-%m-%d").to_string())
+    }
+}
+
+/// The position of the range the number is in, a number on an edge is in the range that starts at the edge.
+fn range_key(edges: &[f64], number: f64) -> Value {
+    Value::from(edges.iter().filter(|edge| number >= **edge).count())
+}
+
+fn range_label(edges: &[String], index: usize) -> String {
+    match index {
+        0 => format!("< {}", edges[0]),
+        index if index >= edges.len() => format!("{}+", edges[edges.len() - 1]),
+        index => format!("{}–{}", edges[index - 1], edges[index])
+    }
+}
+
+fn duration_edges(durations: &[String]) -> Vec<f64> {
+    durations.iter()
+        .filter_map(|duration| parse_seconds(&Value::from(duration.as_str())))
+        .map(|seconds| seconds as f64)
+        .collect()
+}
+
+/// A duration in days, hours, minutes and seconds, "1h30m".
+fn duration_text(seconds: f64) -> String {
+    let mut rest = seconds.round() as i64;
+    let mut result = String::new();
+
+    for (unit, size) in [("d", 86400), ("h", 3600), ("m", 60), ("s", 1)] {
+        if rest >= size {
+            result.push_str(&format!("{}{}", rest / size, unit));
+            rest %= size;
+        }
+    }
+
+    match result.is_empty() {
+        true => "0s".to_string(),
+        false => result
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::error::ErrorCode;
+    use crate::intent::{parse, Bucket, BucketKind, DatePart};
+
+    fn keys(bucket: &Bucket, values: Value) -> Vec<Value> {
+        values.as_array().unwrap().iter().map(|value| bucket.key(value, &[])).collect()
+    }
+
+    #[test]
+    fn date_test() {
+        let values = json!(["2024-03-05", "2023-12-31T23:00:00", "2021/01/03", "A", null]);
+
+        assert_eq!(keys(&Bucket::new(BucketKind::Date(DatePart::Year)), values.clone()), vec![json!(2024), json!(2023), json!(2021), json!(null), json!(null)]);
+        assert_eq!(keys(&Bucket::new(BucketKind::Date(DatePart::Quarter)), values.clone())[..2], [json!("2024-Q1"), json!("2023-Q4")]);
+        assert_eq!(keys(&Bucket::new(BucketKind::Date(DatePart::Month)), values.clone())[..2], [json!("2024-03"), json!("2023-12")]);
+        assert_eq!(keys(&Bucket::new(BucketKind::Date(DatePart::Week)), values.clone())[..3], [json!("2024-W10"), json!("2023-W52"), json!("2020-W53")]);
+        assert_eq!(keys(&Bucket::new(BucketKind::Date(DatePart::Day)), values)[..2], [json!("2024-03-05"), json!("2023-12-31")]);
+
+        let bucket = Bucket::new(BucketKind::Date(DatePart::Month));
+        assert_eq!(bucket.key(&json!("05/03/2024"), &["%d/%m/%Y".to_string()]), json!("2024-03"));
+        assert_eq!(bucket.label(&json!("2024-03")), "2024-03");
+    }
+
+    #[test]
+    fn range_test() {
+        let bucket = Bucket::new(BucketKind::Width(10.0));
+        assert_eq!(keys(&bucket, json!([0, 9.5, 10, -1, "25", "A"])), vec![json!(0), json!(0), json!(10), json!(-10), json!(20), json!(null)]);
+        assert_eq!(bucket.label(&json!(10)), "10–20");
+
+        let bucket = Bucket::new(BucketKind::Edges(vec![0.0, 10.0, 50.0]));
+        assert_eq!(keys(&bucket, json!([-5, 0, 10, 49.9, 50, 1000])), vec![json!(0), json!(1), json!(2), json!(2), json!(3), json!(3)]);
+        assert_eq!((0..4).map(|index| bucket.label(&json!(index))).collect::<Vec<String>>(), vec!["< 0", "0–10", "10–50", "50+"]);
+
+        let bucket = Bucket::new(BucketKind::Durations(vec!["PT1H".into(), "PT4H".into()])).with_labels(&["short", "medium", "long"]);
+        assert_eq!(keys(&bucket, json!(["PT30M", "PT1H", "PT3H59M", "P1D", "A"])), vec![json!(0), json!(1), json!(1), json!(2), json!(null)]);
+        assert_eq!(bucket.label(&json!(2)), "long");
+
+        let bucket = Bucket::new(BucketKind::Durations(vec!["PT1H".into(), "PT4H30M".into()]));
+        assert_eq!((0..3).map(|index| bucket.label(&json!(index))).collect::<Vec<String>>(), vec!["< 1h", "1h–4h30m", "4h30m+"]);
+    }
+
+    #[test]
+    fn parse_test() {
+        let bucket: Bucket = parse(&json!({ "edges": [0, 10], "labels": ["low", "mid", "high"] }), "bucket").unwrap();
+        assert_eq!(bucket, Bucket::new(BucketKind::Edges(vec![0.0, 10.0])).with_labels(&["low", "mid", "high"]));
+        assert_eq!(serde_json::to_value(&bucket).unwrap(), json!({ "edges": [0.0, 10.0], "labels": ["low", "mid", "high"] }));
+
+        let bucket: Bucket = parse(&json!({ "date": "week" }), "bucket").unwrap();
+        assert_eq!(bucket.kind, BucketKind::Date(DatePart::Week));
+
+        let problems = [
+            (json!({ "width": 0 }), "bucket.width"),
+            (json!({ "edges": [] }), "bucket.edges"),
+            (json!({ "edges": [0, 10, 5] }), "bucket.edges[2]"),
+            (json!({ "durations": ["PT1H", "1 hour"] }), "bucket.durations[1]"),
+            (json!({ "durations": ["PT4H", "PT1H"] }), "bucket.durations[1]"),
+            (json!({ "edges": [0], "labels": ["low"] }), "bucket.labels"),
+            (json!({ "width": 5, "labels": ["low"] }), "bucket.labels")
+        ];
+
+        for (intent, path) in problems {
+            let error = parse::<Bucket>(&intent, "bucket").unwrap_err();
+            assert_eq!(error.code, ErrorCode::InvalidValue);
+            assert_eq!(error.path, path);
+        }
+    }
+}
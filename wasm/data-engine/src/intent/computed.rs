@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::expression::Expression;
+use crate::intent::Intent;
+
+/// A field calculated from the other fields of the row, for example { "name": "total", "expression": "qty * price" }.
+/// The other parts of the perspective use the name as if it was a field on the data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComputedField {
+    pub name: String,
+    pub expression: String
+}
+
+impl ComputedField {
+    pub fn new(name: &str, expression: &str) -> ComputedField {
+        ComputedField {
+            name: name.to_string(),
+            expression: expression.to_string()
+        }
+    }
+}
+
+impl Intent for ComputedField {
+    fn check(&self) -> DataResult<()> {
+        if self.name.is_empty() {
+            return Err(DataError::new(ErrorCode::InvalidValue, "name", "the name can't be empty".to_string()));
+        }
+
+        Expression::parse(&self.expression).map_err(|error| error.at("expression"))?;
+        Ok(())
+    }
+}
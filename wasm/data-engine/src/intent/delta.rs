@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::error::{DataError, DataResult};
+use crate::intent::Intent;
+
+/// A change to the rows of a live perspective, for example {"op": "update", "id": 10, "row": {...}}.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum RowDelta {
+    Insert {
+        row: Value
+    },
+    Update {
+        #[serde(flatten)]
+        target: RowTarget,
+        row: Value
+    },
+    Delete {
+        #[serde(flatten)]
+        target: RowTarget
+    }
+}
+
+/// The row a delta applies to, either the index of the row or the value of its id field.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct RowTarget {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>
+}
+
+impl Intent for RowDelta {
+    fn check(&self) -> DataResult<()> {
+        let target = match self {
+            RowDelta::Insert { .. } => return Ok(()),
+            RowDelta::Update { target, .. } | RowDelta::Delete { target } => target
+        };
+
+        if target.index.is_none() && target.id.is_none() {
+            return Err(DataError::missing_property("index", "index or id"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+    use crate::error::ErrorCode;
+    use crate::intent::{parse, RowDelta, RowTarget};
+
+    #[test]
+    fn parse_delta_test() {
+        let deltas: Vec<RowDelta> = parse(&json!([
+            { "op": "insert", "row": {"id": 1} },
+            { "op": "update", "id": "A", "row": {"id": "A"} },
+            { "op": "delete", "index": 2 }
+        ]), "deltas").unwrap();
+
+        assert_eq!(deltas[0], RowDelta::Insert { row: json!({"id": 1}) });
+        assert_eq!(deltas[1], RowDelta::Update { target: RowTarget { index: None, id: Some(json!("A")) }, row: json!({"id": "A"}) });
+        assert_eq!(deltas[2], RowDelta::Delete { target: RowTarget { index: Some(2), id: None } });
+    }
+
+    #[test]
+    fn parse_delta_error_test() {
+        let error = parse::<Vec<RowDelta>>(&json!([{ "op": "insert", "row": {} }, { "op": "delete" }]), "deltas").unwrap_err();
+        assert_eq!(error.code, ErrorCode::MissingProperty);
+        assert_eq!(error.path, "deltas[1].index");
+
+        let error = parse::<Vec<RowDelta>>(&json!([{ "op": "move", "index": 1 }]), "deltas").unwrap_err();
+        assert_eq!(error.path, "deltas[0].op");
+    }
+}
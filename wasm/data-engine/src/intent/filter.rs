@@ -0,0 +1,694 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use chrono::NaiveDateTime;
+use serde_json::Value;
+use crate::date::{parse_reference, parse_time_span};
+use crate::duration::parse_seconds;
+use crate::evaluators::{build_regex, REGEX_FLAGS};
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::intent::Intent;
+
+/// Operators used in a filter expression.
+/// The aliases are accepted when reading the intent, the first name is used when writing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    And,
+    Or,
+    Not,
+    GreaterThan,
+    GreaterOrEqual,
+    LessThan,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+    IsNull,
+    NotNull,
+    Like,
+    NotLike,
+    Matches,
+    NotMatches,
+    In,
+    Between,
+    StartsWith,
+    EndsWith,
+    Contains,
+    ContainsAny,
+    ContainsAll,
+    IsEmpty,
+    Any,
+    All,
+    None,
+    Before,
+    After,
+    On,
+    WithinLast,
+    WithinNext,
+    SameWeek,
+    SameMonth,
+    SameYear
+}
+
+impl Operator {
+    pub fn parse(value: &str) -> Option<Operator> {
+        let result = match value {
+            "and"       | "&&"          => Operator::And,
+            "or"        | "||"          => Operator::Or,
+            "not"       | "!"           => Operator::Not,
+            ">"         | "gt"          => Operator::GreaterThan,
+            ">="        | "ge"          => Operator::GreaterOrEqual,
+            "<"         | "lt"          => Operator::LessThan,
+            "<="        | "le"          => Operator::LessOrEqual,
+            "==" | "="  | "eq"          => Operator::Equal,
+            "!=" | "<>" | "ne" | "neq"  => Operator::NotEqual,
+            "is_null"                   => Operator::IsNull,
+            "not_null"                  => Operator::NotNull,
+            "like"                      => Operator::Like,
+            "not_like"                  => Operator::NotLike,
+            "matches"                   => Operator::Matches,
+            "not_matches"               => Operator::NotMatches,
+            "in"                        => Operator::In,
+            "between"                   => Operator::Between,
+            "startswith" | "starts_with" => Operator::StartsWith,
+            "endswith"  | "ends_with"   => Operator::EndsWith,
+            "contains"                  => Operator::Contains,
+            "contains_any"              => Operator::ContainsAny,
+            "contains_all"              => Operator::ContainsAll,
+            "is_empty"                  => Operator::IsEmpty,
+            "any"                       => Operator::Any,
+            "all"                       => Operator::All,
+            "none"                      => Operator::None,
+            "before"                    => Operator::Before,
+            "after"                     => Operator::After,
+            "on"                        => Operator::On,
+            "within_last"               => Operator::WithinLast,
+            "within_next"               => Operator::WithinNext,
+            "same_week"                 => Operator::SameWeek,
+            "same_month"                => Operator::SameMonth,
+            "same_year"                 => Operator::SameYear,
+            _                           => return None
+        };
+
+        Some(result)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operator::And               => "and",
+            Operator::Or                => "or",
+            Operator::Not               => "not",
+            Operator::GreaterThan       => ">",
+            Operator::GreaterOrEqual    => ">=",
+            Operator::LessThan          => "<",
+            Operator::LessOrEqual       => "<=",
+            Operator::Equal             => "==",
+            Operator::NotEqual          => "!=",
+            Operator::IsNull            => "is_null",
+            Operator::NotNull           => "not_null",
+            Operator::Like              => "like",
+            Operator::NotLike           => "not_like",
+            Operator::Matches           => "matches",
+            Operator::NotMatches        => "not_matches",
+            Operator::In                => "in",
+            Operator::Between           => "between",
+            Operator::StartsWith        => "startswith",
+            Operator::EndsWith          => "endswith",
+            Operator::Contains          => "contains",
+            Operator::ContainsAny       => "contains_any",
+            Operator::ContainsAll       => "contains_all",
+            Operator::IsEmpty           => "is_empty",
+            Operator::Any               => "any",
+            Operator::All               => "all",
+            Operator::None              => "none",
+            Operator::Before            => "before",
+            Operator::After             => "after",
+            Operator::On                => "on",
+            Operator::WithinLast        => "within_last",
+            Operator::WithinNext        => "within_next",
+            Operator::SameWeek          => "same_week",
+            Operator::SameMonth         => "same_month",
+            Operator::SameYear          => "same_year"
+        }
+    }
+
+    /// Logical operators combine child expressions instead of comparing a field.
+    pub fn is_logical(&self) -> bool {
+        matches!(self, Operator::And | Operator::Or | Operator::Not)
+    }
+
+    /// Quantifiers apply child expressions to the items of the array in the field.
+    pub fn is_quantifier(&self) -> bool {
+        matches!(self, Operator::Any | Operator::All | Operator::None)
+    }
+
+    /// Date operators always compare the field as a date, with or without the date type on the comparison.
+    pub fn is_date(&self) -> bool {
+        matches!(self,
+            Operator::Before | Operator::After | Operator::On |
+            Operator::WithinLast | Operator::WithinNext |
+            Operator::SameWeek | Operator::SameMonth | Operator::SameYear)
+    }
+
+    /// The operators that test the field on its own, the same week, month and year operators default to now.
+    pub fn needs_value(&self) -> bool {
+        !matches!(self,
+            Operator::IsNull | Operator::NotNull | Operator::IsEmpty |
+            Operator::SameWeek | Operator::SameMonth | Operator::SameYear)
+    }
+}
+
+impl Serialize for Operator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Operator {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Operator::parse(&value).ok_or_else(|| de::Error::custom(format!("unknown operator '{}'", value)))
+    }
+}
+
+/// Compare the field as a type instead of its json value, for example {"type": "date"}.
+/// Length compares the number of items in an array or characters in a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterType {
+    Date,
+    Duration,
+    Length
+}
+
+/// Which of the values of a projected field such as "lines[*].qty" must pass the comparison.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Quantifier {
+    #[default]
+    Any,
+    All,
+    None
+}
+
+impl Quantifier {
+    pub fn test(&self, items: &[Value], predicate: impl Fn(&Value) -> bool) -> bool {
+        match self {
+            Quantifier::Any => items.iter().any(predicate),
+            Quantifier::All => items.iter().all(predicate),
+            Quantifier::None => !items.iter().any(predicate)
+        }
+    }
+}
+
+/// Compare the field on the row against the value using the operator.
+/// With a value field the value is read from that field on the same row, for example
+/// {"field": "actual_hours", "operator": ">", "value_field": "planned_hours"}.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub field: String,
+    pub operator: Operator,
+    pub value: Value,
+    pub data_type: Option<FilterType>,
+    pub flags: Option<String>,
+    pub quantifier: Option<Quantifier>,
+    pub value_field: Option<String>
+}
+
+impl Comparison {
+    pub fn new(field: &str, operator: Operator, value: Value) -> Comparison {
+        Comparison {
+            field: field.to_string(),
+            operator,
+            value,
+            data_type: None,
+            flags: None,
+            quantifier: None,
+            value_field: None
+        }
+    }
+
+    /// Compare the field against another field of the same row instead of a value.
+    pub fn with_field(field: &str, operator: Operator, value_field: &str) -> Comparison {
+        Comparison {
+            value_field: Some(value_field.to_string()),
+            ..Comparison::new(field, operator, Value::Null)
+        }
+    }
+
+    pub fn with_type(mut self, data_type: FilterType) -> Comparison {
+        self.data_type = Some(data_type);
+        self
+    }
+
+    /// Regex flags for the matches operators, for example "i" for case insensitive.
+    pub fn with_flags(mut self, flags: &str) -> Comparison {
+        self.flags = Some(flags.to_string());
+        self
+    }
+
+    /// Any or all of the values of a projected field must pass, any when not set.
+    pub fn with_quantifier(mut self, quantifier: Quantifier) -> Comparison {
+        self.quantifier = Some(quantifier);
+        self
+    }
+
+    /// The field and value are read as dates.
+    pub fn is_date(&self) -> bool {
+        match self.data_type {
+            Some(FilterType::Date) => true,
+            Some(_) => false,
+            None => self.operator.is_date()
+        }
+    }
+
+    /// The field and value are ISO 8601 durations compared on their length.
+    pub fn is_duration(&self) -> bool {
+        self.data_type == Some(FilterType::Duration)
+    }
+
+    /// The length of the field is compared to the value.
+    pub fn is_length(&self) -> bool {
+        self.data_type == Some(FilterType::Length)
+    }
+
+    /// The operators that read the intent value need it in the right shape, else the evaluators can't do their work.
+    /// The value of a value field is only known on the row, only the operator is checked.
+    pub fn check(&self) -> DataResult<()> {
+        if self.value_field.is_some() {
+            return self.check_value_field();
+        }
+
+        if self.value.get(FIELD_REFERENCE).is_some() {
+            return Err(DataError::invalid_type("value.$field", "a field name"));
+        }
+
+        if self.is_duration() {
+            return self.check_duration();
+        }
+
+        if self.is_date() {
+            return self.check_date();
+        }
+
+        if self.is_length() {
+            return self.check_length();
+        }
+
+        match self.operator {
+            Operator::Between => {
+                match self.value.as_array() {
+                    Some(values) if values.len() == 2 && values.iter().all(is_single) => Ok(()),
+                    _ => Err(DataError::new(ErrorCode::InvalidValue, "value", "between requires an array of two values".to_string()))
+                }
+            }
+            Operator::In | Operator::ContainsAny | Operator::ContainsAll => check_values(&self.value, self.operator),
+            Operator::Like | Operator::NotLike | Operator::StartsWith | Operator::EndsWith => {
+                match self.value.is_string() {
+                    true => Ok(()),
+                    false => Err(DataError::invalid_type("value", "a string"))
+                }
+            }
+            Operator::Contains => {
+                match self.value.is_null() || self.value.is_array() || self.value.is_object() {
+                    true => Err(DataError::invalid_type("value", "a string, number or boolean")),
+                    false => Ok(())
+                }
+            }
+            Operator::Matches | Operator::NotMatches => self.check_regex(),
+            Operator::IsNull | Operator::NotNull | Operator::IsEmpty => Ok(()),
+            _ => {
+                match is_single(&self.value) {
+                    true => Ok(()),
+                    false => Err(DataError::invalid_type("value", "a single value"))
+                }
+            }
+        }
+    }
+
+    /// A value field gives one value per row, operators that need a list, a pattern or no value can't use it.
+    fn check_value_field(&self) -> DataResult<()> {
+        if !self.value.is_null() {
+            return Err(DataError::new(ErrorCode::InvalidValue, "value_field", "use either value or value_field, not both".to_string()));
+        }
+
+        let comparison = matches!(self.operator,
+            Operator::GreaterThan | Operator::GreaterOrEqual | Operator::LessThan |
+            Operator::LessOrEqual | Operator::Equal | Operator::NotEqual);
+
+        let allowed = if self.is_duration() || self.is_length() {
+            comparison
+        }
+        else if self.is_date() {
+            comparison || matches!(self.operator,
+                Operator::Before | Operator::After | Operator::On | Operator::WithinLast | Operator::WithinNext |
+                Operator::SameWeek | Operator::SameMonth | Operator::SameYear)
+        }
+        else {
+            comparison || matches!(self.operator, Operator::StartsWith | Operator::EndsWith | Operator::Contains)
+        };
+
+        match allowed {
+            true => Ok(()),
+            false => Err(DataError::new(ErrorCode::InvalidValue, "operator", format!("{} can't compare against a value_field", self.operator.as_str())))
+        }
+    }
+
+    /// The pattern is compiled once with the filter, a pattern that doesn't compile would never match.
+    fn check_regex(&self) -> DataResult<()> {
+        let pattern = self.value.as_str().ok_or_else(|| DataError::invalid_type("value", "a string"))?;
+        let flags = self.flags.as_deref().unwrap_or_default();
+
+        if let Some(flag) = flags.chars().find(|flag| !REGEX_FLAGS.contains(flag)) {
+            let message = format!("unknown regex flag '{}', expected one of i, m, s, x", flag);
+            return Err(DataError::new(ErrorCode::InvalidValue, "flags", message));
+        }
+
+        match build_regex(pattern, flags, true) {
+            Ok(_) => Ok(()),
+            Err(message) => Err(DataError::new(ErrorCode::InvalidValue, "value", message))
+        }
+    }
+
+    /// Date values are parsed when the filter is compiled, one that is not a date would never match.
+    fn check_date(&self) -> DataResult<()> {
+        match self.operator {
+            Operator::IsNull | Operator::NotNull => Ok(()),
+            Operator::SameWeek | Operator::SameMonth | Operator::SameYear if self.value.is_null() => Ok(()),
+            Operator::WithinLast | Operator::WithinNext => {
+                match parse_time_span(&self.value) {
+                    Some(_) => Ok(()),
+                    None => Err(DataError::new(ErrorCode::InvalidValue, "value", format!("{} is not an ISO 8601 duration such as \"P7D\"", self.value)))
+                }
+            }
+            Operator::Between => {
+                match self.value.as_array() {
+                    Some(values) if values.len() == 2 => check_dates(values),
+                    _ => Err(DataError::new(ErrorCode::InvalidValue, "value", "between requires an array of two values".to_string()))
+                }
+            }
+            Operator::In => {
+                match self.value.as_array() {
+                    Some(values) => check_dates(values),
+                    None => Err(DataError::invalid_type("value", "an array"))
+                }
+            }
+            Operator::Like | Operator::NotLike | Operator::Matches | Operator::NotMatches |
+            Operator::StartsWith | Operator::EndsWith | Operator::Contains |
+            Operator::ContainsAny | Operator::ContainsAll | Operator::IsEmpty => {
+                Err(DataError::new(ErrorCode::InvalidValue, "operator", format!("{} can't be used on dates", self.operator.as_str())))
+            }
+            _ => check_date(&self.value).map_err(|error| error.at("value"))
+        }
+    }
+
+    /// Durations only support the comparison operators, the values must parse as durations.
+    fn check_duration(&self) -> DataResult<()> {
+        match self.operator {
+            Operator::IsNull | Operator::NotNull => Ok(()),
+            Operator::GreaterThan | Operator::GreaterOrEqual | Operator::LessThan |
+            Operator::LessOrEqual | Operator::Equal | Operator::NotEqual => {
+                check_duration(&self.value).map_err(|error| error.at("value"))
+            }
+            Operator::Between => {
+                match self.value.as_array() {
+                    Some(values) if values.len() == 2 => check_durations(values),
+                    _ => Err(DataError::new(ErrorCode::InvalidValue, "value", "between requires an array of two values".to_string()))
+                }
+            }
+            Operator::In => {
+                match self.value.as_array() {
+                    Some(values) => check_durations(values),
+                    None => Err(DataError::invalid_type("value", "an array"))
+                }
+            }
+            _ => Err(DataError::new(ErrorCode::InvalidValue, "operator", format!("{} can't be used on durations", self.operator.as_str())))
+        }
+    }
+
+    /// Lengths are counts, only the comparison operators on numbers make sense.
+    fn check_length(&self) -> DataResult<()> {
+        match self.operator {
+            Operator::GreaterThan | Operator::GreaterOrEqual | Operator::LessThan |
+            Operator::LessOrEqual | Operator::Equal | Operator::NotEqual => {
+                check_length(&self.value).map_err(|error| error.at("value"))
+            }
+            Operator::Between => {
+                match self.value.as_array() {
+                    Some(values) if values.len() == 2 => check_lengths(values),
+                    _ => Err(DataError::new(ErrorCode::InvalidValue, "value", "between requires an array of two values".to_string()))
+                }
+            }
+            Operator::In => {
+                match self.value.as_array() {
+                    Some(values) => check_lengths(values),
+                    None => Err(DataError::invalid_type("value", "an array"))
+                }
+            }
+            _ => Err(DataError::new(ErrorCode::InvalidValue, "operator", format!("{} can't be used on lengths", self.operator.as_str())))
+        }
+    }
+}
+
+/// A value that is not a list or an object.
+fn is_single(value: &Value) -> bool {
+    !value.is_array() && !value.is_object()
+}
+
+/// The list of options of in, contains_any and contains_all.
+fn check_values(value: &Value, operator: Operator) -> DataResult<()> {
+    match value.as_array() {
+        None => Err(DataError::invalid_type("value", "an array")),
+        Some(values) if !values.iter().all(is_single) => {
+            Err(DataError::new(ErrorCode::InvalidValue, "value", format!("{} requires an array of single values", operator.as_str())))
+        }
+        Some(_) => Ok(())
+    }
+}
+
+fn check_length(value: &Value) -> DataResult<()> {
+    match value.is_u64() {
+        true => Ok(()),
+        false => Err(DataError::invalid_type("", "a whole number"))
+    }
+}
+
+fn check_lengths(values: &[Value]) -> DataResult<()> {
+    for (index, value) in values.iter().enumerate() {
+        check_length(value).map_err(|error| error.at(&format!("[{}]", index)).at("value"))?;
+    }
+
+    Ok(())
+}
+
+fn check_duration(value: &Value) -> DataResult<()> {
+    match parse_seconds(value) {
+        Some(_) => Ok(()),
+        None => Err(DataError::new(ErrorCode::InvalidValue, "", format!("{} is not an ISO 8601 duration", value)))
+    }
+}
+
+fn check_durations(values: &[Value]) -> DataResult<()> {
+    for (index, value) in values.iter().enumerate() {
+        check_duration(value).map_err(|error| error.at(&format!("[{}]", index)).at("value"))?;
+    }
+
+    Ok(())
+}
+
+fn check_date(value: &Value) -> DataResult<()> {
+    match parse_reference(value, NaiveDateTime::default()) {
+        Some(_) => Ok(()),
+        None => Err(DataError::new(ErrorCode::InvalidValue, "", format!("{} is not a date", value)))
+    }
+}
+
+fn check_dates(values: &[Value]) -> DataResult<()> {
+    for (index, value) in values.iter().enumerate() {
+        check_date(value).map_err(|error| error.at(&format!("[{}]", index)).at("value"))?;
+    }
+
+    Ok(())
+}
+
+/// A filter expression is either a comparison on a field or a logical operator over child expressions.
+/// In json both use the same object, for example {"operator": "or", "expressions": [...]}.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawExpression", into = "RawExpression")]
+pub enum FilterExpr {
+    Comparison(Comparison),
+    And(Vec<FilterExpr>),
+    Or(Vec<FilterExpr>),
+    Not(Vec<FilterExpr>),
+    Quantified(Quantified)
+}
+
+/// Apply the expressions to each item of the array in the field, for example
+/// {"operator": "any", "field": "lines", "expressions": [{"field": "qty", "operator": ">", "value": 5}]}.
+/// The fields of the expressions are read from the item, an empty field is the item itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantified {
+    pub field: String,
+    pub quantifier: Quantifier,
+    pub expressions: Vec<FilterExpr>
+}
+
+impl Quantified {
+    pub fn new(field: &str, quantifier: Quantifier, expressions: Vec<FilterExpr>) -> Quantified {
+        Quantified {
+            field: field.to_string(),
+            quantifier,
+            expressions
+        }
+    }
+}
+
+impl Intent for FilterExpr {
+    fn check(&self) -> DataResult<()> {
+        match self {
+            FilterExpr::Comparison(comparison) => comparison.check(),
+            FilterExpr::Quantified(quantified) => {
+                quantified.expressions.check().map_err(|error| error.at("expressions"))
+            }
+            FilterExpr::And(expressions) |
+            FilterExpr::Or(expressions) |
+            FilterExpr::Not(expressions) => {
+                expressions.check().map_err(|error| error.at("expressions"))
+            }
+        }
+    }
+}
+
+/// The property of a value object that refers to another field of the row.
+pub const FIELD_REFERENCE: &str = "$field";
+
+/// The json shape of a filter expression, only used while reading and writing the intent.
+#[derive(Serialize, Deserialize)]
+struct RawExpression {
+    operator: Operator,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    field: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<Value>,
+
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    data_type: Option<FilterType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    flags: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    quantifier: Option<Quantifier>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value_field: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expressions: Option<Vec<FilterExpr>>
+}
+
+impl TryFrom<RawExpression> for FilterExpr {
+    type Error = String;
+
+    fn try_from(raw: RawExpression) -> Result<Self, Self::Error> {
+        if raw.operator.is_logical() {
+            let expressions = raw.expressions.ok_or("missing field `expressions`")?;
+
+            let result = match raw.operator {
+                Operator::And => FilterExpr::And(expressions),
+                Operator::Or  => FilterExpr::Or(expressions),
+                _             => FilterExpr::Not(expressions)
+            };
+
+            return Ok(result);
+        }
+
+        let field = raw.field.ok_or("missing field `field`")?;
+
+        if raw.operator.is_quantifier() {
+            let expressions = raw.expressions.ok_or("missing field `expressions`")?;
+
+            let quantifier = match raw.operator {
+                Operator::Any => Quantifier::Any,
+                Operator::All => Quantifier::All,
+                _             => Quantifier::None
+            };
+
+            return Ok(FilterExpr::Quantified(Quantified { field, quantifier, expressions }));
+        }
+
+        if raw.value.is_none() && raw.value_field.is_none() && raw.operator.needs_value() {
+            return Err("missing field `value`".to_string());
+        }
+
+        let mut value = raw.value.unwrap_or(Value::Null);
+        let mut value_field = raw.value_field;
+
+        // {"value": {"$field": "planned_hours"}} is the same as {"value_field": "planned_hours"},
+        // a reference that is not a field name or next to a value field is left for the check to report
+        if let Some(reference) = value.get(FIELD_REFERENCE).and_then(Value::as_str).filter(|_| value_field.is_none()) {
+            value_field = Some(reference.to_string());
+            value = Value::Null;
+        }
+
+        Ok(FilterExpr::Comparison(Comparison {
+            field,
+            operator: raw.operator,
+            value,
+            data_type: raw.data_type,
+            flags: raw.flags,
+            quantifier: raw.quantifier,
+            value_field
+        }))
+    }
+}
+
+impl From<FilterExpr> for RawExpression {
+    fn from(expression: FilterExpr) -> Self {
+        let (operator, expressions) = match expression {
+            FilterExpr::Comparison(comparison) => {
+                return RawExpression {
+                    operator: comparison.operator,
+                    field: Some(comparison.field),
+                    value: Some(comparison.value).filter(|value| !value.is_null() || (comparison.operator.needs_value() && comparison.value_field.is_none())),
+                    data_type: comparison.data_type,
+                    flags: comparison.flags,
+                    quantifier: comparison.quantifier,
+                    value_field: comparison.value_field,
+                    expressions: None
+                };
+            }
+            FilterExpr::Quantified(quantified) => {
+                let operator = match quantified.quantifier {
+                    Quantifier::Any  => Operator::Any,
+                    Quantifier::All  => Operator::All,
+                    Quantifier::None => Operator::None
+                };
+
+                return RawExpression {
+                    operator,
+                    field: Some(quantified.field),
+                    value: None,
+                    data_type: None,
+                    flags: None,
+                    quantifier: None,
+                    value_field: None,
+                    expressions: Some(quantified.expressions)
+                };
+            }
+            FilterExpr::And(expressions) => (Operator::And, expressions),
+            FilterExpr::Or(expressions)  => (Operator::Or, expressions),
+            FilterExpr::Not(expressions) => (Operator::Not, expressions)
+        };
+
+        RawExpression {
+            operator,
+            field: None,
+            value: None,
+            data_type: None,
+            flags: None,
+            quantifier: None,
+            value_field: None,
+            expressions: Some(expressions)
+        }
+    }
+}
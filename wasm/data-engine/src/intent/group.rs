@@ -0,0 +1,324 @@
+use std::fmt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{MapAccess, Visitor};
+use serde::de::value::MapAccessDeserializer;
+use serde_json::Value;
+use crate::collation::Collation;
+use crate::enums::{NullOrder, SortDirection};
+use crate::error::DataResult;
+use crate::expression::to_number;
+use crate::intent::{AggregateSpec, Aggregates, Bucket, Intent, SortField, SortType};
+
+/// What the groups of a level are ordered on.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum GroupOrder {
+    /// The value the rows of the group have in common.
+    #[default]
+    Key,
+
+    /// The number of rows in the group.
+    Count,
+
+    /// An aggregate of the rows in the group, in json an object such as {"sum": "value"}.
+    Aggregate(AggregateSpec)
+}
+
+/// A level in the group hierarchy, the first spec is the top level.
+/// In json this is the name of the field to group on, or an object with the field and how the groups are ordered:
+/// { "field": "site", "order_by": "count", "direction": "desc" }.
+/// The sort options of the object (type, direction, nulls, collation, formats and order) are those of a sort field.
+/// Null and missing values are in one group, labeled "null" unless the object has a null_label.
+/// With case_insensitive strings that only differ in case are in the same group, the key of the group is in lower case.
+/// A bucket groups on ranges of the values, { "field": "created", "bucket": { "date": "month" } }.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupSpec {
+    pub field: String,
+    pub order_by: GroupOrder,
+
+    /// How the groups compare, the groups are in ascending order of their key when there is none.
+    pub sort: Option<SortField>,
+
+    pub case_insensitive: bool,
+    pub null_label: Option<String>,
+    pub bucket: Option<Bucket>
+}
+
+/// The label of the group of null and missing values when the spec does not have one.
+pub const NULL_LABEL: &str = "null";
+
+impl GroupSpec {
+    pub fn with_order(mut self, order_by: GroupOrder) -> GroupSpec {
+        self.order_by = order_by;
+        self
+    }
+
+    pub fn with_sort(mut self, sort: SortField) -> GroupSpec {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn with_case_insensitive(mut self) -> GroupSpec {
+        self.case_insensitive = true;
+        self
+    }
+
+    pub fn with_null_label(mut self, label: &str) -> GroupSpec {
+        self.null_label = Some(label.to_string());
+        self
+    }
+
+    pub fn with_bucket(mut self, bucket: Bucket) -> GroupSpec {
+        self.bucket = Some(bucket);
+        self
+    }
+
+    /// The value of the field as the group key, whole numbers are integers so 10 and 10.0 are the same group.
+    /// Strings are in lower case when the spec is case insensitive, with a bucket the key is the key of the bucket.
+    pub fn key_value(&self, value: Value) -> Value {
+        if let Some(bucket) = &self.bucket {
+            let formats = self.sort.as_ref().and_then(|sort| sort.formats.as_deref()).unwrap_or_default();
+            return bucket.key(&value, formats);
+        }
+
+        match value {
+            Value::String(text) if self.case_insensitive => Value::String(text.to_lowercase()),
+            Value::Number(number) if number.is_f64() => number.as_f64().map(to_number).unwrap_or(Value::Null),
+            value => value
+        }
+    }
+
+    /// The text shown for a group with the key, strings are used as is and other values as json.
+    /// Buckets label their ranges, "10–20".
+    pub fn label(&self, key: &Value) -> String {
+        match (key, &self.bucket) {
+            (Value::Null, _) => self.null_label.as_deref().unwrap_or(NULL_LABEL).to_string(),
+            (key, Some(bucket)) => bucket.label(key),
+            (Value::String(text), None) => text.clone(),
+            (key, None) => key.to_string()
+        }
+    }
+
+    /// Groups on a field the rows are sorted on follow the sort, unless the spec has an order of its own.
+    pub fn follow_sort(&self, sort: &[SortField]) -> GroupSpec {
+        let field = sort.iter().find(|field| field.name == self.field);
+
+        match (&self.order_by, &self.sort, field) {
+            (GroupOrder::Key, None, Some(field)) => self.clone().with_sort(field.clone()),
+            _ => self.clone()
+        }
+    }
+
+    /// How the values the groups are ordered on compare.
+    /// Counts, aggregates and bucket keys only take the direction and null order from the sort options.
+    pub fn order_field(&self) -> SortField {
+        let sort = self.sort.clone().unwrap_or_else(|| SortField::new(&self.field, None, SortDirection::Ascending));
+
+        match self.order_by {
+            GroupOrder::Key if self.bucket.is_none() => sort,
+            _ => {
+                let mut field = SortField::new(&self.field, None, sort.direction);
+                field.nulls = sort.nulls;
+                field
+            }
+        }
+    }
+}
+
+impl From<String> for GroupSpec {
+    fn from(field: String) -> Self {
+        GroupSpec {
+            field,
+            order_by: GroupOrder::Key,
+            sort: None,
+            case_insensitive: false,
+            null_label: None,
+            bucket: None
+        }
+    }
+}
+
+impl From<&str> for GroupSpec {
+    fn from(field: &str) -> Self {
+        GroupSpec::from(field.to_string())
+    }
+}
+
+impl Intent for GroupSpec {
+    fn check(&self) -> DataResult<()> {
+        if let Some(sort) = &self.sort {
+            sort.check()?;
+        }
+
+        match &self.bucket {
+            None => Ok(()),
+            Some(bucket) => bucket.check().map_err(|error| error.at("bucket"))
+        }
+    }
+}
+
+/// The object form of a group spec.
+#[derive(Serialize, Deserialize)]
+struct GroupObject {
+    field: String,
+
+    #[serde(default, skip_serializing_if = "is_key")]
+    order_by: GroupOrder,
+
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    data_type: Option<SortType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    direction: Option<SortDirection>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nulls: Option<NullOrder>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    collation: Option<Collation>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    formats: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    order: Option<Vec<Value>>,
+
+    #[serde(default, skip_serializing_if = "is_false")]
+    case_insensitive: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    null_label: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bucket: Option<Bucket>
+}
+
+fn is_key(order_by: &GroupOrder) -> bool {
+    *order_by == GroupOrder::Key
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl From<GroupObject> for GroupSpec {
+    fn from(object: GroupObject) -> Self {
+        let has_sort = object.data_type.is_some() || object.direction.is_some() || object.nulls.is_some()
+            || object.collation.is_some() || object.formats.is_some() || object.order.is_some();
+
+        let sort = match has_sort {
+            false => None,
+            true => Some(SortField {
+                name: object.field.clone(),
+                data_type: object.data_type,
+                direction: object.direction.unwrap_or_default(),
+                nulls: object.nulls,
+                collation: object.collation,
+                formats: object.formats,
+                order: object.order
+            })
+        };
+
+        GroupSpec {
+            field: object.field,
+            order_by: object.order_by,
+            sort,
+            case_insensitive: object.case_insensitive,
+            null_label: object.null_label,
+            bucket: object.bucket
+        }
+    }
+}
+
+impl Serialize for GroupSpec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let plain = !self.case_insensitive && self.null_label.is_none() && self.bucket.is_none();
+
+        let sort = match (&self.order_by, &self.sort) {
+            (GroupOrder::Key, None) if plain => return serializer.serialize_str(&self.field),
+            (_, sort) => sort.clone()
+        };
+
+        let object = GroupObject {
+            field: self.field.clone(),
+            order_by: self.order_by.clone(),
+            data_type: sort.as_ref().and_then(|sort| sort.data_type),
+            direction: sort.as_ref().map(|sort| sort.direction),
+            nulls: sort.as_ref().and_then(|sort| sort.nulls),
+            collation: sort.as_ref().and_then(|sort| sort.collation),
+            formats: sort.as_ref().and_then(|sort| sort.formats.clone()),
+            order: sort.and_then(|sort| sort.order),
+            case_insensitive: self.case_insensitive,
+            null_label: self.null_label.clone(),
+            bucket: self.bucket.clone()
+        };
+
+        object.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupSpec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(GroupSpecVisitor)
+    }
+}
+
+struct GroupSpecVisitor;
+
+impl<'de> Visitor<'de> for GroupSpecVisitor {
+    type Value = GroupSpec;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a field name or a group object")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(GroupSpec::from(value))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        GroupObject::deserialize(MapAccessDeserializer::new(map)).map(GroupSpec::from)
+    }
+}
+
+impl Serialize for GroupOrder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            GroupOrder::Key => serializer.serialize_str("key"),
+            GroupOrder::Count => serializer.serialize_str("count"),
+            GroupOrder::Aggregate(spec) => Aggregates(vec![spec.clone()]).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(GroupOrderVisitor)
+    }
+}
+
+struct GroupOrderVisitor;
+
+impl<'de> Visitor<'de> for GroupOrderVisitor {
+    type Value = GroupOrder;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("key, count or an aggregate object")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        match value {
+            "key" => Ok(GroupOrder::Key),
+            "count" => Ok(GroupOrder::Count),
+            _ => Err(E::custom(format!("unknown group order '{}', expected key, count or an aggregate", value)))
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let mut aggregates = Aggregates::deserialize(MapAccessDeserializer::new(map))?;
+
+        match aggregates.0.len() {
+            1 => Ok(GroupOrder::Aggregate(aggregates.0.remove(0))),
+            _ => Err(de::Error::custom("the group order is one aggregate"))
+        }
+    }
+}
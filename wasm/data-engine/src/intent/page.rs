@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::intent::Intent;
+
+/// A window on the sorted rows, the rows from offset up to the limit.
+/// Without a limit the page runs to the last row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Page {
+    #[serde(default)]
+    pub offset: usize,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>
+}
+
+impl Page {
+    pub fn new(offset: usize, limit: Option<usize>) -> Page {
+        Page {
+            offset,
+            limit
+        }
+    }
+
+    /// The position after the last row of the page in a list of the given length.
+    pub fn end(&self, length: usize) -> usize {
+        match self.limit {
+            None => length,
+            Some(limit) => self.offset.saturating_add(limit).min(length)
+        }
+    }
+
+    /// A page is a window on the rows, a group or aggregates don't give back rows to page through.
+    /// The error is on the page property of the perspective.
+    pub fn check_use(group: bool, aggregates: bool) -> DataResult<()> {
+        match group || aggregates {
+            true => Err(DataError::new(ErrorCode::InvalidValue, "page", "a page can't be used with group or aggregates".to_string())),
+            false => Ok(())
+        }
+    }
+
+    /// The rows of the page out of all the rows.
+    pub fn apply(&self, mut rows: Vec<usize>) -> Vec<usize> {
+        let end = self.end(rows.len());
+        rows.truncate(end);
+        rows.drain(..self.offset.min(end));
+        rows
+    }
+}
+
+impl Intent for Page {}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::intent::{Intent, ComputedField, FilterExpr, SortField, GroupSpec, Aggregates, Page};
+
+/// How the data must be presented: filter the rows, sort them and then group or aggregate them.
+/// Every part is optional, properties that are not part of the model are ignored.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Perspective {
+    /// Fields calculated first so the other parts can use them, in order so one can use those before it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub computed: Option<Vec<ComputedField>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Vec<FilterExpr>>,
+
+    #[serde(default)]
+    pub case_sensitive: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<Vec<SortField>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<Vec<GroupSpec>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregates: Option<Aggregates>,
+
+    /// Only for row results, the result is then the rows of the page and the total number of rows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<Page>
+}
+
+impl Intent for Perspective {
+    fn check(&self) -> DataResult<()> {
+        if let Some(computed) = &self.computed {
+            computed.check().map_err(|error| error.at("computed"))?;
+
+            for (index, field) in computed.iter().enumerate() {
+                if computed[..index].iter().any(|other| other.name == field.name) {
+                    let message = format!("the computed field '{}' is defined more than once", field.name);
+                    return Err(DataError::new(ErrorCode::InvalidValue, &format!("computed[{}].name", index), message));
+                }
+            }
+        }
+
+        if let Some(filter) = &self.filter {
+            filter.check().map_err(|error| error.at("filter"))?;
+        }
+
+        if let Some(sort) = &self.sort {
+            sort.check().map_err(|error| error.at("sort"))?;
+        }
+
+        if let Some(group) = &self.group {
+            group.check().map_err(|error| error.at("group"))?;
+        }
+
+        if self.page.is_some() {
+            Page::check_use(self.group.is_some(), self.aggregates.is_some())?;
+        }
+
+        Ok(())
+    }
+}
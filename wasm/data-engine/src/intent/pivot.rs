@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::intent::{AggregateSpec, GroupSpec, Intent};
+
+/// A cross tab of the rows: the groups of the row fields down, the groups of the column fields across
+/// and the values aggregated where they meet, for example
+/// { "rows": ["site"], "columns": [{ "field": "date", "bucket": { "date": "year" } }], "values": [{ "agg": "sum", "field": "cost" }] }.
+/// The group specs are the same as those of the group intent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Pivot {
+    #[serde(default)]
+    pub rows: Vec<GroupSpec>,
+
+    #[serde(default)]
+    pub columns: Vec<GroupSpec>,
+
+    pub values: Vec<AggregateSpec>
+}
+
+impl Pivot {
+    pub fn new(rows: Vec<GroupSpec>, columns: Vec<GroupSpec>, values: Vec<AggregateSpec>) -> Pivot {
+        Pivot {
+            rows,
+            columns,
+            values
+        }
+    }
+}
+
+impl Intent for Pivot {
+    fn check(&self) -> DataResult<()> {
+        self.rows.check().map_err(|error| error.at("rows"))?;
+        self.columns.check().map_err(|error| error.at("columns"))?;
+
+        if self.values.is_empty() {
+            return Err(DataError::new(ErrorCode::InvalidValue, "values", "at least one value is required".to_string()));
+        }
+
+        Ok(())
+    }
+}
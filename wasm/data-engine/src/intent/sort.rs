@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::collation::Collation;
+use crate::date::is_date_format;
+use crate::enums::{NullOrder, SortDirection};
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::intent::Intent;
+
+/// Special handling for values that don't sort on their json value.
+/// Values that can't be read as the type sort as nulls, except durations that sort as a zero duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortType {
+    Duration,
+
+    /// Dates in the formats of the field or the formats parse_date reads.
+    Date,
+
+    /// Numbers and strings holding a number such as "10.5".
+    Number,
+
+    /// Booleans, "true", "false", "yes", "no" and numbers where zero is false.
+    Boolean,
+
+    /// Values in the order of the field, values that are not in the order come after them.
+    Enum
+}
+
+/// Field to sort on, the first field in the sort intent has the highest priority.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SortField {
+    pub name: String,
+
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<SortType>,
+
+    #[serde(default)]
+    pub direction: SortDirection,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<NullOrder>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collation: Option<Collation>,
+
+    /// Chrono formats for a date field, tried in order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formats: Option<Vec<String>>,
+
+    /// The values of an enum field from first to last in an ascending sort.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<Vec<Value>>
+}
+
+impl SortField {
+    pub fn new(name: &str, data_type: Option<SortType>, direction: SortDirection) -> SortField {
+        SortField {
+            name: name.to_string(),
+            data_type,
+            direction,
+            nulls: None,
+            collation: None,
+            formats: None,
+            order: None
+        }
+    }
+
+    pub fn with_nulls(mut self, nulls: NullOrder) -> SortField {
+        self.nulls = Some(nulls);
+        self
+    }
+
+    pub fn with_collation(mut self, collation: Collation) -> SortField {
+        self.collation = Some(collation);
+        self
+    }
+
+    pub fn with_formats(mut self, formats: &[&str]) -> SortField {
+        self.formats = Some(formats.iter().map(|format| format.to_string()).collect());
+        self
+    }
+
+    pub fn with_order(mut self, order: Vec<Value>) -> SortField {
+        self.order = Some(order);
+        self
+    }
+}
+
+impl Intent for SortField {
+    fn check(&self) -> DataResult<()> {
+        if self.data_type == Some(SortType::Enum) && self.order.is_none() {
+            return Err(DataError::missing_property("order", "order"));
+        }
+
+        if let Some(formats) = &self.formats {
+            if let Some(index) = formats.iter().position(|format| !is_date_format(format)) {
+                let message = format!("'{}' is not a valid date format", formats[index]);
+                return Err(DataError::new(ErrorCode::InvalidValue, &format!("formats[{}]", index), message));
+            }
+        }
+
+        Ok(())
+    }
+}
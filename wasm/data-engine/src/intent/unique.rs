@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use crate::collation::Collation;
+use crate::intent::Intent;
+
+/// How the unique values of a field are read and ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueType {
+    #[default]
+    String,
+    Long,
+    Number,
+    Boolean,
+    Duration
+}
+
+impl ValueType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValueType::String   => "string",
+            ValueType::Long     => "long",
+            ValueType::Number   => "number",
+            ValueType::Boolean  => "boolean",
+            ValueType::Duration => "duration"
+        }
+    }
+}
+
+/// Field to collect the unique values for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UniqueField {
+    pub name: String,
+
+    #[serde(rename = "type", default)]
+    pub data_type: ValueType,
+
+    /// Orders the values, values that only differ in case or accents are still counted apart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collation: Option<Collation>
+}
+
+impl UniqueField {
+    pub fn new(name: &str, data_type: ValueType) -> UniqueField {
+        UniqueField {
+            name: name.to_string(),
+            data_type,
+            collation: None
+        }
+    }
+
+    pub fn with_collation(mut self, collation: Collation) -> UniqueField {
+        self.collation = Some(collation);
+        self
+    }
+}
+
+impl Intent for UniqueField {}
@@ -0,0 +1,40 @@
+//! The filter, sort, group, aggregate and perspective engine shared by the wasm modules.
+//! Processors read rows through the Row trait so the same code runs on json values and javascript objects.
+//!
+//! Rust services use the functions at the root of the crate with intents read by `intent::parse`,
+//! the results are the same json the browser gets.
+//!
+//! ```
+//! use serde_json::json;
+//! use data_engine::intent;
+//! use data_engine::intent::Perspective;
+//!
+//! let data = vec![json!({"code": "B", "value": 20}), json!({"code": "A", "value": 10})];
+//! let perspective: Perspective = intent::parse(&json!({ "sort": [{ "name": "code" }] }), "").unwrap();
+//!
+//! assert_eq!(data_engine::perspective(&data, &perspective, None).unwrap(), json!([1, 0]));
+//! ```
+
+mod macros;
+mod api;
+
+pub mod evaluators;
+pub mod path;
+pub mod expression;
+pub mod processors;
+pub mod utils;
+pub mod duration;
+pub mod date;
+pub mod enums;
+pub mod compare;
+pub mod collation;
+mod aggregates;
+mod traits;
+pub mod error;
+pub mod validate;
+pub mod intent;
+pub mod columnar;
+pub mod row;
+
+pub use row::Row;
+pub use api::{filter, sort, sort_page, group, aggregate, unique, perspective, pivot};
@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+use serde_json::Value;
+
+/// A step from a value down to one of its children.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Segment {
+    Key(String),
+    Index(usize),
+
+    /// Every item of an array, written as [*].
+    Each
+}
+
+/// A field name resolved once so rows don't have to parse the path again.
+/// Fields are property names separated by dots, with array indexes such as "lines[0].qty".
+/// "lines[*].qty" projects the quantity of every line into an array.
+/// A dot or bracket that is part of a property name is escaped with a backslash, "size\.cm".
+/// An empty field is the value itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldPath {
+    segments: Vec<Segment>
+}
+
+impl FieldPath {
+    pub fn new(field: &str) -> FieldPath {
+        let mut segments = Vec::new();
+        let mut key = String::new();
+        let mut chars = field.chars();
+
+        while let Some(char) = chars.next() {
+            match char {
+                '\\' => key.extend(chars.next()),
+                '.' => push_key(&mut segments, &mut key),
+                '[' => {
+                    let mut inner = String::new();
+                    let mut closed = false;
+
+                    for char in chars.by_ref() {
+                        if char == ']' {
+                            closed = true;
+                            break;
+                        }
+
+                        inner.push(char);
+                    }
+
+                    let segment = match (closed, inner.as_str()) {
+                        (true, "*") => Some(Segment::Each),
+                        (true, index) => index.parse::<usize>().ok().map(Segment::Index),
+                        _ => None
+                    };
+
+                    match segment {
+                        Some(segment) => {
+                            push_key(&mut segments, &mut key);
+                            segments.push(segment);
+                        }
+                        None => {
+                            // not an index, the brackets are part of the name
+                            key.push('[');
+                            key.push_str(&inner);
+
+                            if closed {
+                                key.push(']');
+                            }
+                        }
+                    }
+                }
+                _ => key.push(char)
+            }
+        }
+
+        push_key(&mut segments, &mut key);
+
+        FieldPath {
+            segments
+        }
+    }
+
+    /// The value on the row, a missing field reads as null.
+    pub fn get<'a>(&self, row: &'a Value) -> Cow<'a, Value> {
+        self.find(row).unwrap_or(Cow::Owned(Value::Null))
+    }
+
+    /// The value on the row or None when the row does not have the field.
+    /// A projection gives an array of the values found on the items, items without the field are left out.
+    pub fn find<'a>(&self, row: &'a Value) -> Option<Cow<'a, Value>> {
+        resolve(row, &self.segments)
+    }
+
+    /// The steps from the row down to the field, for rows that are not json.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Does the path read every item of an array, the value is then an array of the values on the items.
+    pub fn is_projection(&self) -> bool {
+        self.segments.contains(&Segment::Each)
+    }
+}
+
+/// Follow the segments from the value, rows that are not json use it for the segments after a projection.
+pub fn resolve<'a>(value: &'a Value, segments: &[Segment]) -> Option<Cow<'a, Value>> {
+    let mut current = value;
+
+    for (position, segment) in segments.iter().enumerate() {
+        current = match segment {
+            Segment::Key(key) => current.get(key)?,
+            Segment::Index(index) => current.get(index)?,
+            Segment::Each => {
+                let rest = &segments[position + 1..];
+                let nested = rest.contains(&Segment::Each);
+                let mut values = Vec::new();
+
+                for item in current.as_array()? {
+                    match (resolve(item, rest), nested) {
+                        (None, _) => {}
+                        (Some(Cow::Owned(Value::Array(items))), true) => values.extend(items),
+                        (Some(value), _) => values.push(value.into_owned())
+                    }
+                }
+
+                return Some(Cow::Owned(Value::Array(values)));
+            }
+        };
+    }
+
+    Some(Cow::Borrowed(current))
+}
+
+fn push_key(segments: &mut Vec<Segment>, key: &mut String) {
+    if !key.is_empty() {
+        segments.push(Segment::Key(std::mem::take(key)));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::path::{FieldPath, Segment};
+
+    fn key(name: &str) -> Segment {
+        Segment::Key(name.to_string())
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(FieldPath::new("value").segments(), &[key("value")]);
+        assert_eq!(FieldPath::new("person.name").segments(), &[key("person"), key("name")]);
+        assert_eq!(FieldPath::new("lines[0].qty").segments(), &[key("lines"), Segment::Index(0), key("qty")]);
+        assert_eq!(FieldPath::new("lines[*].qty").segments(), &[key("lines"), Segment::Each, key("qty")]);
+        assert_eq!(FieldPath::new("matrix[1][2]").segments(), &[key("matrix"), Segment::Index(1), Segment::Index(2)]);
+        assert_eq!(FieldPath::new("size\\.cm").segments(), &[key("size.cm")]);
+        assert_eq!(FieldPath::new("notes[a]").segments(), &[key("notes[a]")]);
+        assert_eq!(FieldPath::new("notes\\[0]").segments(), &[key("notes[0]")]);
+        assert!(FieldPath::new("").segments().is_empty());
+        assert_eq!(FieldPath::new("a.b"), FieldPath::new("a.b"));
+        assert_ne!(FieldPath::new("a.b"), FieldPath::new("a\\.b"));
+    }
+
+    #[test]
+    fn find_test() {
+        let row = json!({
+            "person": {"name": "john"},
+            "size.cm": 10,
+            "lines": [{"qty": 1, "tags": ["a", "b"]}, {"qty": 2, "tags": ["c"]}, {"code": "x"}]
+        });
+
+        assert_eq!(FieldPath::new("person.name").get(&row).as_ref(), &json!("john"));
+        assert_eq!(FieldPath::new("person.age").get(&row).as_ref(), &Value::Null);
+        assert_eq!(FieldPath::new("size\\.cm").get(&row).as_ref(), &json!(10));
+        assert_eq!(FieldPath::new("lines[1].qty").get(&row).as_ref(), &json!(2));
+        assert_eq!(FieldPath::new("lines[5].qty").find(&row), None);
+        assert_eq!(FieldPath::new("lines[*].qty").get(&row).as_ref(), &json!([1, 2]));
+        assert_eq!(FieldPath::new("lines[*].tags[*]").get(&row).as_ref(), &json!(["a", "b", "c"]));
+        assert_eq!(FieldPath::new("person[*]").find(&row), None);
+        assert_eq!(FieldPath::new("").get(&row).as_ref(), &row);
+
+        assert!(FieldPath::new("lines[*].qty").is_projection());
+        assert!(!FieldPath::new("lines[0].qty").is_projection());
+    }
+}
@@ -0,0 +1,22 @@
+mod filter;
+mod sort;
+mod group;
+mod aggregate;
+mod unique;
+mod perspective;
+mod live_perspective;
+mod pivot;
+//mod summary;
+//mod structures;
+
+pub use filter::{filter, in_filter};
+pub use sort::{sort, sort_page, compare_objects};
+pub(crate) use sort::compare_field;
+pub use group::group;
+pub use group::{calculate_group_aggregate, calculate_group_aggregate_with};
+pub use group::{Field as GroupField, group_key, group_result, group_id, child_group, ROOT_ID, NULL_KEY, OrderAggregate};
+pub use aggregate::{aggregate_rows, aggregate_summary, create_aggregators};
+pub use unique::get_unique;
+pub use perspective::{build_perspective, perspective_result, page_result};
+pub use live_perspective::LivePerspective;
+pub use pivot::pivot;
\ No newline at end of file
@@ -0,0 +1,190 @@
+use serde_json::Value;
+use crate::traits::Aggregate;
+use crate::aggregates;
+use crate::error::DataResult;
+use crate::path::FieldPath;
+use crate::intent::{AggregateKind, AggregateSpec};
+use crate::row::Row;
+use crate::utils::flood_indexes;
+
+/// Create aggregate objects based on the rows and data provided
+pub fn aggregate_rows<R: Row>(intent: &[AggregateSpec], data: &[R], rows: Option<Vec<usize>>) -> DataResult<Value> {
+    let rows = match rows {
+        Some(array) => array,
+        None => flood_indexes(data)
+    };
+
+    let mut aggregator = create_aggregators(intent);
+    add_rows(&mut aggregator, intent, data, &rows)?;
+
+    Ok(aggregate_summary(intent, &aggregator))
+}
+
+/// Add the fields of the rows to the aggregators of the intent.
+pub(crate) fn add_rows<R: Row>(aggregator: &mut [Box<dyn Aggregate>], intent: &[AggregateSpec], data: &[R], rows: &[usize]) -> DataResult<()> {
+    let paths: Vec<FieldPath> = intent.iter().map(|spec| FieldPath::new(&spec.field)).collect();
+
+    for row_index in rows {
+        let row = &data[*row_index];
+
+        for (i, spec) in intent.iter().enumerate() {
+            match row.field(&paths[i]) {
+                None => {}
+                Some(value) => {
+                    aggregator[i].add_value(&value)
+                        .map_err(|error| error.at(&spec.field).at(&format!("data[{}]", row_index)))?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Merge the aggregators of a group into the aggregators of its parent, both made for the same intent.
+pub(crate) fn merge_aggregators(aggregator: &mut [Box<dyn Aggregate>], other: &[Box<dyn Aggregate>]) {
+    for (target, source) in aggregator.iter_mut().zip(other) {
+        target.merge(source.as_ref());
+    }
+}
+
+pub fn create_aggregators(intent: &[AggregateSpec]) -> Vec<Box<dyn Aggregate>> {
+    intent.iter().map(|spec| create_aggregator(spec.aggregate)).collect()
+}
+
+/// One object per aggregate with the name of the aggregate, the field and the value calculated.
+pub fn aggregate_summary(intent: &[AggregateSpec], aggregator: &[Box<dyn Aggregate>]) -> Value {
+    let mut result: Vec<Value> = Vec::new();
+
+    for (i, spec) in intent.iter().enumerate() {
+        let mut summary     = Value::Object(Default::default());
+
+        summary["agg"]      = Value::from(spec.aggregate.as_str());
+        summary["field"]    = Value::from(spec.field.as_str());
+        summary["value"]    = aggregator[i].value();
+
+        result.push(summary);
+    }
+
+    Value::from(result)
+}
+
+fn create_aggregator(kind: AggregateKind) -> Box<dyn Aggregate> {
+    match kind {
+        AggregateKind::Ave          => Box::new(aggregates::Ave::new()),
+        AggregateKind::Min          => Box::new(aggregates::Min::new()),
+        AggregateKind::Max          => Box::new(aggregates::Max::new()),
+        AggregateKind::Sum          => Box::new(aggregates::Sum::new()),
+        AggregateKind::Count        => Box::new(aggregates::Count::new()),
+
+        AggregateKind::DurationAve  => Box::new(aggregates::DurationAve::new()),
+        AggregateKind::DurationMin  => Box::new(aggregates::DurationMin::new()),
+        AggregateKind::DurationMax  => Box::new(aggregates::DurationMax::new()),
+        AggregateKind::DurationSum  => Box::new(aggregates::DurationSum::new()),
+
+        AggregateKind::DateMax      => Box::new(aggregates::DateMax::new()),
+        AggregateKind::DateMin      => Box::new(aggregates::DateMin::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::processors::aggregate::{aggregate_rows};
+    use crate::error::{DataResult, ErrorCode};
+    use crate::intent;
+    use crate::intent::Aggregates;
+    use random_data::generate_data;
+
+    fn parse(intent: &Value) -> DataResult<Aggregates> {
+        intent::parse(intent, "aggregates")
+    }
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "A", "value": 10, "isActive": true}));
+        result.push(json!({"id": 1, "code": "B", "value": 10, "isActive": false}));
+        result.push(json!({"id": 2, "code": "C", "value": 20, "isActive": true}));
+        result.push(json!({"id": 3, "code": "D", "value": 20, "isActive": true}));
+        result.push(json!({"id": 4, "code": "E", "value": 5, "isActive": false}));
+        result
+    }
+
+    #[test]
+    fn count_durations_test() {
+        let count: i64 = 10000;
+        let data = generate_data(count as usize);
+
+        let intent = json!({
+            "count": "duration"
+        });
+
+        let result = aggregate_rows(&parse(&intent).unwrap().0, &data, None).unwrap();
+
+        let array = result.as_array().unwrap();
+        let agg = array[0]["value"].as_array().unwrap();
+
+        let mut sum = 0;
+        for item in agg {
+            let value: i64 = item["count"].as_i64().unwrap();
+            sum += value
+        }
+        assert_eq!(sum, count)
+    }
+
+    #[test]
+    fn aggregate_simple_test() {
+        let intent = json!({
+            "sum": "value"
+        });
+
+        let data = get_data();
+        let rows = vec![0, 1];
+        let result = aggregate_rows(&parse(&intent).unwrap().0, &data, Some(rows)).unwrap();
+
+        assert_eq!(result[0]["value"], Value::from(20.));
+    }
+
+    #[test]
+    fn aggregate_test() {
+        let intent = json!({
+            "min": "value",
+            "max": "value",
+            "ave": "value"
+        });
+
+        let data = get_data();
+        let rows = vec![0, 1, 2, 3, 4];
+
+        let result = aggregate_rows(&parse(&intent).unwrap().0, &data, Some(rows)).unwrap();
+
+        assert_eq!(result.as_array().unwrap().len(), 3);
+        assert_eq!(result[0]["value"], 13.);
+        assert_eq!(result[1]["value"], 20.);
+        assert_eq!(result[2]["value"], 5.);
+    }
+
+    #[test]
+    fn unknown_aggregate_test() {
+        let intent = json!({
+            "sum": "value",
+            "summ": "value"
+        });
+
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::UnknownAggregate);
+        assert_eq!(error.path, "aggregates.summ");
+        assert_eq!(error.message, "unknown aggregate 'summ'");
+    }
+
+    #[test]
+    fn invalid_row_value_test() {
+        let intent = json!({
+            "sum": "code"
+        });
+
+        let error = aggregate_rows(&parse(&intent).unwrap().0, &get_data(), None).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidType);
+        assert_eq!(error.path, "data[0].code");
+    }
+}
@@ -1,49 +1,28 @@
-use serde_json::Value;
-use crate::evaluate_object;
+use crate::evaluators::FilterPlan;
+use crate::intent::FilterExpr;
+use crate::row::Row;
 
-pub fn filter(intent: &[Value], data: &[Value], case_sensitive: bool) -> Vec<usize> {
-    let mut index = 0;
-    let mut filter_result = Vec::new();
-
-    let filters = intent;
-    let mut pass: bool;
-
-    for row in data {
-        pass = true;
-
-        for filter in filters {
-            pass = evaluate_object(filter, row, case_sensitive);
-
-            if pass == false {
-                break;
-            }
-        }
-
-        if pass == true {
-            filter_result.push(index);
-        }
-
-        index += 1;
-    }
-
-    filter_result
+pub fn filter<R: Row>(intent: &[FilterExpr], data: &[R], case_sensitive: bool) -> Vec<usize> {
+    FilterPlan::compile(intent, case_sensitive).run(data)
 }
 
-pub fn in_filter(filters: &[Value], obj: &Value, case_sensitive: bool) -> bool {
-    for filter in filters {
-        if evaluate_object(&filter, &obj, case_sensitive) == false {
-            return false;
-        }
-    }
-    return true;
+pub fn in_filter(filters: &[FilterExpr], obj: &dyn Row, case_sensitive: bool) -> bool {
+    FilterPlan::compile(filters, case_sensitive).matches(obj)
 }
 
 #[cfg(test)]
 mod test {
     use serde_json::{json, Value};
     use crate::processors::{filter, in_filter};
+    use crate::error::{DataResult, ErrorCode};
+    use crate::intent;
+    use crate::intent::FilterExpr;
     use serde_json::Value::Null;
 
+    fn parse(intent: &[Value]) -> DataResult<Vec<FilterExpr>> {
+        intent::parse(&Value::from(intent.to_vec()), "filter")
+    }
+
     fn get_data() -> Vec<Value> {
         let mut result: Vec<Value> = Vec::new();
         result.push(json!({"id": 0, "code": "A", "value": 10, "isActive": true, "person": {"name": "John"}}));
@@ -51,7 +30,7 @@ mod test {
         result.push(json!({"id": 2, "code": "C", "value": 20, "isActive": true, "person": {"name": "Jane"}}));
         result.push(json!({"id": 3, "code": "D", "value": 20, "isActive": true, "person": {"name": "Jane"}}));
         result.push(json!({"id": 4, "code": "E", "value": 5, "isActive": false, "person": {"name": "Andrew"}}));
-        return result;
+        result
     }
 
     #[test]
@@ -60,7 +39,7 @@ mod test {
         let mut intent: Vec<Value> = Vec::new();
         intent.push(json!({ "field": "value", "operator": "<", "value": 20 }));
 
-        let result = filter(&intent, &data, true);
+        let result = filter(&parse(&intent).unwrap(), &data, true);
 
         assert_eq!(result.len(), 3);
         assert_eq!(result[0], 0);
@@ -75,7 +54,7 @@ mod test {
         intent.push(json!({"field": "value", "operator": "<", "value": 20}));
         intent.push(json!({"field": "isActive", "operator": "==", "value": false}));
 
-        let result = filter(&intent, &data, true);
+        let result = filter(&parse(&intent).unwrap(), &data, true);
 
         assert_eq!(result.len(), 2);
         assert_eq!(result[0], 1);
@@ -91,7 +70,7 @@ mod test {
         intent.push(json!({ "field": "value", "operator": "==", "value": 10 }));
         intent.push(json!({ "field": "isActive", "operator": "not_null", "value": Null}));
 
-        let result = filter(&intent, &data, true);
+        let result = filter(&parse(&intent).unwrap(), &data, true);
 
         assert_eq!(result.len(), 2);
         assert_eq!(result[0], 0);
@@ -105,7 +84,7 @@ mod test {
         intent.push(json!({ "field": "code", "operator": "==", "value": "A"}));
         intent.push(json!({ "field": "value", "operator": "==", "value": 10 }));
 
-        let result = filter(&intent, &data, false);
+        let result = filter(&parse(&intent).unwrap(), &data, false);
         assert_eq!(result.len(), 1);
     }
 
@@ -116,7 +95,7 @@ mod test {
         intent.push(json!({ "field": "person.name", "operator": "==", "value": "John"}));
         intent.push(json!({ "field": "value", "operator": "==", "value": 10 }));
 
-        let result = filter(&intent, &data, false);
+        let result = filter(&parse(&intent).unwrap(), &data, false);
         assert_eq!(result.len(), 2);
     }
 
@@ -128,11 +107,50 @@ mod test {
         let mut intent: Vec<Value> = Vec::new();
         intent.push(json!({ "field": "code", "operator": "==", "value": "A"}));
         intent.push(json!({ "field": "value", "operator": "==", "value": 10 }));
+        let intent = parse(&intent).unwrap();
 
         let value1_result = in_filter(&intent, &value1, true);
         let value2_result = in_filter(&intent, &value2, true);
 
-        assert_eq!(value1_result, true);
-        assert_eq!(value2_result, false);
+        assert!(value1_result);
+        assert!(!value2_result);
+    }
+
+    #[test]
+    fn unknown_operator_test() {
+        let mut intent: Vec<Value> = Vec::new();
+        intent.push(json!({ "field": "value", "operator": "<", "value": 20 }));
+        intent.push(json!({ "operator": "or", "expressions": [
+            { "field": "code", "operator": "==", "value": "A" },
+            { "field": "value", "operator": "betwen", "value": [1, 10] }
+        ]}));
+
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::UnknownOperator);
+        assert_eq!(error.path, "filter[1].expressions[1].operator");
+        assert_eq!(error.message, "unknown operator 'betwen'");
+    }
+
+    #[test]
+    fn invalid_intent_test() {
+        let intent = vec![json!({ "field": "value", "value": 20 })];
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MissingProperty);
+        assert_eq!(error.path, "filter[0].operator");
+
+        let intent = vec![json!({ "operator": "==", "value": 20 })];
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MissingProperty);
+        assert_eq!(error.path, "filter[0].field");
+
+        let intent = vec![json!({ "field": "value", "operator": "between", "value": 20 })];
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "filter[0].value");
+
+        let intent = vec![json!({ "field": "code", "operator": "like", "value": 20 })];
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidType);
+        assert_eq!(error.path, "filter[0].value");
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,677 @@
+use std::slice;
+use hashbrown::HashMap;
+use serde_json::Value;
+use crate::processors::aggregate::{add_rows, aggregate_rows, aggregate_summary, create_aggregators, merge_aggregators};
+use crate::processors::sort::compare_field;
+use crate::error::{DataError, DataResult};
+use crate::path::FieldPath;
+use crate::intent::{AggregateSpec, GroupOrder, GroupSpec};
+use crate::row::Row;
+use crate::traits::Aggregate;
+use crate::utils::{check_rows, flood_indexes};
+
+#[derive(Debug)]
+pub struct Field {
+    name        : String,
+    bucket      : String,
+    key         : Value,
+    value       : String,
+    children    : Vec<Field>,
+    positions   : HashMap<String, usize>,
+    rows        : Option<Vec<i64>>,
+    child_count : i64,
+    row_count   : i64,
+    aggregates  : Option<Value>
+}
+
+impl Field {
+    /// The bucket is the group key of the key, the value the rows have in common, the value is the label of the group.
+    pub fn new(name: String, bucket: String, key: Value, value: String) -> Field {
+        Field {
+            name,
+            bucket,
+            key,
+            value,
+            children    : Vec::new(),
+            positions   : HashMap::new(),
+            rows        : None,
+            child_count : 0,
+            row_count   : 0,
+            aggregates  : None
+        }
+    }
+
+    /// The root of a group structure.
+    pub fn root() -> Field {
+        Field::new(ROOT_ID.into(), ROOT_ID.into(), Value::from(ROOT_ID), ROOT_ID.into())
+    }
+
+    /// Add the row to the group of its keys, there is a group key for every spec.
+    /// The key value of a level is only read when the row starts a new group.
+    pub fn process_row(&mut self, buckets: &[&str], specs: &[GroupSpec], field_index: usize, row_index: usize, key_value: &dyn Fn(usize) -> Value) {
+        if field_index >= specs.len() {
+            self.rows.get_or_insert_with(Vec::new).push(row_index as i64);
+            return;
+        }
+
+        let bucket = buckets[field_index];
+
+        let position = match self.positions.get(bucket) {
+            Some(position) => *position,
+            None => {
+                let spec = &specs[field_index];
+                let key = key_value(field_index);
+                let label = spec.label(&key);
+
+                self.children.push(Field::new(spec.field.clone(), bucket.to_string(), key, label));
+                self.positions.insert(bucket.to_string(), self.children.len() - 1);
+                self.children.len() - 1
+            }
+        };
+
+        self.children[position].process_row(buckets, specs, field_index + 1, row_index, key_value);
+    }
+
+    pub fn calculate_count(&mut self) {
+        match &self.rows {
+            None => {
+                self.child_count = self.children.len() as i64;
+
+                let mut count = 0;
+
+                for child in self.children.iter_mut() {
+                    child.calculate_count();
+                    count += child.row_count;
+                }
+
+                self.row_count = count;
+            }
+            Some(rows) => {
+                self.child_count = rows.len() as i64;
+                self.row_count = self.child_count;
+            }
+        }
+    }
+
+    /// Put the groups of every level in the order of their spec, the counts must be calculated first.
+    pub fn order(&mut self, specs: &[GroupSpec], aggregate: &OrderAggregate) -> DataResult<()> {
+        let spec = match specs.first() {
+            None => return Ok(()),
+            Some(spec) => spec
+        };
+
+        let mut values = Vec::with_capacity(self.children.len());
+
+        for child in &self.children {
+            values.push(order_value(spec, &child.key, child.row_count as usize, || child.collect_rows(), aggregate)?);
+        }
+
+        let buckets: Vec<&str> = self.children.iter().map(|child| child.bucket.as_str()).collect();
+        let order = group_order(spec, &values, &buckets);
+
+        let mut children: Vec<Option<Field>> = std::mem::take(&mut self.children).into_iter().map(Some).collect();
+        self.children = order.iter().filter_map(|index| children[*index].take()).collect();
+        self.positions = self.children.iter().enumerate().map(|(index, child)| (child.bucket.clone(), index)).collect();
+
+        for child in self.children.iter_mut() {
+            child.order(&specs[1..], aggregate)?;
+        }
+
+        Ok(())
+    }
+
+    /// Add the aggregates to the group and every group under it in one pass from the bottom up.
+    /// Only the last level adds its rows, the groups above merge the aggregators of their children.
+    pub(crate) fn aggregate(&mut self, intent: &[AggregateSpec], aggregate: &GroupAggregate) -> DataResult<Vec<Box<dyn Aggregate>>> {
+        let mut aggregator = create_aggregators(intent);
+
+        if let Some(rows) = &self.rows {
+            let rows: Vec<usize> = rows.iter().map(|row| *row as usize).collect();
+            aggregate(&mut aggregator, &rows)?;
+        }
+
+        for child in self.children.iter_mut() {
+            merge_aggregators(&mut aggregator, &child.aggregate(intent, aggregate)?);
+        }
+
+        self.aggregates = Some(aggregate_summary(intent, &aggregator));
+        Ok(aggregator)
+    }
+
+    /// The group under this one for the group key.
+    pub fn child(&self, bucket: &str) -> Option<&Field> {
+        self.positions.get(bucket).map(|position| &self.children[*position])
+    }
+
+    pub(crate) fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    pub(crate) fn key(&self) -> &Value {
+        &self.key
+    }
+
+    pub(crate) fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub(crate) fn children(&self) -> &[Field] {
+        &self.children
+    }
+
+    /// The rows of the group and its sub groups.
+    pub(crate) fn collect_rows(&self) -> Vec<usize> {
+        let mut rows: Vec<usize> = self.rows.iter().flatten().map(|row| *row as usize).collect();
+
+        for child in &self.children {
+            rows.extend(child.collect_rows());
+        }
+
+        rows
+    }
+
+    /// The group with its id, the children are a list in the order of the group.
+    pub fn to_json(&self, id: &str) -> Value {
+        let mut obj         = Value::Object(Default::default());
+        obj["id"]           = Value::from(id);
+        obj["key"]          = self.key.clone();
+        obj["value"]        = Value::from(self.value.clone());
+        obj["child_count"]  = Value::from(self.child_count);
+        obj["row_count"]    = Value::from(self.row_count);
+        obj["field"]        = Value::from(self.name.clone());
+
+        match &self.rows {
+            None => {
+                let children: Vec<Value> = self.children.iter()
+                    .map(|child| child.to_json(&group_id(id, &child.bucket)))
+                    .collect();
+
+                obj["children"] = Value::from(children);
+            }
+            Some(rows) => {
+                obj["rows"]     = Value::from(rows.clone());
+            }
+        }
+
+        if let Some(aggregates) = &self.aggregates {
+            obj["aggregates"] = aggregates.clone();
+        }
+
+        obj
+    }
+}
+
+/// Given a group intent, group the data based on their values
+pub fn group<R: Row>(intent: &[GroupSpec], data: &[R], rows: Option<Vec<usize>>, aggregates: Option<&[AggregateSpec]>) -> DataResult<Value> {
+    let mut root = build_field_structure(data, intent, rows);
+    root.order(intent, &|spec, rows| aggregate_rows(slice::from_ref(spec), data, Some(rows)))?;
+
+    if let Some(aggregates) = aggregates {
+        root.aggregate(aggregates, &|aggregator, rows| add_rows(aggregator, aggregates, data, rows))?;
+    }
+
+    Ok(group_result(root))
+}
+
+/// Adds the rows of a group to the aggregators of the aggregate intent.
+pub type GroupAggregate<'a> = dyn Fn(&mut [Box<dyn Aggregate>], &[usize]) -> DataResult<()> + 'a;
+
+/// Calculates one aggregate for the rows of a group, for groups that are ordered on an aggregate.
+pub type OrderAggregate<'a> = dyn Fn(&AggregateSpec, Vec<usize>) -> DataResult<Value> + 'a;
+
+/// The json of the group structure, with the aggregates on every group if they were calculated.
+pub fn group_result(root: Field) -> Value {
+    let mut result = Value::Object(Default::default());
+    result["root"] = root.to_json(ROOT_ID);
+    result
+}
+
+/// The value a group is ordered on, the rows are only collected when the order is on an aggregate.
+pub(crate) fn order_value(spec: &GroupSpec, key: &Value, row_count: usize, rows: impl FnOnce() -> Vec<usize>, aggregate: &OrderAggregate) -> DataResult<Value> {
+    let value = match &spec.order_by {
+        GroupOrder::Key => key.clone(),
+        GroupOrder::Count => Value::from(row_count),
+        GroupOrder::Aggregate(aggregate_spec) => {
+            let result = aggregate(aggregate_spec, rows())?;
+            result.get(0).and_then(|item| item.get("value")).cloned().unwrap_or(Value::Null)
+        }
+    };
+
+    Ok(value)
+}
+
+/// The positions of the groups of a level in their order.
+/// Groups that order the same are in the order of their group key so the result is always the same.
+pub(crate) fn group_order(spec: &GroupSpec, values: &[Value], buckets: &[&str]) -> Vec<usize> {
+    let sort = spec.order_field();
+    let mut order: Vec<usize> = (0..values.len()).collect();
+
+    order.sort_by(|a, b| compare_field(&sort, &values[*a], &values[*b]).then_with(|| buckets[*a].cmp(buckets[*b])));
+    order
+}
+
+/// The id of the root group, the ids of the groups below it start with it.
+pub const ROOT_ID: &str = "root";
+
+/// The id of a group is the id of its parent and its group key separated by a slash,
+/// so the id stays the same when the groups are built again, "root/north/#true".
+/// Slashes and backslashes in the group key are escaped with a backslash.
+pub fn group_id(parent: &str, bucket: &str) -> String {
+    let bucket = bucket.replace('\\', "\\\\").replace('/', "\\/");
+    format!("{}/{}", parent, bucket)
+}
+
+/// The group under the group json with the label, the children are a list so they are found on their value.
+pub fn child_group<'a>(group: &'a Value, value: &str) -> Option<&'a Value> {
+    group.get("children")?.as_array()?.iter().find(|child| child["value"] == value)
+}
+
+/// Add the aggregates to the group json and the groups under it from the bottom up, gives back the aggregators of the group.
+fn aggregate_group(group_data: &mut Value, intent: &[AggregateSpec], aggregate: &GroupAggregate) -> DataResult<Vec<Box<dyn Aggregate>>> {
+    let group_data = group_data.as_object_mut().ok_or_else(|| DataError::invalid_type("", "a group object"))?;
+    let mut aggregator = create_aggregators(intent);
+
+    if let Some(rows) = group_data.get("rows") {
+        let rows_array = rows.as_array().ok_or_else(|| DataError::invalid_type("rows", "an array"))?;
+        let mut rows_result: Vec<usize> = Vec::new();
+
+        for (index, rv) in rows_array.iter().enumerate() {
+            let row = rv.as_u64().ok_or_else(|| DataError::invalid_type(&format!("rows[{}]", index), "a row index"))?;
+            rows_result.push(row as usize);
+        }
+
+        aggregate(&mut aggregator, &rows_result)?;
+    }
+
+    if let Some(children) = group_data.get_mut("children").and_then(Value::as_array_mut) {
+        for (index, child) in children.iter_mut().enumerate() {
+            let child_aggregator = aggregate_group(child, intent, aggregate)
+                .map_err(|error| error.at(&format!("children[{}]", index)))?;
+
+            merge_aggregators(&mut aggregator, &child_aggregator);
+        }
+    }
+
+    group_data.insert("aggregates".to_string(), aggregate_summary(intent, &aggregator));
+    Ok(aggregator)
+}
+
+/// Add the aggregates to every group of the group json, including the root.
+pub fn calculate_group_aggregate<R: Row>(group_data: &mut Value, aggregate_intent: &[AggregateSpec], data: &[R]) -> DataResult<()> {
+    calculate_group_aggregate_with(group_data, aggregate_intent, &|aggregator, rows| {
+        check_rows(rows, data.len())?;
+        add_rows(aggregator, aggregate_intent, data, rows)
+    })
+}
+
+/// Add the aggregates to a group json, the rows of the group come from the json so the callback must check them.
+pub fn calculate_group_aggregate_with(group_data: &mut Value, aggregate_intent: &[AggregateSpec], aggregate: &GroupAggregate) -> DataResult<()> {
+    let group_data = match group_data.get_mut("root") {
+        None => group_data,
+        Some(root) => root
+    };
+
+    aggregate_group(group_data, aggregate_intent, aggregate)?;
+    Ok(())
+}
+
+/// The group a key value belongs to, values of different types are never in the same group.
+/// Strings are used as is and other values are their json after a hash, "#10", "#true" or "#null".
+/// Strings that start with a hash or a backslash are escaped with a backslash.
+pub fn group_key(value: &Value) -> String {
+    match value {
+        Value::String(text) if text.starts_with('#') || text.starts_with('\\') => format!("\\{}", text),
+        Value::String(text) => text.clone(),
+        Value::Null => NULL_KEY.to_string(),
+        value => format!("#{}", value)
+    }
+}
+
+/// The group key of null and missing values.
+pub const NULL_KEY: &str = "#null";
+
+/// The group tree of the rows with the counts calculated, the groups are in the order they were found.
+pub(crate) fn build_field_structure<R: Row>(data: &[R], intent: &[GroupSpec], rows: Option<Vec<usize>>) -> Field {
+    let mut root = Field::root();
+
+    let rows = match rows {
+        Some(array) => array,
+        None => flood_indexes(data)
+    };
+
+    let paths: Vec<FieldPath> = intent.iter().map(|spec| FieldPath::new(&spec.field)).collect();
+
+    for row_index in rows {
+        let record = &data[row_index];
+        let values: Vec<Value> = intent.iter().zip(&paths).map(|(spec, path)| spec.key_value(record.value(path).into_owned())).collect();
+        let buckets: Vec<String> = values.iter().map(group_key).collect();
+        let buckets: Vec<&str> = buckets.iter().map(String::as_str).collect();
+
+        root.process_row(&buckets, intent, 0, row_index, &|level| values[level].clone());
+    }
+
+    root.calculate_count();
+    root
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::error::ErrorCode;
+    use crate::processors::group::{build_field_structure, calculate_group_aggregate, child_group, group, group_id};
+    use crate::intent;
+    use crate::intent::{AggregateSpec, Aggregates, GroupSpec};
+
+    fn child<'a>(group: &'a Value, value: &str) -> &'a Value {
+        child_group(group, value).unwrap()
+    }
+
+    fn get_intent(fields: &[&str]) -> Vec<GroupSpec> {
+        fields.iter().map(|field| GroupSpec::from(*field)).collect()
+    }
+
+    fn get_aggregates(intent: &Value) -> Vec<AggregateSpec> {
+        let aggregates: Aggregates = intent::parse(intent, "aggregates").unwrap();
+        aggregates.0
+    }
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "A", "value": 10, "isActive": true}));
+        result.push(json!({"id": 1, "code": "B", "value": 10, "isActive": false}));
+        result.push(json!({"id": 2, "code": "C", "value": 20, "isActive": true}));
+        result.push(json!({"id": 3, "code": "D", "value": 20, "isActive": true}));
+        result.push(json!({"id": 4, "code": "E", "value": 5, "isActive": false}));
+        result
+    }
+
+    #[test]
+    fn group_test() {
+        let data = get_data();
+        let intent = get_intent(&["value", "isActive"]);
+        let result = group(&intent, &data, None, None).unwrap();
+
+        let group_5 = child(&result["root"], "5");
+        let group_10 = child(&result["root"], "10");
+        let group_20 = child(&result["root"], "20");
+
+        assert_eq!(group_5["child_count"], Value::from(1));
+        assert_eq!(group_10["child_count"], Value::from(2));
+        assert_eq!(group_20["child_count"], Value::from(1));
+
+        assert_eq!(child(group_5, "false")["child_count"], Value::from(1));
+        assert_eq!(child(group_10, "false")["child_count"], Value::from(1));
+        assert_eq!(child(group_10, "true")["child_count"], Value::from(1));
+        assert_eq!(child(group_20, "true")["child_count"], Value::from(2));
+    }
+
+    #[test]
+    fn field_structure_test() {
+        let fields = get_intent(&["field1", "field2"]);
+
+        let mut data: Vec<Value> = Vec::new();
+        data.push(json!({"field1": 10, "field2": "a", "value": 1}));
+        data.push(json!({"field1": 10, "field2": "b", "value": 2}));
+        data.push(json!({"field1": 11, "field2": "c", "value": 3}));
+        data.push(json!({"field1": 10, "field2": "a", "value": 4}));
+
+        let result = build_field_structure(&data, &fields, None);
+
+        let child_10 = result.child("#10").unwrap();
+        assert_eq!(child_10.value, "10");
+        assert_eq!(child_10.children.len(), 2);
+        assert_eq!(child_10.child_count, 2);
+
+        let child_10_a = child_10.child("a").unwrap();
+        let child_10_a_rows = child_10_a.rows.as_ref().unwrap();
+        assert_eq!(child_10_a.value, "a");
+        assert_eq!(child_10_a.children.len(), 0);
+        assert_eq!(child_10_a.child_count, 2);
+        assert_eq!(child_10_a_rows.len(), 2);
+        assert_eq!(child_10_a_rows[0], 0);
+        assert_eq!(child_10_a_rows[1], 3);
+
+        let child_10_b = child_10.child("b").unwrap();
+        let child_10_b_rows = child_10_b.rows.as_ref().unwrap();
+        assert_eq!(child_10_b.value, "b");
+        assert_eq!(child_10_b.children.len(), 0);
+        assert_eq!(child_10_b.child_count, 1);
+        assert_eq!(child_10_b_rows.len(), 1);
+        assert_eq!(child_10_b_rows[0], 1);
+
+        let child_11 = result.child("#11").unwrap();
+        assert_eq!(child_11.value, "11");
+        assert_eq!(child_11.children.len(), 1);
+        assert_eq!(child_11.child_count, 1);
+
+        let child_11_c = child_11.child("c").unwrap();
+        let child_11_c_rows = child_11_c.rows.as_ref().unwrap();
+        assert_eq!(child_11_c.value, "c");
+        assert_eq!(child_11_c.children.len(), 0);
+        assert_eq!(child_11_c.child_count, 1);
+        assert_eq!(child_11_c_rows.len(), 1);
+        assert_eq!(child_11_c_rows[0], 2);
+    }
+
+    #[test]
+    fn aggregate_group_test() {
+        let data = get_data();
+        let group_intent = get_intent(&["value", "isActive"]);
+        let mut group = group(&group_intent, &data, None, None).unwrap();
+        let ag_intent = json!({
+            "min": "value",
+            "max": "value",
+            "ave": "value"
+        });
+
+        calculate_group_aggregate(&mut group, &get_aggregates(&ag_intent), &data).unwrap();
+
+        assert_eq!(group["root"]["aggregates"][0]["value"], 13.);
+        assert_eq!(group["root"]["aggregates"][0]["agg"], "ave");
+        assert_eq!(group["root"]["aggregates"][0]["field"], "value");
+        assert_eq!(group["root"]["aggregates"][1]["value"], 20.);
+        assert_eq!(group["root"]["aggregates"][1]["agg"], "max");
+        assert_eq!(group["root"]["aggregates"][1]["field"], "value");
+        assert_eq!(group["root"]["aggregates"][2]["value"], 5.);
+        assert_eq!(group["root"]["aggregates"][2]["agg"], "min");
+        assert_eq!(group["root"]["aggregates"][2]["field"], "value");
+    }
+
+    #[test]
+    fn aggregate_children_test() {
+        let data = get_data();
+        let group_intent = get_intent(&["value", "isActive"]);
+        let mut grouping = group(&group_intent, &data, None, None).unwrap();
+        let ag_intent = json!({
+            "min": "value",
+            "max": "value",
+            "ave": "value"
+        });
+
+        let aggregates = get_aggregates(&ag_intent);
+        calculate_group_aggregate(&mut grouping, &aggregates, &data).unwrap();
+        let group = group(&group_intent, &data, None, Some(&aggregates)).unwrap();
+        assert_eq!(grouping, group);
+
+        assert_eq!(child(&group["root"], "10")["aggregates"][0]["value"], 10.);
+        assert_eq!(child(&group["root"], "10")["aggregates"][0]["agg"], "ave");
+        assert_eq!(child(&group["root"], "10")["aggregates"][0]["field"], "value");
+        assert_eq!(child(&group["root"], "10")["aggregates"][1]["value"], 10.);
+        assert_eq!(child(&group["root"], "10")["aggregates"][1]["agg"], "max");
+        assert_eq!(child(&group["root"], "10")["aggregates"][1]["field"], "value");
+        assert_eq!(child(&group["root"], "10")["aggregates"][2]["value"], 10.);
+        assert_eq!(child(&group["root"], "10")["aggregates"][2]["agg"], "min");
+        assert_eq!(child(&group["root"], "10")["aggregates"][2]["field"], "value");
+
+        assert_eq!(child(&group["root"], "20")["aggregates"][0]["value"], 20.);
+        assert_eq!(child(&group["root"], "20")["aggregates"][0]["agg"], "ave");
+        assert_eq!(child(&group["root"], "20")["aggregates"][0]["field"], "value");
+        assert_eq!(child(&group["root"], "20")["aggregates"][1]["value"], 20.);
+        assert_eq!(child(&group["root"], "20")["aggregates"][1]["agg"], "max");
+        assert_eq!(child(&group["root"], "20")["aggregates"][1]["field"], "value");
+        assert_eq!(child(&group["root"], "20")["aggregates"][2]["value"], 20.);
+        assert_eq!(child(&group["root"], "20")["aggregates"][2]["agg"], "min");
+        assert_eq!(child(&group["root"], "20")["aggregates"][2]["field"], "value");
+
+        assert_eq!(child(&group["root"], "5")["aggregates"][0]["value"], 5.);
+        assert_eq!(child(&group["root"], "5")["aggregates"][0]["agg"], "ave");
+        assert_eq!(child(&group["root"], "5")["aggregates"][0]["field"], "value");
+        assert_eq!(child(&group["root"], "5")["aggregates"][1]["value"], 5.);
+        assert_eq!(child(&group["root"], "5")["aggregates"][1]["agg"], "max");
+        assert_eq!(child(&group["root"], "5")["aggregates"][1]["field"], "value");
+        assert_eq!(child(&group["root"], "5")["aggregates"][2]["value"], 5.);
+        assert_eq!(child(&group["root"], "5")["aggregates"][2]["agg"], "min");
+        assert_eq!(child(&group["root"], "5")["aggregates"][2]["field"], "value");
+
+        let group_10 = child(&group["root"], "10");
+        assert_eq!(child(group_10, "true")["aggregates"][0]["value"], 10.);
+        assert_eq!(child(group_10, "false")["aggregates"][2]["value"], 10.);
+        assert_eq!(child(child(&group["root"], "5"), "false")["aggregates"][1]["value"], 5.);
+
+        assert_eq!(group["root"]["aggregates"][0]["value"], 13.);
+        assert_eq!(group["root"]["aggregates"][1]["value"], 20.);
+        assert_eq!(group["root"]["aggregates"][2]["value"], 5.);
+    }
+
+    #[test]
+    fn aggregate_rows_error_test() {
+        let data = get_data();
+        let mut group = json!({ "root": { "children": [{ "rows": [0, 1] }, { "children": [{ "rows": [2, 9] }] }] } });
+
+        let error = calculate_group_aggregate(&mut group, &get_aggregates(&json!({ "sum": "value" })), &data).unwrap_err();
+        assert_eq!(error.code, ErrorCode::RowOutOfRange);
+        assert_eq!(error.path, "children[1].children[0].rows[1]");
+    }
+
+    #[test]
+    fn aggregate_shape_error_test() {
+        let data = get_data();
+        let aggregates = get_aggregates(&json!({ "sum": "value" }));
+
+        for (mut group, path) in [(json!({ "root": 5 }), ""), (json!([1, 2]), ""), (json!({ "root": { "children": [{ "rows": [0] }, 1] } }), "children[1]")] {
+            let error = calculate_group_aggregate(&mut group, &aggregates, &data).unwrap_err();
+            assert_eq!(error.code, ErrorCode::InvalidType);
+            assert_eq!(error.path, path);
+        }
+    }
+
+    #[test]
+    fn aggregate_subset_test() {
+        let data = get_data();
+        let group_intent = get_intent(&["value"]);
+        let group = group(&group_intent, &data, Some(vec![0, 1, 2]), None).unwrap();
+
+        assert_eq!(group["root"]["child_count"], 2);
+        assert_eq!(child(&group["root"], "10")["child_count"], 2);
+        assert_eq!(child(&group["root"], "20")["child_count"], 1);
+    }
+
+    fn values(group: &Value) -> Vec<&str> {
+        group["children"].as_array().unwrap().iter().map(|child| child["value"].as_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn order_test() {
+        let data = get_data();
+
+        let result = group(&get_intent(&["value"]), &data, None, None).unwrap();
+        assert_eq!(values(&result["root"]), vec!["5", "10", "20"]);
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([{ "field": "value", "direction": "desc" }, "isActive"]), "group").unwrap();
+        let result = group(&intent, &data, None, None).unwrap();
+        assert_eq!(values(&result["root"]), vec!["20", "10", "5"]);
+        assert_eq!(values(child(&result["root"], "10")), vec!["false", "true"]);
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([{ "field": "isActive", "order_by": "count", "direction": "desc" }]), "group").unwrap();
+        let result = group(&intent, &data, None, None).unwrap();
+        assert_eq!(values(&result["root"]), vec!["true", "false"]);
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([{ "field": "code", "order_by": { "sum": "value" }, "direction": "desc" }]), "group").unwrap();
+        let result = group(&intent, &data, None, None).unwrap();
+        assert_eq!(values(&result["root"]), vec!["C", "D", "A", "B", "E"]);
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([{ "field": "code", "type": "enum", "order": ["E", "A"] }]), "group").unwrap();
+        let result = group(&intent, &data, None, None).unwrap();
+        assert_eq!(values(&result["root"]), vec!["E", "A", "B", "C", "D"]);
+    }
+
+    #[test]
+    fn group_id_test() {
+        let data = get_data();
+        let result = group(&get_intent(&["value", "isActive"]), &data, Some(vec![4, 3]), None).unwrap();
+
+        assert_eq!(result["root"]["id"], "root");
+        assert_eq!(child(&result["root"], "20")["id"], "root/#20");
+        assert_eq!(child(child(&result["root"], "20"), "true")["id"], "root/#20/#true");
+        assert_eq!(child(child(&result["root"], "20"), "true")["key"], true);
+
+        // the ids are the same when the groups are built on other rows
+        let result = group(&get_intent(&["value", "isActive"]), &data, None, None).unwrap();
+        assert_eq!(child(child(&result["root"], "20"), "true")["id"], "root/#20/#true");
+
+        assert_eq!(group_id("root", "a/b"), "root/a\\/b");
+        assert_eq!(group_id("root", "a\\b"), "root/a\\\\b");
+    }
+
+    #[test]
+    fn typed_key_test() {
+        let data = vec![
+            json!({"code": 10, "name": "Doe"}),
+            json!({"code": "10", "name": "doe"}),
+            json!({"code": null, "name": "Smith"}),
+            json!({"name": "DOE"}),
+            json!({"code": "null", "name": null}),
+            json!({"code": 10.0, "name": "#1"})
+        ];
+
+        let result = group(&get_intent(&["code"]), &data, None, None).unwrap();
+        let root = &result["root"];
+        assert_eq!(root["child_count"], 4);
+
+        let keys: Vec<&Value> = root["children"].as_array().unwrap().iter().map(|child| &child["key"]).collect();
+        assert_eq!(keys, vec![&json!(10), &json!("10"), &json!("null"), &json!(null)]);
+        assert_eq!(root["children"][0]["rows"], json!([0, 5]));
+        assert_eq!(root["children"][1]["id"], "root/10");
+        assert_eq!(root["children"][2]["id"], "root/null");
+        assert_eq!(root["children"][3]["id"], "root/#null");
+        assert_eq!(root["children"][3]["rows"], json!([2, 3]));
+
+        let intent = vec![GroupSpec::from("name").with_case_insensitive().with_null_label("Unknown")];
+        let result = group(&intent, &data, None, None).unwrap();
+        let root = &result["root"];
+        assert_eq!(root["child_count"], 4);
+        assert_eq!(child(root, "doe")["rows"], json!([0, 1, 3]));
+        assert_eq!(child(root, "Unknown")["key"], json!(null));
+        assert_eq!(child(root, "#1")["id"], "root/\\\\#1");
+    }
+
+    #[test]
+    fn bucket_test() {
+        let data = vec![
+            json!({"date": "2024-03-05", "value": 45, "duration": "PT30M"}),
+            json!({"date": "2023-12-31", "value": 5, "duration": "PT2H"}),
+            json!({"date": "2024-01-15", "value": 120, "duration": "PT5H"}),
+            json!({"date": "2024-03-20", "value": 10, "duration": "PT1H"}),
+            json!({"date": null, "value": "A"})
+        ];
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([{ "field": "date", "bucket": { "date": "month" }, "direction": "desc" }]), "group").unwrap();
+        let result = group(&intent, &data, None, None).unwrap();
+        assert_eq!(values(&result["root"]), vec!["2024-03", "2024-01", "2023-12", "null"]);
+        assert_eq!(child(&result["root"], "2024-03")["rows"], json!([0, 3]));
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([{ "field": "value", "bucket": { "edges": [10, 50] } }]), "group").unwrap();
+        let result = group(&intent, &data, None, None).unwrap();
+        assert_eq!(values(&result["root"]), vec!["< 10", "10–50", "50+", "null"]);
+        assert_eq!(child(&result["root"], "10–50")["rows"], json!([0, 3]));
+        assert_eq!(child(&result["root"], "10–50")["key"], 1);
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([{ "field": "value", "bucket": { "width": 50 } }]), "group").unwrap();
+        let result = group(&intent, &data, None, None).unwrap();
+        assert_eq!(values(&result["root"]), vec!["0–50", "100–150", "null"]);
+
+        let intent: Vec<GroupSpec> = intent::parse(&json!([{ "field": "duration", "bucket": { "durations": ["PT1H", "PT4H"] }, "null_label": "none" }]), "group").unwrap();
+        let result = group(&intent, &data, None, None).unwrap();
+        assert_eq!(values(&result["root"]), vec!["< 1h", "1h–4h", "4h+", "none"]);
+    }
+}
@@ -0,0 +1,945 @@
+use std::cmp::Ordering;
+use std::slice;
+use std::collections::{BTreeMap, BTreeSet};
+use hashbrown::HashMap;
+use serde_json::Value;
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::evaluators::FilterPlan;
+use crate::expression::Computed;
+use crate::intent::{AggregateSpec, GroupSpec, Perspective, RowDelta, RowTarget, SortField};
+use crate::path::FieldPath;
+use crate::processors::aggregate::{add_rows, aggregate_rows, aggregate_summary, create_aggregators, merge_aggregators};
+use crate::processors::group::{group_id, group_key, group_order, order_value, ROOT_ID};
+use crate::processors::perspective::page_result;
+use crate::processors::sort::compare_rows;
+use crate::row::Row;
+use crate::traits::Aggregate;
+
+/// A group of the live group tree, the last level holds the rows in sort order.
+struct GroupNode {
+    field: String,
+    key: Value,
+    value: String,
+    children: BTreeMap<String, GroupNode>,
+    rows: Vec<usize>,
+    aggregator: Vec<Box<dyn Aggregate>>,
+    aggregates: Option<Value>
+}
+
+impl GroupNode {
+    fn new(field: &str, key: Value, value: String) -> GroupNode {
+        GroupNode {
+            field: field.to_string(),
+            key,
+            value,
+            children: BTreeMap::new(),
+            rows: Vec::new(),
+            aggregator: Vec::new(),
+            aggregates: None
+        }
+    }
+
+    fn get(&self, path: &[String]) -> Option<&GroupNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((key, rest)) => self.children.get(key).and_then(|child| child.get(rest))
+        }
+    }
+
+    /// The group at the end of the path, groups that don't exist yet are created with the values of the row.
+    fn get_or_create(&mut self, fields: &[GroupSpec], path: &[String], values: &[Value]) -> &mut GroupNode {
+        let mut node = self;
+
+        for ((spec, key), value) in fields.iter().zip(path).zip(values) {
+            node = node.children.entry(key.clone()).or_insert_with(|| GroupNode::new(&spec.field, value.clone(), spec.label(value)));
+        }
+
+        node
+    }
+
+    /// Take the row out of the group on the path, groups left without rows are removed.
+    fn remove(&mut self, path: &[String], row: usize) {
+        match path.split_first() {
+            None => self.rows.retain(|item| *item != row),
+            Some((key, rest)) => {
+                let empty = match self.children.get_mut(key) {
+                    None => false,
+                    Some(child) => {
+                        child.remove(rest, row);
+                        child.children.is_empty() && child.rows.is_empty()
+                    }
+                };
+
+                if empty {
+                    self.children.remove(key);
+                }
+            }
+        }
+    }
+
+    /// Give the rows of the group and its sub groups their index after the deleted rows are taken out.
+    fn reindex(&mut self, indexes: &[usize]) {
+        self.rows.iter_mut().for_each(|row| *row = indexes[*row]);
+        self.children.values_mut().for_each(|child| child.reindex(indexes));
+    }
+
+    /// The rows of the group and its sub groups, in the same order as processors::group gives them.
+    fn collect_rows(&self, rows: &mut Vec<usize>) {
+        rows.extend(&self.rows);
+
+        for child in self.children.values() {
+            child.collect_rows(rows);
+        }
+    }
+
+    /// Calculate the aggregates again for the groups on the changed paths, from the bottom up.
+    /// The last level adds its rows, the groups above merge the aggregators of their children.
+    /// Without changed paths every group is calculated.
+    fn aggregate(&mut self, path: &mut Vec<String>, changed: Option<&BTreeSet<Vec<String>>>, intent: &[AggregateSpec], data: &[Value]) -> DataResult<()> {
+        let mut aggregator = create_aggregators(intent);
+        add_rows(&mut aggregator, intent, data, &self.rows)?;
+
+        for (key, child) in self.children.iter_mut() {
+            path.push(key.clone());
+
+            if changed.is_none_or(|changed| changed.contains(path)) {
+                child.aggregate(path, changed, intent, data)?;
+            }
+
+            path.pop();
+            merge_aggregators(&mut aggregator, &child.aggregator);
+        }
+
+        self.aggregates = Some(aggregate_summary(intent, &aggregator));
+        self.aggregator = aggregator;
+        Ok(())
+    }
+
+    fn row_count(&self) -> usize {
+        self.rows.len() + self.children.values().map(GroupNode::row_count).sum::<usize>()
+    }
+
+    fn child_count(&self, leaf: bool) -> usize {
+        match leaf {
+            true => self.rows.len(),
+            false => self.children.len()
+        }
+    }
+}
+
+/// A perspective that is kept up to date as rows are inserted, updated or deleted.
+/// Row indexes are stable: deleted rows leave a gap and inserted rows are added at the end.
+/// Once more than half the rows are deleted the gaps are taken out, the diff of those deltas
+/// has the old index of every row in "reindexed" and the indexes after that are new.
+/// Applying deltas only touches the rows, groups and aggregates they affect and gives back what changed.
+pub struct LivePerspective {
+    perspective: Perspective,
+    computed: Option<Computed>,
+    plan: Option<FilterPlan>,
+    groups: Vec<GroupSpec>,
+    group_paths: Vec<FieldPath>,
+    id_field: Option<String>,
+    id_path: Option<FieldPath>,
+    data: Vec<Value>,
+    deleted: Vec<bool>,
+    deleted_count: usize,
+    ids: HashMap<String, usize>,
+    visible: Vec<bool>,
+    order: Vec<usize>,
+    root: Option<GroupNode>,
+    blocks: Vec<Vec<Box<dyn Aggregate>>>,
+    aggregates: Option<Value>
+}
+
+/// The rows in a block of the aggregates without grouping.
+const AGGREGATE_BLOCK: usize = 256;
+
+impl LivePerspective {
+    /// The id field is optional, with it deltas can find rows by id instead of index.
+    pub fn new(perspective: Perspective, mut data: Vec<Value>, id_field: Option<String>) -> DataResult<LivePerspective> {
+        let computed = match &perspective.computed {
+            None => None,
+            Some(fields) => Some(Computed::compile(fields).map_err(|error| error.at("computed"))?)
+        };
+
+        // the computed values are kept on the rows, deltas compute them again for the rows they change
+        if let Some(computed) = &computed {
+            data.iter_mut().for_each(|row| computed.extend(row));
+        }
+
+        let plan = perspective.filter.as_ref().map(|filter| FilterPlan::compile(filter, perspective.case_sensitive));
+        let root = perspective.group.as_ref().map(|_| GroupNode::new(ROOT_ID, Value::from(ROOT_ID), ROOT_ID.to_string()));
+        let sort = perspective.sort.as_deref().unwrap_or_default();
+        let groups: Vec<GroupSpec> = perspective.group.iter().flatten().map(|spec| spec.follow_sort(sort)).collect();
+        let group_paths = groups.iter().map(|spec| FieldPath::new(&spec.field)).collect();
+        let length = data.len();
+
+        let mut result = LivePerspective {
+            perspective,
+            computed,
+            plan,
+            groups,
+            group_paths,
+            id_path: id_field.as_deref().map(FieldPath::new),
+            id_field,
+            data,
+            deleted: vec![false; length],
+            deleted_count: 0,
+            ids: HashMap::new(),
+            visible: vec![false; length],
+            order: Vec::new(),
+            root,
+            blocks: Vec::new(),
+            aggregates: None
+        };
+
+        for row in 0..length {
+            result.check_id(&result.data[row], None).map_err(|error| error.at(&format!("data[{}]", row)))?;
+            result.register_id(row);
+            result.visible[row] = result.matches(row);
+        }
+
+        let mut order: Vec<usize> = (0..length).filter(|row| result.visible[*row]).collect();
+        order.sort_by(|a, b| result.compare(*a, *b));
+
+        if let Some(mut root) = result.root.take() {
+            for row in &order {
+                root.get_or_create(result.group_fields(), &result.group_path(*row), &result.group_values(*row)).rows.push(*row);
+            }
+
+            result.root = Some(root);
+        }
+
+        result.order = order;
+        result.refresh_aggregates(None, None)?;
+        Ok(result)
+    }
+
+    /// The current result, the same as build_perspective gives for the rows that are not deleted.
+    pub fn result(&self) -> Value {
+        if let Some(root) = &self.root {
+            let mut result = Value::Object(Default::default());
+            result["root"] = self.group_json(root, ROOT_ID, 0);
+            return result;
+        }
+
+        match (&self.aggregates, self.perspective.page) {
+            (Some(aggregates), _) => aggregates.clone(),
+            (None, None) => Value::from(self.order.clone()),
+            (None, Some(page)) => page_result(page.apply(self.order.clone()), self.order.len())
+        }
+    }
+
+    /// Apply the deltas in order and give back what changed.
+    /// When a delta fails the deltas before it stay applied, use result to get back in sync.
+    pub fn apply(&mut self, deltas: &[RowDelta]) -> DataResult<Value> {
+        // the rows touched and if they were visible before the deltas
+        let mut touched: BTreeMap<usize, bool> = BTreeMap::new();
+        let mut groups: BTreeSet<Vec<String>> = BTreeSet::new();
+
+        for (index, delta) in deltas.iter().enumerate() {
+            self.apply_delta(delta, &mut touched, &mut groups)
+                .map_err(|error| error.at(&format!("[{}]", index)))?;
+        }
+
+        self.refresh_aggregates(Some(&groups), Some(&touched))?;
+
+        let mut diff = self.diff(&touched, &groups);
+
+        if self.deleted_count * 2 > self.data.len() {
+            diff["reindexed"] = Value::from(self.compact()?);
+        }
+
+        Ok(diff)
+    }
+
+    /// Take the deleted rows out and give the rows after them a lower index, the order of the rows stays the same.
+    /// Gives back the old index of each row, the blocks of aggregates are calculated again for the new indexes.
+    fn compact(&mut self) -> DataResult<Vec<usize>> {
+        let kept: Vec<usize> = (0..self.data.len()).filter(|row| !self.deleted[*row]).collect();
+        let mut indexes: Vec<usize> = vec![0; self.data.len()];
+
+        for (index, row) in kept.iter().enumerate() {
+            indexes[*row] = index;
+        }
+
+        let data = std::mem::take(&mut self.data);
+        self.data = data.into_iter().enumerate().filter(|(row, _)| !self.deleted[*row]).map(|(_, row)| row).collect();
+        self.visible = kept.iter().map(|row| self.visible[*row]).collect();
+        self.deleted = vec![false; kept.len()];
+        self.deleted_count = 0;
+
+        self.ids.values_mut().for_each(|row| *row = indexes[*row]);
+        self.order.iter_mut().for_each(|row| *row = indexes[*row]);
+
+        match self.root.as_mut() {
+            Some(root) => root.reindex(&indexes),
+            None => {
+                self.blocks.clear();
+                self.refresh_aggregates(None, None)?;
+            }
+        }
+
+        Ok(kept)
+    }
+
+    fn apply_delta(&mut self, delta: &RowDelta, touched: &mut BTreeMap<usize, bool>, groups: &mut BTreeSet<Vec<String>>) -> DataResult<()> {
+        match delta {
+            RowDelta::Insert { row } => {
+                let row = self.computed_row(row);
+                self.check_id(&row, None)?;
+
+                self.data.push(row);
+                self.deleted.push(false);
+                self.visible.push(false);
+
+                let index = self.data.len() - 1;
+                touched.entry(index).or_insert(false);
+
+                self.register_id(index);
+                self.show(index, groups);
+            }
+            RowDelta::Update { target, row } => {
+                let index = self.resolve(target)?;
+                let row = self.computed_row(row);
+                self.check_id(&row, Some(index))?;
+
+                touched.entry(index).or_insert(self.visible[index]);
+
+                self.hide(index, groups);
+                self.unregister_id(index);
+                self.data[index] = row;
+                self.register_id(index);
+                self.show(index, groups);
+            }
+            RowDelta::Delete { target } => {
+                let index = self.resolve(target)?;
+                touched.entry(index).or_insert(self.visible[index]);
+
+                self.hide(index, groups);
+                self.unregister_id(index);
+                self.data[index] = Value::Null;
+                self.deleted[index] = true;
+                self.deleted_count += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn computed_row(&self, row: &Value) -> Value {
+        let mut row = row.clone();
+
+        if let Some(computed) = &self.computed {
+            computed.extend(&mut row);
+        }
+
+        row
+    }
+
+    /// Find the row of the delta, deleted rows can't be changed again.
+    fn resolve(&self, target: &RowTarget) -> DataResult<usize> {
+        if let Some(index) = target.index {
+            if index >= self.data.len() || self.deleted[index] {
+                let message = format!("there is no row at index {}", index);
+                return Err(DataError::new(ErrorCode::RowOutOfRange, "index", message));
+            }
+
+            return Ok(index);
+        }
+
+        let id = target.id.as_ref().map(id_key).unwrap_or_default();
+
+        match self.ids.get(&id) {
+            None => Err(DataError::new(ErrorCode::RowOutOfRange, "id", format!("there is no row with id {}", id))),
+            Some(index) => Ok(*index)
+        }
+    }
+
+    /// Ids must be unique, the id of the row may only be used by the row it replaces.
+    fn check_id(&self, row: &Value, index: Option<usize>) -> DataResult<()> {
+        let id = match self.id_of(row) {
+            None => return Ok(()),
+            Some(id) => id
+        };
+
+        match self.ids.get(&id) {
+            Some(other) if Some(*other) != index => {
+                let message = format!("the id {} is already used by row {}", id, other);
+                Err(DataError::new(ErrorCode::InvalidValue, self.id_field.as_deref().unwrap_or_default(), message))
+            }
+            _ => Ok(())
+        }
+    }
+
+    fn register_id(&mut self, row: usize) {
+        if let Some(id) = self.row_id(row) {
+            self.ids.insert(id, row);
+        }
+    }
+
+    fn unregister_id(&mut self, row: usize) {
+        if let Some(id) = self.row_id(row) {
+            if self.ids.get(&id) == Some(&row) {
+                self.ids.remove(&id);
+            }
+        }
+    }
+
+    fn row_id(&self, row: usize) -> Option<String> {
+        self.id_of(&self.data[row])
+    }
+
+    /// The id field is a path like the other fields of the perspective, "meta.id" or "keys[0]".
+    fn id_of(&self, row: &Value) -> Option<String> {
+        let path = self.id_path.as_ref()?;
+
+        match row.field(path).as_deref() {
+            None | Some(Value::Null) => None,
+            Some(value) => Some(id_key(value))
+        }
+    }
+
+    fn matches(&self, row: usize) -> bool {
+        match &self.plan {
+            None => true,
+            Some(plan) => plan.matches(&self.data[row])
+        }
+    }
+
+    fn sort_fields(&self) -> &[SortField] {
+        self.perspective.sort.as_deref().unwrap_or(&[])
+    }
+
+    fn group_fields(&self) -> &[GroupSpec] {
+        &self.groups
+    }
+
+    fn compare(&self, a: usize, b: usize) -> Ordering {
+        compare_rows(self.sort_fields(), &self.data, a, b)
+    }
+
+    fn group_path(&self, row: usize) -> Vec<String> {
+        self.group_values(row).iter().map(group_key).collect()
+    }
+
+    fn group_values(&self, row: usize) -> Vec<Value> {
+        self.groups.iter().zip(&self.group_paths)
+            .map(|(spec, path)| spec.key_value(path.get(&self.data[row]).into_owned()))
+            .collect()
+    }
+
+    /// The group in the same json structure as processors::group, with the children in the order of the group spec.
+    fn group_json(&self, node: &GroupNode, id: &str, depth: usize) -> Value {
+        let leaf = depth >= self.groups.len();
+
+        let mut obj         = Value::Object(Default::default());
+        obj["id"]           = Value::from(id);
+        obj["key"]          = node.key.clone();
+        obj["value"]        = Value::from(node.value.as_str());
+        obj["child_count"]  = Value::from(node.child_count(leaf));
+        obj["row_count"]    = Value::from(node.row_count());
+        obj["field"]        = Value::from(node.field.as_str());
+
+        match leaf {
+            true => obj["rows"] = Value::from(node.rows.clone()),
+            false => {
+                let spec = &self.groups[depth];
+                let aggregate = |spec: &AggregateSpec, rows| aggregate_rows(slice::from_ref(spec), &self.data, Some(rows));
+                let children: Vec<(&String, &GroupNode)> = node.children.iter().collect();
+
+                let values: Vec<Value> = children.iter().map(|(_, child)| {
+                    let rows = || {
+                        let mut rows = Vec::new();
+                        child.collect_rows(&mut rows);
+                        rows
+                    };
+
+                    order_value(spec, &child.key, child.row_count(), rows, &aggregate).unwrap_or(Value::Null)
+                }).collect();
+
+                let keys: Vec<&str> = children.iter().map(|(key, _)| key.as_str()).collect();
+
+                let ordered: Vec<Value> = group_order(spec, &values, &keys).into_iter()
+                    .map(|index| self.group_json(children[index].1, &group_id(id, keys[index]), depth + 1))
+                    .collect();
+
+                obj["children"] = Value::from(ordered);
+            }
+        }
+
+        if let Some(aggregates) = &node.aggregates {
+            obj["aggregates"] = aggregates.clone();
+        }
+
+        obj
+    }
+
+    /// Take a visible row out of the order and its group.
+    fn hide(&mut self, row: usize, groups: &mut BTreeSet<Vec<String>>) {
+        if !self.visible[row] {
+            return;
+        }
+
+        self.visible[row] = false;
+
+        // the row still has the values it was sorted on
+        if let Ok(position) = self.order.binary_search_by(|item| self.compare(*item, row)) {
+            self.order.remove(position);
+        }
+
+        let path = self.group_path(row);
+
+        if let Some(root) = self.root.as_mut() {
+            root.remove(&path, row);
+            mark_groups(&path, groups);
+        }
+    }
+
+    /// Add the row to the order and its group when it passes the filter.
+    fn show(&mut self, row: usize, groups: &mut BTreeSet<Vec<String>>) {
+        if !self.matches(row) {
+            return;
+        }
+
+        self.visible[row] = true;
+
+        let position = self.order.partition_point(|item| self.compare(*item, row) == Ordering::Less);
+        self.order.insert(position, row);
+
+        let path = self.group_path(row);
+
+        if let Some(mut root) = self.root.take() {
+            let rows = &mut root.get_or_create(self.group_fields(), &path, &self.group_values(row)).rows;
+            let position = rows.partition_point(|item| self.compare(*item, row) == Ordering::Less);
+            rows.insert(position, row);
+
+            self.root = Some(root);
+            mark_groups(&path, groups);
+        }
+    }
+
+    /// Aggregates are calculated again for the groups that changed and the groups above them.
+    /// Without grouping the rows are aggregated in blocks of row indexes and only the blocks with touched rows
+    /// add their rows again, min and max can't take a row out so a block starts over.
+    /// Without changes every group or block is calculated.
+    fn refresh_aggregates(&mut self, groups: Option<&BTreeSet<Vec<String>>>, touched: Option<&BTreeMap<usize, bool>>) -> DataResult<()> {
+        let aggregates = match &self.perspective.aggregates {
+            None => return Ok(()),
+            Some(aggregates) => &aggregates.0
+        };
+
+        if let Some(root) = self.root.as_mut() {
+            // the root is on the changed paths when any group changed
+            if groups.is_none_or(|groups| groups.contains(&Vec::new())) {
+                root.aggregate(&mut Vec::new(), groups, aggregates, &self.data)?;
+            }
+
+            return Ok(());
+        }
+
+        let length = self.data.len();
+        let blocks: BTreeSet<usize> = match touched {
+            None => (0..length.div_ceil(AGGREGATE_BLOCK)).collect(),
+            Some(touched) => touched.iter()
+                .filter(|(row, before)| **before || self.visible[**row])
+                .map(|(row, _)| row / AGGREGATE_BLOCK)
+                .collect()
+        };
+
+        if blocks.is_empty() && self.aggregates.is_some() {
+            return Ok(());
+        }
+
+        self.blocks.resize_with(length.div_ceil(AGGREGATE_BLOCK), || create_aggregators(aggregates));
+
+        for block in blocks {
+            let start = block * AGGREGATE_BLOCK;
+            let rows: Vec<usize> = (start..length.min(start + AGGREGATE_BLOCK)).filter(|row| self.visible[*row]).collect();
+
+            let mut aggregator = create_aggregators(aggregates);
+            add_rows(&mut aggregator, aggregates, &self.data, &rows)?;
+            self.blocks[block] = aggregator;
+        }
+
+        let mut aggregator = create_aggregators(aggregates);
+
+        for block in &self.blocks {
+            merge_aggregators(&mut aggregator, block);
+        }
+
+        self.aggregates = Some(aggregate_summary(aggregates, &aggregator));
+        Ok(())
+    }
+
+    fn diff(&self, touched: &BTreeMap<usize, bool>, groups: &BTreeSet<Vec<String>>) -> Value {
+        let mut added: Vec<usize> = Vec::new();
+        let mut removed: Vec<usize> = Vec::new();
+        let mut updated: Vec<usize> = Vec::new();
+
+        for (row, before) in touched {
+            match (*before, self.visible[*row]) {
+                (false, true)   => added.push(*row),
+                (true, false)   => removed.push(*row),
+                (true, true)    => updated.push(*row),
+                (false, false)  => {}
+            }
+        }
+
+        let mut positions: Vec<Value> = Vec::new();
+
+        if !added.is_empty() || !updated.is_empty() {
+            for (index, row) in self.order.iter().enumerate() {
+                if touched.contains_key(row) {
+                    let mut position    = Value::Object(Default::default());
+                    position["row"]     = Value::from(*row);
+                    position["index"]   = Value::from(index);
+                    positions.push(position);
+                }
+            }
+        }
+
+        let mut result          = Value::Object(Default::default());
+        result["added"]         = Value::from(added);
+        result["removed"]       = Value::from(removed);
+        result["updated"]       = Value::from(updated);
+        result["positions"]     = Value::from(positions);
+
+        if let Some(root) = &self.root {
+            let levels = self.group_fields().len();
+            let mut changed: Vec<Value> = Vec::new();
+            let mut removed_groups: Vec<Value> = Vec::new();
+
+            for path in groups {
+                match root.get(path) {
+                    None => removed_groups.push(Value::from(path.clone())),
+                    Some(group) => {
+                        let mut summary         = Value::Object(Default::default());
+                        summary["path"]         = Value::from(path.clone());
+                        summary["id"]           = Value::from(path_id(path));
+                        summary["field"]        = Value::from(group.field.as_str());
+                        summary["child_count"]  = Value::from(group.child_count(path.len() >= levels));
+                        summary["row_count"]    = Value::from(group.row_count());
+
+                        if let Some(aggregates) = &group.aggregates {
+                            summary["aggregates"] = aggregates.clone();
+                        }
+
+                        changed.push(summary);
+                    }
+                }
+            }
+
+            result["groups"] = Value::from(changed);
+            result["removed_groups"] = Value::from(removed_groups);
+        }
+
+        if self.root.is_none() {
+            if let Some(aggregates) = &self.aggregates {
+                result["aggregates"] = aggregates.clone();
+            }
+        }
+
+        result
+    }
+}
+
+/// Row ids are found on their text, strings as is and other values as json.
+fn id_key(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string()
+    }
+}
+
+/// The id processors::group gives the group on the path.
+fn path_id(path: &[String]) -> String {
+    path.iter().fold(ROOT_ID.to_string(), |id, key| group_id(&id, key))
+}
+
+/// The group of the row and all the groups above it, including the root, changed.
+fn mark_groups(path: &[String], groups: &mut BTreeSet<Vec<String>>) {
+    for length in 0..=path.len() {
+        groups.insert(path[..length].to_vec());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::error::ErrorCode;
+    use crate::intent;
+    use crate::intent::{Perspective, RowDelta};
+    use crate::processors::{build_perspective, LivePerspective};
+    use random_data::generate_data;
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "A", "value": 10, "site": "north", "isActive": true}));
+        result.push(json!({"id": 1, "code": "B", "value": 10, "site": "south", "isActive": false}));
+        result.push(json!({"id": 2, "code": "C", "value": 20, "site": "north", "isActive": true}));
+        result.push(json!({"id": 3, "code": "D", "value": 20, "site": "south", "isActive": true}));
+        result.push(json!({"id": 4, "code": "E", "value": 5, "site": "north", "isActive": false}));
+        result
+    }
+
+    fn parse_perspective(intent: &Value) -> Perspective {
+        intent::parse(intent, "").unwrap()
+    }
+
+    fn parse_deltas(deltas: Value) -> Vec<RowDelta> {
+        intent::parse(&deltas, "deltas").unwrap()
+    }
+
+    /// The live result must be the same as building the perspective again on the rows that are left.
+    fn assert_same(live: &LivePerspective) {
+        let rows: Vec<usize> = (0..live.data.len()).filter(|row| !live.deleted[*row]).collect();
+        let expected = build_perspective(&live.perspective, &live.data, None, &rows).unwrap();
+        assert_eq!(live.result().to_string(), expected);
+    }
+
+    #[test]
+    fn live_matches_build_test() {
+        let intents = [
+            json!({}),
+            json!({ "filter": [{ "field": "value", "operator": "<", "value": 20 }] }),
+            json!({ "sort": [{ "name": "code", "direction": "desc" }] }),
+            json!({ "sort": [{ "name": "value" }], "page": { "offset": 1, "limit": 2 } }),
+            json!({ "filter": [{ "field": "isActive", "operator": "==", "value": true }], "aggregates": { "sum": "value", "max": "value" } }),
+            json!({ "sort": [{ "name": "code" }], "group": ["site"], "aggregates": { "sum": "value", "min": "value" } }),
+            json!({ "sort": [{ "name": "value" }, { "name": "code", "direction": "desc" }], "group": ["site", "isActive"], "aggregates": { "ave": "value" } }),
+            json!({ "sort": [{ "name": "site", "direction": "desc" }], "group": ["site", { "field": "code", "order_by": "count", "direction": "desc" }] }),
+            json!({ "group": [{ "field": "site", "order_by": { "sum": "value" }, "direction": "desc" }, "isActive"], "aggregates": { "max": "value" } }),
+            json!({ "group": [{ "field": "code", "case_insensitive": true }, { "field": "missing", "null_label": "none" }] }),
+            json!({ "sort": [{ "name": "value", "direction": "desc" }], "group": [{ "field": "value", "bucket": { "edges": [10, 20] } }], "aggregates": { "sum": "value" } }),
+            json!({
+                "computed": [{ "name": "score", "expression": "value * 2 + length(code)" }, { "name": "band", "expression": "if(score > 20, 'high', 'low')" }],
+                "sort": [{ "name": "score", "direction": "desc" }],
+                "group": ["band"],
+                "aggregates": { "sum": "score" }
+            })
+        ];
+
+        let deltas = [
+            json!([{ "op": "insert", "row": {"id": 5, "code": "F", "value": 15, "site": "east", "isActive": true} }]),
+            json!([{ "op": "update", "id": 1, "row": {"id": 1, "code": "AA", "value": 30, "site": "north", "isActive": true} }]),
+            json!([{ "op": "delete", "index": 2 }, { "op": "delete", "id": 4 }]),
+            json!([{ "op": "update", "index": 5, "row": {"id": 5, "code": "G", "value": 1, "site": "south", "isActive": false} }]),
+            json!([{ "op": "insert", "row": {"id": 6, "code": "0", "value": 8, "site": "west", "isActive": false} }, { "op": "delete", "id": 6 }])
+        ];
+
+        for intent in intents {
+            let mut live = LivePerspective::new(parse_perspective(&intent), get_data(), Some("id".into())).unwrap();
+            assert_same(&live);
+
+            for delta in &deltas {
+                live.apply(&parse_deltas(delta.clone())).unwrap();
+                assert_same(&live);
+            }
+        }
+    }
+
+    #[test]
+    fn diff_test() {
+        let intent = json!({
+            "filter": [{ "field": "value", "operator": "<", "value": 20 }],
+            "sort": [{ "name": "code" }],
+            "group": ["site"],
+            "aggregates": { "sum": "value" }
+        });
+
+        let mut live = LivePerspective::new(parse_perspective(&intent), get_data(), Some("id".into())).unwrap();
+
+        let diff = live.apply(&parse_deltas(json!([
+            { "op": "insert", "row": {"id": 5, "code": "0", "value": 1, "site": "south"} },
+            { "op": "update", "id": 0, "row": {"id": 0, "code": "A", "value": 50, "site": "north"} },
+            { "op": "update", "id": 4, "row": {"id": 4, "code": "E", "value": 6, "site": "east"} },
+            { "op": "update", "id": 3, "row": {"id": 3, "code": "D", "value": 25, "site": "south"} }
+        ]))).unwrap();
+
+        assert_eq!(diff["added"], json!([5]));
+        assert_eq!(diff["removed"], json!([0]));
+        assert_eq!(diff["updated"], json!([4]));
+        assert_eq!(diff["positions"], json!([{"row": 5, "index": 0}, {"row": 4, "index": 2}]));
+        assert_eq!(diff["removed_groups"], json!([["north"]]));
+        assert_eq!(diff["groups"], json!([
+            {"path": [], "id": "root", "field": "root", "child_count": 2, "row_count": 3, "aggregates": [{"agg": "sum", "field": "value", "value": 17.0}]},
+            {"path": ["east"], "id": "root/east", "field": "site", "child_count": 1, "row_count": 1, "aggregates": [{"agg": "sum", "field": "value", "value": 6.0}]},
+            {"path": ["south"], "id": "root/south", "field": "site", "child_count": 2, "row_count": 2, "aggregates": [{"agg": "sum", "field": "value", "value": 11.0}]}
+        ]));
+    }
+
+    #[test]
+    fn diff_aggregates_test() {
+        let intent = json!({ "aggregates": { "sum": "value" } });
+        let mut live = LivePerspective::new(parse_perspective(&intent), get_data(), None).unwrap();
+
+        let diff = live.apply(&parse_deltas(json!([{ "op": "delete", "index": 0 }]))).unwrap();
+
+        assert_eq!(diff["removed"], json!([0]));
+        assert_eq!(diff["aggregates"], json!([{"agg": "sum", "field": "value", "value": 55.0}]));
+        assert!(diff.get("groups").is_none());
+    }
+
+    #[test]
+    fn delta_error_test() {
+        let mut live = LivePerspective::new(parse_perspective(&json!({})), get_data(), Some("id".into())).unwrap();
+
+        let error = live.apply(&parse_deltas(json!([{ "op": "delete", "id": 10 }]))).unwrap_err();
+        assert_eq!(error.code, ErrorCode::RowOutOfRange);
+        assert_eq!(error.path, "[0].id");
+
+        live.apply(&parse_deltas(json!([{ "op": "delete", "index": 1 }]))).unwrap();
+
+        let error = live.apply(&parse_deltas(json!([{ "op": "insert", "row": {} }, { "op": "update", "index": 1, "row": {} }]))).unwrap_err();
+        assert_eq!(error.code, ErrorCode::RowOutOfRange);
+        assert_eq!(error.path, "[1].index");
+
+        let error = live.apply(&parse_deltas(json!([{ "op": "update", "id": 1, "row": {} }]))).unwrap_err();
+        assert_eq!(error.path, "[0].id");
+    }
+
+    #[test]
+    fn duplicate_id_test() {
+        let mut data = get_data();
+        data.push(json!({"id": 1, "code": "X", "value": 1}));
+
+        let error = LivePerspective::new(parse_perspective(&json!({})), data, Some("id".into())).err().unwrap();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "data[5].id");
+
+        let mut live = LivePerspective::new(parse_perspective(&json!({})), get_data(), Some("id".into())).unwrap();
+
+        let error = live.apply(&parse_deltas(json!([{ "op": "insert", "row": {"id": 2, "code": "X"} }]))).unwrap_err();
+        assert_eq!(error.path, "[0].id");
+
+        let error = live.apply(&parse_deltas(json!([{ "op": "update", "id": 3, "row": {"id": 2, "code": "X"} }]))).unwrap_err();
+        assert_eq!(error.path, "[0].id");
+
+        live.apply(&parse_deltas(json!([{ "op": "update", "id": 3, "row": {"id": 3, "code": "X"} }]))).unwrap();
+        assert_same(&live);
+    }
+
+    #[test]
+    fn nested_id_test() {
+        let data = vec![
+            json!({"meta": {"id": "a"}, "keys": [10], "value": 1}),
+            json!({"meta": {"id": "b"}, "keys": [20], "value": 2})
+        ];
+
+        let mut live = LivePerspective::new(parse_perspective(&json!({})), data.clone(), Some("meta.id".into())).unwrap();
+        let diff = live.apply(&parse_deltas(json!([{ "op": "delete", "id": "b" }]))).unwrap();
+        assert_eq!(diff["removed"], json!([1]));
+
+        let mut live = LivePerspective::new(parse_perspective(&json!({})), data, Some("keys[0]".into())).unwrap();
+        let diff = live.apply(&parse_deltas(json!([{ "op": "update", "id": 10, "row": {"meta": {"id": "a"}, "keys": [10], "value": 5} }]))).unwrap();
+        assert_eq!(diff["updated"], json!([0]));
+    }
+
+    #[test]
+    fn aggregate_blocks_test() {
+        let data = generate_data(700);
+        let intent = json!({
+            "filter": [{ "field": "number", "operator": ">", "value": 2 }],
+            "aggregates": { "sum": "number", "min": "number", "max": "number", "max:duration": "duration" }
+        });
+
+        let mut live = LivePerspective::new(parse_perspective(&intent), data.clone(), None).unwrap();
+        assert_same(&live);
+
+        for step in 0..20 {
+            let deltas = json!([
+                { "op": "update", "index": step * 20, "row": data[step * 7] },
+                { "op": "delete", "index": step * 20 + 301 },
+                { "op": "insert", "row": data[step * 13] }
+            ]);
+
+            live.apply(&parse_deltas(deltas)).unwrap();
+            assert_same(&live);
+        }
+    }
+
+    #[test]
+    fn compact_test() {
+        let data = generate_data(300);
+
+        let intents = [
+            json!({ "filter": [{ "field": "number", "operator": ">", "value": 2 }], "aggregates": { "sum": "number", "min": "number" } }),
+            json!({ "sort": [{ "name": "number" }], "group": ["externalCode"], "aggregates": { "sum": "number" } }),
+            json!({ "sort": [{ "name": "number", "direction": "desc" }] })
+        ];
+
+        for intent in intents {
+            let mut rows: Vec<Value> = data.iter().take(100).enumerate().map(|(index, row)| {
+                let mut row = row.clone();
+                row["id"] = json!(index);
+                row
+            }).collect();
+
+            let mut live = LivePerspective::new(parse_perspective(&intent), rows.clone(), Some("id".into())).unwrap();
+            let mut compactions = 0;
+
+            for step in 0..200 {
+                let mut inserted = data[(step * 7) % data.len()].clone();
+                inserted["id"] = json!(1000 + step);
+
+                // two rows out for every row in until the rows run low, then the other way around
+                let deletes = if rows.len() > 20 { 2 } else { 0 };
+                let mut deltas = vec![json!({ "op": "insert", "row": inserted })];
+
+                for _ in 0..deletes {
+                    let row = rows.remove((step * 11) % rows.len());
+                    deltas.push(json!({ "op": "delete", "id": row["id"] }));
+                }
+
+                rows.push(inserted);
+
+                let diff = live.apply(&parse_deltas(Value::from(deltas))).unwrap();
+
+                if let Some(reindexed) = diff.get("reindexed") {
+                    compactions += 1;
+                    assert_eq!(reindexed.as_array().unwrap().len(), rows.len());
+                }
+
+                assert!(live.data.len() <= rows.len() * 2 + 3);
+                assert_eq!(live.data.len() - live.deleted_count, rows.len());
+                assert_same(&live);
+            }
+
+            assert!(compactions > 0);
+        }
+    }
+
+    #[test]
+    fn random_data_test() {
+        let data = generate_data(300);
+        let intent = json!({
+            "filter": [{ "field": "number", "operator": ">", "value": 2 }],
+            "sort": [{ "name": "number", "direction": "desc" }, { "name": "id" }],
+            "group": ["externalCode", "number"],
+            "aggregates": { "sum": "number", "max:duration": "duration" }
+        });
+
+        let mut live = LivePerspective::new(parse_perspective(&intent), data.clone(), Some("id".into())).unwrap();
+
+        for step in 0..50 {
+            let source = &data[(step * 7) % data.len()];
+
+            let mut row = source.clone();
+            row["id"] = json!(1000 + step);
+
+            let mut inserted = source.clone();
+            inserted["id"] = json!(2000 + step);
+
+            let deltas = json!([
+                { "op": "update", "index": step * 5 + 2, "row": row },
+                { "op": "delete", "index": step * 5 + 1 },
+                { "op": "insert", "row": inserted }
+            ]);
+
+            live.apply(&parse_deltas(deltas)).unwrap();
+        }
+
+        assert_same(&live);
+    }
+}
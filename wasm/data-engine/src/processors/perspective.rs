@@ -0,0 +1,495 @@
+use serde_json::{json, Value};
+use crate::processors;
+use crate::error::DataResult;
+use crate::columnar;
+use crate::columnar::{ColumnFilter, ColumnTable};
+use crate::evaluators::FilterPlan;
+use crate::expression::Computed;
+use crate::intent::{GroupSpec, Perspective};
+use crate::row::Row;
+
+/// Columnar datasets pass their columns, the processors then run on the columns instead of the rows.
+pub fn build_perspective<R: Row>(perspective: &Perspective, data: &[R], columns: Option<&ColumnTable>, rows: &[usize]) -> DataResult<String> {
+    perspective_result(perspective, data, columns, rows).map(|result| result.to_string())
+}
+
+/// The perspective as json, row indexes when there is no group or aggregates.
+/// With a page the row indexes are the rows of the page, see page_result.
+/// An empty rows slice means all the rows of the data.
+pub fn perspective_result<R: Row>(perspective: &Perspective, data: &[R], columns: Option<&ColumnTable>, rows: &[usize]) -> DataResult<Value> {
+    match &perspective.computed {
+        None => apply_perspective(perspective, data, columns, rows),
+        Some(fields) => {
+            let computed = Computed::compile(fields).map_err(|error| error.at("computed"))?;
+
+            // the columns of the dataset don't have the computed fields, the computed rows are read directly
+            apply_perspective(perspective, &computed.apply(data), None, rows)
+        }
+    }
+}
+
+fn apply_perspective<R: Row>(perspective: &Perspective, data: &[R], columns: Option<&ColumnTable>, rows: &[usize]) -> DataResult<Value> {
+    let mut rows = get_rows(perspective, data, columns, rows);
+
+    let sort = perspective.sort.as_deref();
+    let group = perspective.group.as_deref();
+    let aggregates = perspective.aggregates.as_ref().map(|aggregates| aggregates.0.as_slice());
+
+    if group.is_none() && aggregates.is_none() {
+        let total = rows.len();
+        let page = perspective.page.unwrap_or_default();
+
+        // only the rows up to the end of the page need sorting
+        let rows = match sort {
+            None => page.apply(rows),
+            Some(def) => match columns {
+                None => processors::sort_page(def, data, Some(rows), &page),
+                Some(table) => columnar::sort_page(table, def, data, Some(rows), &page)
+            }
+        };
+
+        return match perspective.page {
+            None => Ok(Value::from(rows)),
+            Some(_) => Ok(page_result(rows, total))
+        };
+    }
+
+    if let Some(def) = sort {
+        rows = match columns {
+            None => processors::sort(def, data, Some(rows)),
+            Some(table) => columnar::sort(table, def, data, Some(rows))
+        };
+    }
+
+    match group {
+        None => {}
+        Some(def) => {
+            // groups on a sort field are in the order of the sort
+            let def: Vec<GroupSpec> = def.iter().map(|spec| spec.follow_sort(sort.unwrap_or_default())).collect();
+            let def = def.as_slice();
+
+            let grouping = match columns {
+                None => processors::group(def, data, Some(rows), aggregates)?,
+                Some(table) => columnar::group(table, def, data, Some(rows), aggregates)?
+            };
+            return Ok(grouping);
+        }
+    }
+
+    match aggregates {
+        None => {}
+        Some(def) => {
+            let result = match columns {
+                None => processors::aggregate_rows(def, data, Some(rows))?,
+                Some(table) => columnar::aggregate_rows(table, def, data, Some(rows))?
+            };
+            return Ok(result);
+        }
+    }
+
+    Ok(Value::Null)
+}
+
+/// The rows of a page with the number of rows in all the pages.
+pub fn page_result(rows: Vec<usize>, total: usize) -> Value {
+    json!({ "rows": rows, "total": total })
+}
+
+fn get_rows<R: Row>(perspective: &Perspective, data: &[R], columns: Option<&ColumnTable>, rows: &[usize]) -> Vec<usize> {
+    let case_sensitive = perspective.case_sensitive;
+
+    let result: Vec<usize>;
+
+    match &perspective.filter {
+        None => {
+            if !rows.is_empty() {
+                result = rows.to_vec();
+            }
+            else {
+                result = get_row_range(data.len())
+            }
+        }
+        Some(filters) => {
+            let matches: Box<dyn Fn(usize) -> bool + '_> = match columns {
+                None => {
+                    let plan = FilterPlan::compile(filters, case_sensitive);
+                    Box::new(move |row| plan.matches(&data[row]))
+                }
+                Some(table) => {
+                    let filter = ColumnFilter::compile(table, data, filters, case_sensitive);
+                    Box::new(move |row| filter.matches(row))
+                }
+            };
+
+            if rows.is_empty() {
+                result = (0..data.len()).filter(|row| matches(*row)).collect();
+            }
+            else {
+                result = rows.iter().copied().filter(|row| matches(*row)).collect();
+            }
+        }
+    }
+
+    result
+}
+
+fn get_row_range(length: usize) -> Vec<usize> {
+    let mut result: Vec<usize> = Vec::new();
+    for i in 0..length {
+        result.push(i);
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::columnar::ColumnTable;
+    use crate::processors::{build_perspective, child_group};
+    use crate::processors::perspective::get_rows;
+    use crate::error::{DataResult, ErrorCode};
+    use crate::intent;
+    use crate::intent::Perspective;
+
+    fn child<'a>(group: &'a Value, value: &str) -> &'a Value {
+        child_group(group, value).unwrap()
+    }
+
+    fn parse(intent: &Value) -> DataResult<Perspective> {
+        intent::parse(intent, "")
+    }
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "A", "value": 10, "isActive": true, "person": {"name": "John"}}));
+        result.push(json!({"id": 1, "code": "B", "value": 10, "isActive": false, "person": {"name": "John"}}));
+        result.push(json!({"id": 2, "code": "C", "value": 20, "isActive": true, "person": {"name": "Jane"}}));
+        result.push(json!({"id": 3, "code": "D", "value": 20, "isActive": true, "person": {"name": "Jane"}}));
+        result.push(json!({"id": 4, "code": "E", "value": 5, "isActive": false, "person": {"name": "Andrew"}}));
+        result
+    }
+
+    #[test]
+    fn get_rows_no_filter_test() {
+        let data = get_data();
+        let intent = json!({});
+        let result = get_rows(&parse(&intent).unwrap(), &data, None, &[]);
+
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[0], 0);
+        assert_eq!(result[1], 1);
+        assert_eq!(result[2], 2);
+        assert_eq!(result[3], 3);
+        assert_eq!(result[4], 4);
+    }
+
+    #[test]
+    fn get_rows_with_filter_test() {
+        let data = get_data();
+        let intent = json!({
+            "filter": [{ "field": "value", "operator": "<", "value": 20 }]
+        });
+        let result = get_rows(&parse(&intent).unwrap(), &data, None, &[]);
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0], 0);
+        assert_eq!(result[1], 1);
+        assert_eq!(result[2], 4);
+    }
+
+    #[test]
+    fn get_rows_with_filter_and_rows_test() {
+        let data = get_data();
+        let intent = json!({
+            "filter": [{ "field": "value", "operator": "<", "value": 20 }]
+        });
+        let result = get_rows(&parse(&intent).unwrap(), &data, None, &[1, 2, 3]);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0], 1);
+    }
+
+    #[test]
+    fn just_nothing_test() {
+        let data = get_data();
+
+        let intent = json!({});
+
+        let result = build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap();
+        assert_eq!(result, "[0,1,2,3,4]");
+    }
+
+    #[test]
+    fn just_sort_test() {
+        let data = get_data();
+
+        let intent1 = json!({
+            "sort": [{"name": "code", "direction": "asc"}]
+        });
+
+        let intent2 = json!({
+            "sort": [{"name": "code", "direction": "dec"}]
+        });
+
+        let result = build_perspective(&parse(&intent1).unwrap(), &data, None, &[]).unwrap();
+        assert_eq!(result, "[0,1,2,3,4]");
+
+        let result = build_perspective(&parse(&intent2).unwrap(), &data, None, &[]).unwrap();
+        assert_eq!(result, "[4,3,2,1,0]");
+    }
+
+    #[test]
+    fn just_grouping_test() {
+        let data = get_data();
+        let intent = json!({
+            "group": ["value"]
+        });
+
+        let result = build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap();
+        assert!(result.contains("root"));
+    }
+
+    #[test]
+    fn just_grouping_rows_test() {
+        let data = get_data();
+        let intent = json!({
+            "group": ["value"]
+        });
+
+        let result = build_perspective(&parse(&intent).unwrap(), &data, None, &[0, 1, 2]).unwrap();
+        let json: Value = serde_json::from_str(result.as_str()).unwrap();
+
+        assert_eq!(child(&json["root"], "10")["row_count"], Value::from(2));
+        assert_eq!(child(&json["root"], "20")["row_count"], Value::from(1));
+        assert!(child_group(&json["root"], "5").is_none());
+    }
+
+    #[test]
+    fn just_filter_test() {
+        let data = get_data();
+
+        let intent = json!({
+            "filter": [{ "field": "value", "operator": "<", "value": 20 }],
+            "case_sensitive": false
+        });
+
+        let result = build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap();
+        assert_eq!(result, "[0,1,4]");
+    }
+
+    #[test]
+    fn filter_and_sort_test() {
+        let data = get_data();
+
+        let intent = json!({
+            "filter": [{ "field": "value", "operator": "<", "value": 20 }],
+            "case_sensitive": false,
+            "sort": [{"name": "code", "direction": "asc"}]
+        });
+
+        let result = build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap();
+        assert_eq!(result, "[0,1,4]");
+    }
+
+    #[test]
+    fn filter_and_sort_and_group_test() {
+        let data = get_data();
+
+        let intent = json!({
+            "filter": [{ "field": "value", "operator": "<", "value": 20 }],
+            "case_sensitive": false,
+            "sort": [{"name": "code", "direction": "asc"}],
+            "group": ["value"]
+        });
+
+        let result = build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap();
+        let group: Value = serde_json::from_str(result.as_str()).unwrap();
+
+        assert_eq!(group["root"]["child_count"], 2);
+        assert_eq!(child(&group["root"], "10")["child_count"], 2);
+        assert_eq!(child(&group["root"], "5")["child_count"], 1);
+    }
+
+    #[test]
+    fn filter_and_sort_and_aggregate() {
+        let data = get_data();
+
+        let intent = json!({
+            "filter": [{ "field": "value", "operator": "<", "value": 20 }],
+            "case_sensitive": false,
+            "sort": [{"name": "code", "direction": "asc"}],
+            "aggregates": {
+                "min": "value",
+                "max": "value",
+                "ave": "value"
+            }
+        });
+
+        let result = build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap();
+        let agg: Value = serde_json::from_str(result.as_str()).unwrap();
+        let collection = agg.as_array().unwrap();
+
+        assert_eq!(collection[0]["agg"], String::from("ave"));
+        assert_eq!(collection[0]["value"].to_string(), String::from("8.333333333333334"));
+
+        assert_eq!(collection[1]["agg"], String::from("max"));
+        assert_eq!(collection[1]["value"].to_string(), String::from("10.0"));
+
+        assert_eq!(collection[2]["agg"], String::from("min"));
+        assert_eq!(collection[2]["value"].to_string(), String::from("5.0"));
+    }
+
+    #[test]
+    fn sum_aggregate() {
+        let data = get_data();
+
+        let intent = json!({
+            "case_sensitive": false,
+            "group": ["isActive"],
+            "aggregates": {
+                "sum": "value"
+            }
+        });
+
+        let result = build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap();
+        let expected = "{\"root\":{\"aggregates\":[{\"agg\":\"sum\",\"field\":\"value\",\"value\":65.0}],\"child_count\":2,\"children\":[{\"aggregates\":[{\"agg\":\"sum\",\"field\":\"value\",\"value\":15.0}],\"child_count\":2,\"field\":\"isActive\",\"id\":\"root/#false\",\"key\":false,\"row_count\":2,\"rows\":[1,4],\"value\":\"false\"},{\"aggregates\":[{\"agg\":\"sum\",\"field\":\"value\",\"value\":50.0}],\"child_count\":3,\"field\":\"isActive\",\"id\":\"root/#true\",\"key\":true,\"row_count\":3,\"rows\":[0,2,3],\"value\":\"true\"}],\"field\":\"root\",\"id\":\"root\",\"key\":\"root\",\"row_count\":5,\"value\":\"root\"}}";
+        assert_eq!(expected, result.as_str());
+    }
+
+    #[test]
+    fn group_follows_sort_test() {
+        let data = get_data();
+        let table = ColumnTable::default();
+
+        let intent = json!({ "sort": [{ "name": "value", "direction": "desc" }], "group": ["value", "isActive"] });
+        let perspective = parse(&intent).unwrap();
+        let result: Value = serde_json::from_str(&build_perspective(&perspective, &data, None, &[]).unwrap()).unwrap();
+        let values: Vec<&Value> = result["root"]["children"].as_array().unwrap().iter().map(|child| &child["value"]).collect();
+
+        assert_eq!(values, vec!["20", "10", "5"]);
+        assert_eq!(child(&result["root"], "10")["children"][0]["value"], "false");
+        assert_eq!(build_perspective(&perspective, &data, Some(&table), &[]).unwrap(), result.to_string());
+    }
+
+    #[test]
+    fn null_filter_value() {
+        // Arrange
+        let json= r#"[
+            {"code": "a", "site": "A11"},
+            {"code": "a", "site": "A31"},
+            {"code": "b", "site": "A31"},
+            {"code": "b", "site": "A11"},
+            {"code": "a", "site": "A12"},
+            {"code": null, "site": "A12"}
+        ]"#;
+        let data_array: Vec<Value> = serde_json::from_str(json).unwrap();
+        let intent = r#"{"filter":[{"field":"code","operator":"=","value":"a"}],"sort":[{"direction":"asc","name":"site"}]}"#;
+        let intent_obj: Value = serde_json::from_str(intent).unwrap();
+        let rows: Vec<usize> = vec![];
+
+        // Act
+        let result = build_perspective(&parse(&intent_obj).unwrap(), &data_array, None, &rows).unwrap();
+
+        // Assert
+        assert_eq!(result, "[0,4,1]");
+    }
+
+    #[test]
+    fn invalid_intent_test() {
+        let intent = json!({
+            "filter": [
+                { "field": "value", "operator": "<", "value": 20 },
+                { "field": "value", "operator": ">", "value": 1 },
+                { "field": "value", "operator": "betwen", "value": [1, 10] }
+            ]
+        });
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::UnknownOperator);
+        assert_eq!(error.path, "filter[2].operator");
+        assert_eq!(error.message, "unknown operator 'betwen'");
+
+        let intent = json!({ "group": ["value", 10] });
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidType);
+        assert_eq!(error.path, "group[1]");
+
+        let intent = json!({ "group": ["value"], "aggregates": { "sum": "value", "avg": "value" } });
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::UnknownAggregate);
+        assert_eq!(error.path, "aggregates.avg");
+
+        let intent = json!({ "sort": {"name": "code"} });
+        let error = parse(&intent).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidType);
+        assert_eq!(error.path, "sort");
+    }
+
+    #[test]
+    fn columnar_test() {
+        let data = get_data();
+        let table = ColumnTable::default();
+
+        let intents = [
+            json!({ "filter": [{ "field": "person.name", "operator": "==", "value": "JOHN" }] }),
+            json!({ "filter": [{ "field": "value", "operator": ">", "value": 5 }], "sort": [{ "name": "code", "direction": "desc" }] }),
+            json!({ "sort": [{ "name": "value" }], "group": ["isActive", "value"], "aggregates": { "ave": "value" } }),
+            json!({ "filter": [{ "field": "isActive", "operator": "==", "value": true }], "aggregates": { "max": "value", "min": "id" } })
+        ];
+
+        for intent in intents {
+            let perspective = parse(&intent).unwrap();
+
+            for rows in [vec![], vec![4, 3, 1]] {
+                let expected = build_perspective(&perspective, &data, None, &rows).unwrap();
+                assert_eq!(build_perspective(&perspective, &data, Some(&table), &rows).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn computed_test() {
+        let data = get_data();
+        let table = ColumnTable::default();
+        let computed = json!([
+            { "name": "total", "expression": "value * id" },
+            { "name": "label", "expression": "concat(code, '-', person.name)" }
+        ]);
+
+        let intent = json!({ "computed": computed, "filter": [{ "field": "total", "operator": ">", "value": 10 }], "sort": [{ "name": "total", "direction": "desc" }] });
+        assert_eq!(build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap(), "[3,2,4]");
+        assert_eq!(build_perspective(&parse(&intent).unwrap(), &data, Some(&table), &[]).unwrap(), "[3,2,4]");
+
+        let intent = json!({ "computed": computed, "filter": [{ "field": "label", "operator": "==", "value": "b-john" }] });
+        assert_eq!(build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap(), "[1]");
+
+        let intent = json!({ "computed": computed, "group": ["isActive"], "aggregates": { "sum": "total" } });
+        let result: Value = serde_json::from_str(&build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap()).unwrap();
+        assert_eq!(child(&result["root"], "true")["aggregates"][0]["value"], json!(100.0));
+        assert_eq!(child(&result["root"], "false")["aggregates"][0]["value"], json!(30.0));
+
+        let error = parse(&json!({ "computed": [{ "name": "total", "expression": "value *" }] })).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "computed[0].expression");
+
+        let error = parse(&json!({ "computed": [{ "name": "a", "expression": "1" }, { "name": "a", "expression": "2" }] })).unwrap_err();
+        assert_eq!(error.path, "computed[1].name");
+    }
+
+    #[test]
+    fn page_test() {
+        let data = get_data();
+        let table = ColumnTable::default();
+
+        let intent = json!({ "sort": [{ "name": "value", "direction": "desc" }], "page": { "offset": 1, "limit": 2 } });
+        let expected = json!({ "rows": [3, 0], "total": 5 }).to_string();
+        assert_eq!(build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap(), expected);
+        assert_eq!(build_perspective(&parse(&intent).unwrap(), &data, Some(&table), &[]).unwrap(), expected);
+
+        let intent = json!({ "filter": [{ "field": "value", "operator": "<", "value": 20 }], "page": { "offset": 2 } });
+        assert_eq!(build_perspective(&parse(&intent).unwrap(), &data, None, &[]).unwrap(), json!({ "rows": [4], "total": 3 }).to_string());
+
+        let error = parse(&json!({ "group": ["value"], "page": { "limit": 10 } })).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "page");
+    }
+}
@@ -0,0 +1,377 @@
+use std::slice;
+use serde_json::Value;
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::intent::{AggregateSpec, GroupSpec, Pivot};
+use crate::processors::aggregate::{add_rows, aggregate_rows, create_aggregators, merge_aggregators};
+use crate::processors::group::{build_field_structure, group_id, Field, ROOT_ID};
+use crate::row::Row;
+use crate::traits::Aggregate;
+use crate::utils::flood_indexes;
+
+/// The label of a total in the flat records, in place of the group under the last key.
+const TOTAL_LABEL: &str = "Total";
+
+/// A row or column of the pivot, a group of the fields or the total of the groups under it.
+struct Header {
+    id: String,
+    keys: Vec<Value>,
+    labels: Vec<String>,
+    rows: Vec<usize>,
+    children: Vec<usize>,
+    total: bool
+}
+
+impl Header {
+    fn to_json(&self) -> Value {
+        let mut obj         = Value::Object(Default::default());
+        obj["id"]           = Value::from(self.id.as_str());
+        obj["keys"]         = Value::from(self.keys.clone());
+        obj["labels"]       = Value::from(self.labels.clone());
+        obj["depth"]        = Value::from(self.keys.len());
+        obj["total"]        = Value::from(self.total);
+        obj["row_count"]    = Value::from(self.rows.len());
+        obj
+    }
+
+    /// The labels of the header with the total label after them on a total.
+    fn title(&self) -> Vec<&str> {
+        let mut result: Vec<&str> = self.labels.iter().map(String::as_str).collect();
+
+        if self.total {
+            result.push(TOTAL_LABEL);
+        }
+
+        result
+    }
+}
+
+/// The aggregators of every cell, row by row, with the number of rows in the cell.
+struct Cells {
+    aggregators: Vec<Vec<Box<dyn Aggregate>>>,
+    counts: Vec<usize>,
+    columns: usize
+}
+
+impl Cells {
+    fn new(values: &[AggregateSpec], rows: usize, columns: usize) -> Cells {
+        Cells {
+            aggregators: (0..rows * columns).map(|_| create_aggregators(values)).collect(),
+            counts: vec![0; rows * columns],
+            columns
+        }
+    }
+
+    fn index(&self, row: usize, column: usize) -> usize {
+        row * self.columns + column
+    }
+
+    /// Merge the other cells into the cell, the cells of the groups under a total.
+    fn merge(&mut self, cell: usize, others: impl Iterator<Item = usize>) {
+        let mut aggregator = std::mem::take(&mut self.aggregators[cell]);
+
+        for other in others {
+            merge_aggregators(&mut aggregator, &self.aggregators[other]);
+            self.counts[cell] += self.counts[other];
+        }
+
+        self.aggregators[cell] = aggregator;
+    }
+
+    fn values(&self, cell: usize) -> Vec<Value> {
+        self.aggregators[cell].iter()
+            .map(|aggregator| if self.counts[cell] == 0 { Value::Null } else { aggregator.value() })
+            .collect()
+    }
+}
+
+/// The cross tab of the rows for a grid or an export.
+/// The rows and columns are the groups of their fields in the order of the group specs,
+/// every group is followed by its total and the grand total is last.
+/// Each row has a cell per column with the value of every aggregate, null where no rows meet.
+/// The records and fields are the same cross tab flattened for an export,
+/// one record per row keyed on the row fields and a field per column and aggregate.
+pub fn pivot<R: Row>(intent: &Pivot, data: &[R], rows: Option<Vec<usize>>) -> DataResult<Value> {
+    let rows = rows.unwrap_or_else(|| flood_indexes(data));
+
+    let row_headers = headers(&intent.rows, data, &rows)?;
+    let column_headers = headers(&intent.columns, data, &rows)?;
+    let cells = calculate_cells(intent, data, &row_headers, &column_headers)?;
+
+    let result_rows: Vec<Value> = row_headers.iter().enumerate().map(|(row_header, header)| {
+        let values: Vec<Value> = (0..column_headers.len())
+            .map(|column_header| Value::from(cells.values(cells.index(row_header, column_header))))
+            .collect();
+
+        let mut obj = header.to_json();
+        obj["cells"] = Value::from(values);
+        obj
+    }).collect();
+
+    let values = serde_json::to_value(&intent.values)
+        .map_err(|error| DataError::new(ErrorCode::InvalidValue, "values", error.to_string()))?;
+
+    let mut result              = Value::Object(Default::default());
+    result["row_fields"]        = fields(&intent.rows);
+    result["column_fields"]     = fields(&intent.columns);
+    result["values"]            = values;
+    result["columns"]           = Value::from(column_headers.iter().map(Header::to_json).collect::<Vec<Value>>());
+    result["rows"]              = Value::from(result_rows);
+    result["fields"]            = Value::from(record_fields(intent, &column_headers));
+    result["records"]           = records(intent, &row_headers, &column_headers, &cells);
+
+    Ok(result)
+}
+
+/// The rows of the data are only added to the cells where a group of the last row field meets a group of the last column field.
+/// The totals merge the cells of the groups under them, the columns of a row first and then the rows.
+fn calculate_cells<R: Row>(intent: &Pivot, data: &[R], row_headers: &[Header], column_headers: &[Header]) -> DataResult<Cells> {
+    let mut cells = Cells::new(&intent.values, row_headers.len(), column_headers.len());
+    let mut column_of: Vec<Option<usize>> = vec![None; data.len()];
+
+    for (position, header) in column_headers.iter().enumerate().filter(|(_, header)| !header.total) {
+        for row in &header.rows {
+            column_of[*row] = Some(position);
+        }
+    }
+
+    // the headers are after the headers of the groups under them, those cells are done when a total gets to them
+    for (row_header, header) in row_headers.iter().enumerate() {
+        if header.total {
+            for column_header in 0..column_headers.len() {
+                let cell = cells.index(row_header, column_header);
+                let children: Vec<usize> = header.children.iter().map(|child| cells.index(*child, column_header)).collect();
+                cells.merge(cell, children.into_iter());
+            }
+
+            continue;
+        }
+
+        let mut cell_rows: Vec<Vec<usize>> = vec![Vec::new(); column_headers.len()];
+
+        for row in &header.rows {
+            if let Some(column_header) = column_of[*row] {
+                cell_rows[column_header].push(*row);
+            }
+        }
+
+        for (column_header, column) in column_headers.iter().enumerate() {
+            let cell = cells.index(row_header, column_header);
+
+            if column.total {
+                let children: Vec<usize> = column.children.iter().map(|child| cells.index(row_header, *child)).collect();
+                cells.merge(cell, children.into_iter());
+                continue;
+            }
+
+            add_rows(&mut cells.aggregators[cell], &intent.values, data, &cell_rows[column_header])?;
+            cells.counts[cell] = cell_rows[column_header].len();
+        }
+    }
+
+    Ok(cells)
+}
+
+fn fields(specs: &[GroupSpec]) -> Value {
+    Value::from(specs.iter().map(|spec| spec.field.as_str()).collect::<Vec<&str>>())
+}
+
+/// The name of the aggregate and its field, "sum value".
+fn value_label(spec: &AggregateSpec) -> String {
+    format!("{} {}", spec.aggregate.as_str(), spec.field)
+}
+
+/// The field of a column and aggregate in the records, "2023 / sum value" or "Total / sum value".
+fn column_field(header: &Header, spec: &AggregateSpec) -> String {
+    let mut parts = header.title();
+    let value = value_label(spec);
+    parts.push(&value);
+    parts.join(" / ")
+}
+
+/// The fields of the records in order, the row fields and then every column with each aggregate.
+fn record_fields(intent: &Pivot, column_headers: &[Header]) -> Vec<String> {
+    let mut result: Vec<String> = intent.rows.iter().map(|spec| spec.field.clone()).collect();
+
+    for header in column_headers {
+        for spec in &intent.values {
+            result.push(column_field(header, spec));
+        }
+    }
+
+    result
+}
+
+/// A flat record per row of the pivot.
+/// The row fields hold the labels of the row, a total has the total label after its labels and null after that.
+fn records(intent: &Pivot, row_headers: &[Header], column_headers: &[Header], cells: &Cells) -> Value {
+    let result: Vec<Value> = row_headers.iter().enumerate().map(|(row_header, header)| {
+        let mut record = Value::Object(Default::default());
+        let title = header.title();
+
+        for (i, spec) in intent.rows.iter().enumerate() {
+            record[spec.field.as_str()] = title.get(i).map(|label| Value::from(*label)).unwrap_or(Value::Null);
+        }
+
+        for (column_header, column) in column_headers.iter().enumerate() {
+            let values = cells.values(cells.index(row_header, column_header));
+
+            for (spec, value) in intent.values.iter().zip(values) {
+                record[column_field(column, spec).as_str()] = value;
+            }
+        }
+
+        record
+    }).collect();
+
+    Value::from(result)
+}
+
+/// The groups of the specs in order, each group after the groups under it.
+fn headers<R: Row>(specs: &[GroupSpec], data: &[R], rows: &[usize]) -> DataResult<Vec<Header>> {
+    let mut root = build_field_structure(data, specs, Some(rows.to_vec()));
+    root.order(specs, &|spec, rows| aggregate_rows(slice::from_ref(spec), data, Some(rows)))?;
+
+    let mut result = Vec::new();
+    add_headers(&root, ROOT_ID.to_string(), Vec::new(), Vec::new(), specs.len(), &mut result);
+    Ok(result)
+}
+
+/// Add the header of the field after the headers of its children and give back its position.
+fn add_headers(field: &Field, id: String, keys: Vec<Value>, labels: Vec<String>, levels: usize, result: &mut Vec<Header>) -> usize {
+    let mut children = Vec::new();
+
+    for child in field.children() {
+        let mut child_keys = keys.clone();
+        child_keys.push(child.key().clone());
+
+        let mut child_labels = labels.clone();
+        child_labels.push(child.value().to_string());
+
+        children.push(add_headers(child, group_id(&id, child.bucket()), child_keys, child_labels, levels, result));
+    }
+
+    result.push(Header {
+        id,
+        total: keys.len() < levels,
+        keys,
+        labels,
+        rows: field.collect_rows(),
+        children
+    });
+
+    result.len() - 1
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::error::ErrorCode;
+    use crate::intent;
+    use crate::intent::Pivot;
+    use crate::processors::pivot;
+
+    fn get_data() -> Vec<Value> {
+        vec![
+            json!({"site": "north", "code": "A", "year": 2023, "value": 10}),
+            json!({"site": "south", "code": "B", "year": 2023, "value": 20}),
+            json!({"site": "north", "code": "B", "year": 2024, "value": 30}),
+            json!({"site": "north", "code": "A", "year": 2024, "value": 5}),
+            json!({"site": "south", "code": "B", "year": 2024})
+        ]
+    }
+
+    fn parse(intent: Value) -> Pivot {
+        intent::parse(&intent, "pivot").unwrap()
+    }
+
+    fn labels(headers: &Value) -> Vec<Value> {
+        headers.as_array().unwrap().iter().map(|header| header["labels"].clone()).collect()
+    }
+
+    #[test]
+    fn pivot_test() {
+        let intent = parse(json!({ "rows": ["site"], "columns": ["year"], "values": [{ "agg": "sum", "field": "value" }, { "agg": "max", "field": "value" }] }));
+        let result = pivot(&intent, &get_data(), None).unwrap();
+
+        assert_eq!(result["row_fields"], json!(["site"]));
+        assert_eq!(result["values"], json!([{ "agg": "sum", "field": "value" }, { "agg": "max", "field": "value" }]));
+        assert_eq!(labels(&result["columns"]), vec![json!(["2023"]), json!(["2024"]), json!([])]);
+        assert_eq!(labels(&result["rows"]), vec![json!(["north"]), json!(["south"]), json!([])]);
+
+        let north = &result["rows"][0];
+        assert_eq!(north["id"], "root/north");
+        assert_eq!(north["total"], false);
+        assert_eq!(north["row_count"], 3);
+        assert_eq!(north["cells"], json!([[10.0, 10.0], [35.0, 30.0], [45.0, 30.0]]));
+
+        let total = &result["rows"][2];
+        assert_eq!(total["id"], "root");
+        assert_eq!(total["total"], true);
+        assert_eq!(total["depth"], 0);
+        assert_eq!(total["cells"][2], json!([65.0, 30.0]));
+
+        assert_eq!(result["columns"][0]["keys"], json!([2023]));
+        assert_eq!(result["columns"][2]["row_count"], 5);
+    }
+
+    #[test]
+    fn subtotal_test() {
+        let intent = parse(json!({
+            "rows": ["site", { "field": "code", "direction": "desc" }],
+            "values": [{ "agg": "sum", "field": "value" }]
+        }));
+
+        let result = pivot(&intent, &get_data(), Some(vec![0, 1, 2, 3])).unwrap();
+
+        assert_eq!(labels(&result["rows"]), vec![
+            json!(["north", "B"]), json!(["north", "A"]), json!(["north"]),
+            json!(["south", "B"]), json!(["south"]),
+            json!([])
+        ]);
+
+        let totals: Vec<&Value> = result["rows"].as_array().unwrap().iter().map(|row| &row["total"]).collect();
+        assert_eq!(totals, vec![false, false, true, false, true, true]);
+
+        let cells: Vec<&Value> = result["rows"].as_array().unwrap().iter().map(|row| &row["cells"][0][0]).collect();
+        assert_eq!(cells, vec![30.0, 15.0, 45.0, 20.0, 20.0, 65.0]);
+        assert_eq!(labels(&result["columns"]), vec![json!([])]);
+    }
+
+    #[test]
+    fn empty_cell_test() {
+        let intent = parse(json!({ "rows": ["code"], "columns": ["site"], "values": [{ "agg": "min", "field": "value" }] }));
+        let result = pivot(&intent, &get_data(), None).unwrap();
+
+        assert_eq!(labels(&result["rows"])[0], json!(["A"]));
+        assert_eq!(result["rows"][0]["cells"], json!([[5.0], [null], [5.0]]));
+    }
+
+    #[test]
+    fn records_test() {
+        let intent = parse(json!({ "rows": ["site", "code"], "columns": ["year"], "values": [{ "agg": "sum", "field": "value" }] }));
+        let result = pivot(&intent, &get_data(), None).unwrap();
+
+        assert_eq!(result["fields"], json!(["site", "code", "2023 / sum value", "2024 / sum value", "Total / sum value"]));
+
+        let records = result["records"].as_array().unwrap();
+        assert_eq!(records.len(), result["rows"].as_array().unwrap().len());
+        assert_eq!(records[0], json!({ "site": "north", "code": "A", "2023 / sum value": 10.0, "2024 / sum value": 5.0, "Total / sum value": 15.0 }));
+        assert_eq!(records[2], json!({ "site": "north", "code": "Total", "2023 / sum value": 10.0, "2024 / sum value": 35.0, "Total / sum value": 45.0 }));
+        assert_eq!(records[4]["2023 / sum value"], 20.0);
+        assert_eq!(records[4]["2024 / sum value"], 0.0);
+        assert_eq!(records[5], json!({ "site": "Total", "code": null, "2023 / sum value": 30.0, "2024 / sum value": 35.0, "Total / sum value": 65.0 }));
+    }
+
+    #[test]
+    fn intent_test() {
+        let error = intent::parse::<Pivot>(&json!({ "rows": ["site"], "values": [] }), "pivot").unwrap_err();
+        assert_eq!(error.path, "pivot.values");
+
+        let error = intent::parse::<Pivot>(&json!({ "rows": ["site"], "values": [{ "agg": "avg", "field": "value" }] }), "pivot").unwrap_err();
+        assert_eq!(error.code, ErrorCode::UnknownAggregate);
+        assert_eq!(error.path, "pivot.values[0].agg");
+
+        let error = intent::parse::<Pivot>(&json!({ "columns": [{ "field": "value", "bucket": { "width": 0 } }], "values": [{ "agg": "sum", "field": "value" }] }), "pivot").unwrap_err();
+        assert_eq!(error.path, "pivot.columns[0].bucket.width");
+    }
+}
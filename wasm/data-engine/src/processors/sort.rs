@@ -0,0 +1,573 @@
+use std::cmp::Ordering;
+use serde_json::Value;
+use crate::compare::{compare_sort_values, compare_values_with};
+use crate::date::parse_date_with;
+use crate::duration::{duration_ordering, parse_duration};
+use crate::enums::SortDirection::Descending;
+use crate::path::FieldPath;
+use crate::intent::{Page, SortField, SortType};
+use crate::row::Row;
+
+use crate::utils::{flood_indexes, select_page};
+
+/// Sort the rows on the fields of the intent.
+/// Rows with the same values on all the sort fields keep the order of their index,
+/// so the result is the same whatever order the rows are passed in.
+pub fn sort<R: Row>(intent: &[SortField], data: &[R], rows: Option<Vec<usize>>) -> Vec<usize> {
+    sort_page(intent, data, rows, &Page::default())
+}
+
+/// The rows of the page in sort order, the same rows as slicing the full sort.
+/// Rows after the end of the page are not sorted, so the top rows of a large set are cheap.
+pub fn sort_page<R: Row>(intent: &[SortField], data: &[R], rows: Option<Vec<usize>>, page: &Page) -> Vec<usize> {
+    let rows = match rows {
+        Some(array) => array,
+        None => flood_indexes(data)
+    };
+
+    let paths = sort_paths(intent);
+    select_page(rows, page, |a, b| compare_fields(intent, &paths, &data[*a], &data[*b]).then(a.cmp(b)))
+}
+
+/// The order of two objects on the sort fields, Equal when all the fields are equal.
+pub fn compare_objects(intent: &[SortField], object1: &dyn Row, object2: &dyn Row) -> Ordering {
+    compare_fields(intent, &sort_paths(intent), object1, object2)
+}
+
+/// The order of two rows for keeping a sorted list up to date, the same order sort gives.
+pub fn compare_rows<R: Row>(intent: &[SortField], data: &[R], a: usize, b: usize) -> Ordering {
+    compare_fields(intent, &sort_paths(intent), &data[a], &data[b]).then(a.cmp(&b))
+}
+
+fn sort_paths(intent: &[SortField]) -> Vec<FieldPath> {
+    intent.iter().map(|field| FieldPath::new(&field.name)).collect()
+}
+
+/// The order on the first sort field that differs.
+fn compare_fields(intent: &[SortField], paths: &[FieldPath], row1: &dyn Row, row2: &dyn Row) -> Ordering {
+    for (field, path) in intent.iter().zip(paths) {
+        let value1 = row1.value(path);
+        let value2 = row2.value(path);
+
+        let ordering = compare_field(field, &value1, &value2);
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// The order of the values of a sort field, typed fields compare the values read as their type.
+pub(crate) fn compare_field(field: &SortField, value1: &Value, value2: &Value) -> Ordering {
+    let descending = field.direction == Descending;
+    let nulls = field.nulls.unwrap_or_default();
+    let collation = field.collation.unwrap_or_default();
+
+    match field.data_type {
+        None => compare_sort_values(not_null(value1), not_null(value2), descending, nulls, |a, b| compare_values_with(a, b, &collation)),
+        Some(SortType::Duration) => {
+            let duration1 = not_null(value1).map(parse_duration);
+            let duration2 = not_null(value2).map(parse_duration);
+            compare_sort_values(duration1, duration2, descending, nulls, duration_ordering)
+        }
+        Some(SortType::Date) => {
+            let formats = field.formats.as_deref().unwrap_or_default();
+            compare_sort_values(parse_date_with(value1, formats), parse_date_with(value2, formats), descending, nulls, |a, b| a.cmp(b))
+        }
+        Some(SortType::Number) => {
+            compare_sort_values(parse_number(value1), parse_number(value2), descending, nulls, |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+        }
+        Some(SortType::Boolean) => {
+            compare_sort_values(parse_boolean(value1), parse_boolean(value2), descending, nulls, |a, b| a.cmp(b))
+        }
+        Some(SortType::Enum) => {
+            let order = field.order.as_deref().unwrap_or_default();
+            compare_sort_values(enum_rank(order, value1), enum_rank(order, value2), descending, nulls, |a, b| {
+                a.0.cmp(&b.0).then_with(|| compare_values_with(a.1, b.1, &collation))
+            })
+        }
+    }
+}
+
+fn parse_number(value: &Value) -> Option<f64> {
+    let number = match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse::<f64>().ok(),
+        _ => None
+    };
+
+    number.filter(|number| number.is_finite())
+}
+
+fn parse_boolean(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(value) => Some(*value),
+        Value::Number(number) => number.as_f64().map(|number| number != 0.0),
+        Value::String(text) => match text.trim().to_lowercase().as_str() {
+            "true" | "yes" | "1" => Some(true),
+            "false" | "no" | "0" => Some(false),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+/// The position of the value in the order, values that are not in the order are after all of them.
+fn enum_rank<'a>(order: &[Value], value: &'a Value) -> Option<(usize, &'a Value)> {
+    let value = not_null(value)?;
+    let rank = order.iter().position(|item| item == value).unwrap_or(order.len());
+    Some((rank, value))
+}
+
+fn not_null(value: &Value) -> Option<&Value> {
+    match value.is_null() {
+        true => None,
+        false => Some(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+    use serde_json::{json, Value};
+    use crate::processors::sort::{compare_objects, sort};
+    use crate::enums::SortDirection;
+    use crate::error::{DataResult, ErrorCode};
+    use crate::intent;
+    use crate::intent::{SortField, SortType};
+
+    fn parse(intent: &[Value]) -> DataResult<Vec<SortField>> {
+        intent::parse(&Value::from(intent.to_vec()), "sort")
+    }
+
+    fn get_data() -> Vec<Value> {
+        let mut data: Vec<Value> = Vec::new();
+        data.push(json!({"id": 0, "code": "A", "value": 10, "isActive": true}));
+        data.push(json!({"id": 1, "code": "B", "value": 10, "isActive": false}));
+        data.push(json!({"id": 2, "code": "C", "value": 20, "isActive": true}));
+        data.push(json!({"id": 3, "code": "D", "value": 20, "isActive": true}));
+        data.push(json!({"id": 4, "code": "E", "value": 5, "isActive": false}));
+        data
+    }
+
+    #[test]
+    fn test_simple_sort() {
+        let data = get_data();
+        let mut fields: Vec<Value> = Vec::new();
+        fields.push(json!({"name": "value"}));
+
+        let result = sort(&parse(&fields).unwrap(), &data, None);
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[0], 4);
+        assert_eq!(result[1], 0);
+        assert_eq!(result[2], 1);
+        assert_eq!(result[3], 2);
+        assert_eq!(result[4], 3);
+
+        let mut fields: Vec<Value> = Vec::new();
+        fields.push(json!({"name": "code", "direction": "asc"}));
+        let result = sort(&parse(&fields).unwrap(), &data, None);
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[0], 0);
+        assert_eq!(result[1], 1);
+        assert_eq!(result[2], 2);
+        assert_eq!(result[3], 3);
+        assert_eq!(result[4], 4);
+
+        let mut fields: Vec<Value> = Vec::new();
+        fields.push(json!({"name": "isActive"}));
+        let result = sort(&parse(&fields).unwrap(), &data, None);
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[0], 1);
+        assert_eq!(result[1], 4);
+        assert_eq!(result[2], 0);
+        assert_eq!(result[3], 2);
+        assert_eq!(result[4], 3);
+
+        let mut fields: Vec<Value> = Vec::new();
+        fields.push(json!({"name": "code", "direction": "dec"}));
+        let result = sort(&parse(&fields).unwrap(), &data, None);
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[0], 4);
+        assert_eq!(result[1], 3);
+        assert_eq!(result[2], 2);
+        assert_eq!(result[3], 1);
+        assert_eq!(result[4], 0);
+
+    }
+
+    #[test]
+    fn test_multi_sort() {
+        let data = get_data();
+        let mut fields: Vec<Value> = Vec::new();
+        fields.push(json!({"name": "value"}));
+        fields.push(json!({"name": "isActive"}));
+        fields.push(json!({"name": "code"}));
+        let result = sort(&parse(&fields).unwrap(), &data, None);
+
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[0], 4);
+        assert_eq!(result[1], 1);
+        assert_eq!(result[2], 0);
+        assert_eq!(result[3], 2);
+        assert_eq!(result[4], 3);
+    }
+
+    #[test]
+    fn test_multi_directional_sort() {
+        let data = get_data();
+        let mut fields: Vec<Value> = Vec::new();
+        fields.push(json!({"name": "value"}));
+        fields.push(json!({"name": "isActive", "direction": "dec"}));
+        fields.push(json!({"name": "code", "direction": "dec"}));
+
+        let result = sort(&parse(&fields).unwrap(), &data, None);
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[0], 4);
+        assert_eq!(result[1], 0);
+        assert_eq!(result[2], 1);
+        assert_eq!(result[3], 3);
+        assert_eq!(result[4], 2);
+    }
+
+    #[test]
+    fn test_duration_collection() {
+        let data = [
+            json!({"value": "P1Y"}),
+            json!({"value": "P1M"}),
+            json!({"value": "P1D"}),
+            json!({"value": "PT1H"}),
+            json!({"value": "PT1M"}),
+            json!({"value": "PT1S"}),
+            json!({"value": "PT0S"}),
+        ];
+
+        let fields = [json!({"name": "value", "type": "duration"})];
+
+        let result = sort(&parse(&fields).unwrap(), &data, None);
+        assert_eq!(result.len(), 7);
+        assert_eq!(result[0], 6);
+        assert_eq!(result[1], 5);
+        assert_eq!(result[2], 4);
+        assert_eq!(result[3], 3);
+        assert_eq!(result[4], 2);
+        assert_eq!(result[5], 1);
+        assert_eq!(result[6], 0);
+
+        let data = [
+            json!({"value": "P13DT21H21M45S"}),
+            json!({"value": "P0DT21H22M45.97096S"}),
+            json!({"value": "P13DT21H23M45S"}),
+            Value::Null
+        ];
+
+        let result = sort(&parse(&fields).unwrap(), &data, None);
+
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[0], 1);
+        assert_eq!(result[1], 0);
+        assert_eq!(result[2], 2);
+        assert_eq!(result[3], 3);
+
+        let fields = [json!({"name": "value", "type": "duration", "direction": "desc", "nulls": "first"})];
+        let result = sort(&parse(&fields).unwrap(), &data, None);
+        assert_eq!(result, vec![3, 2, 0, 1]);
+    }
+
+    #[test]
+    fn test_ties_keep_index_order() {
+        let data = get_data();
+        let fields = parse(&[json!({"name": "value"})]).unwrap();
+
+        assert_eq!(sort(&fields, &data, Some(vec![3, 1, 2, 0])), vec![0, 1, 2, 3]);
+        assert_eq!(sort(&fields, &data, Some(vec![2, 4, 3])), vec![4, 2, 3]);
+    }
+
+    #[test]
+    fn test_nulls() {
+        let data = [
+            json!({"value": 2}),
+            json!({"value": null}),
+            json!({"value": 1}),
+            json!({})
+        ];
+
+        let result = sort(&parse(&[json!({"name": "value"})]).unwrap(), &data, None);
+        assert_eq!(result, vec![2, 0, 1, 3]);
+
+        let result = sort(&parse(&[json!({"name": "value", "direction": "desc"})]).unwrap(), &data, None);
+        assert_eq!(result, vec![0, 2, 1, 3]);
+
+        let result = sort(&parse(&[json!({"name": "value", "nulls": "first"})]).unwrap(), &data, None);
+        assert_eq!(result, vec![1, 3, 2, 0]);
+
+        let result = sort(&parse(&[json!({"name": "value", "direction": "desc", "nulls": "first"})]).unwrap(), &data, None);
+        assert_eq!(result, vec![1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn test_mixed_types() {
+        let data = [
+            json!({"value": "b"}),
+            json!({"value": 10}),
+            json!({"value": true}),
+            json!({"value": 2.5}),
+            json!({"value": null}),
+            json!({"value": "a"})
+        ];
+
+        let result = sort(&parse(&[json!({"name": "value"})]).unwrap(), &data, None);
+        assert_eq!(result, vec![2, 3, 1, 5, 0, 4]);
+
+        let result = sort(&parse(&[json!({"name": "value", "direction": "desc"})]).unwrap(), &data, None);
+        assert_eq!(result, vec![0, 5, 1, 3, 2, 4]);
+    }
+
+    #[test]
+    fn test_date_type() {
+        let data = [
+            json!({"value": "03/12/2005 00:00"}),
+            json!({"value": "11/04/2005 16:35"}),
+            json!({"value": "not a date"}),
+            json!({"value": "2005/01/20"}),
+            json!({"value": 1104537600000_i64})
+        ];
+
+        let fields = [json!({"name": "value", "type": "date", "formats": ["%d/%m/%Y %H:%M"]})];
+        assert_eq!(sort(&parse(&fields).unwrap(), &data, None), vec![4, 3, 1, 0, 2]);
+
+        let fields = [json!({"name": "value", "type": "date", "formats": ["%d/%m/%Y %H:%M"], "direction": "desc", "nulls": "first"})];
+        assert_eq!(sort(&parse(&fields).unwrap(), &data, None), vec![2, 0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_number_type() {
+        let data = [
+            json!({"value": "100"}),
+            json!({"value": "9.5"}),
+            json!({"value": 20}),
+            json!({"value": " -3 "}),
+            json!({"value": "NaN"})
+        ];
+
+        let fields = [json!({"name": "value", "type": "number"})];
+        assert_eq!(sort(&parse(&fields).unwrap(), &data, None), vec![3, 1, 2, 0, 4]);
+    }
+
+    #[test]
+    fn test_boolean_type() {
+        let data = [
+            json!({"value": "yes"}),
+            json!({"value": false}),
+            json!({"value": "TRUE"}),
+            json!({"value": 0}),
+            json!({"value": "maybe"})
+        ];
+
+        let fields = [json!({"name": "value", "type": "boolean"})];
+        assert_eq!(sort(&parse(&fields).unwrap(), &data, None), vec![1, 3, 0, 2, 4]);
+    }
+
+    #[test]
+    fn test_enum_type() {
+        let data = [
+            json!({"priority": "Low", "code": "A"}),
+            json!({"priority": "High", "code": "B"}),
+            json!({"priority": "Urgent", "code": "C"}),
+            json!({"priority": "Medium", "code": "D"}),
+            json!({"priority": null, "code": "E"}),
+            json!({"priority": "High", "code": "F"}),
+            json!({"priority": "Blocked", "code": "G"})
+        ];
+
+        let fields = [
+            json!({"name": "priority", "type": "enum", "order": ["High", "Medium", "Low"]}),
+            json!({"name": "code", "direction": "desc"})
+        ];
+        assert_eq!(sort(&parse(&fields).unwrap(), &data, None), vec![5, 1, 3, 0, 6, 2, 4]);
+
+        let fields = [json!({"name": "priority", "type": "enum", "order": ["High", "Medium", "Low"], "direction": "desc"})];
+        assert_eq!(sort(&parse(&fields).unwrap(), &data, None), vec![2, 6, 0, 3, 1, 5, 4]);
+
+        let error = parse(&[json!({"name": "priority", "type": "enum"})]).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MissingProperty);
+        assert_eq!(error.path, "sort[0].order");
+    }
+
+    #[test]
+    fn test_typed_fields_keep_comparing() {
+        let data = [
+            json!({"date": "2022/01/01", "code": "B"}),
+            json!({"date": "2022-01-01", "code": "A"})
+        ];
+
+        let fields = [json!({"name": "date", "type": "date"}), json!({"name": "code"})];
+        assert_eq!(sort(&parse(&fields).unwrap(), &data, None), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_int_objects() {
+        let object1 = json!({"value": 1});
+        let object2 = json!({"value": 2});
+
+        let fields = vec![SortField::new("value", None, SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_float_objects() {
+        let object1 = json!({"value": 1.0});
+        let object2 = json!({"value": 1.1});
+
+        let fields = vec![SortField::new("value", None, SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_alpha_sort_objects() {
+        let object1 = json!({"value": "a"});
+        let object2 = json!({"value": "b"});
+
+        let fields = vec![SortField::new("value", None, SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_alpha2_sort_objects() {
+        let object1 = json!({"value": "*00123"});
+        let object2 = json!({"value": "*01123"});
+
+        let fields = vec![SortField::new("value", None, SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_duration_sort_objects() {
+        let object1 = json!({"value": "10:00"});
+        let object2 = json!({"value": "12:24"});
+
+        let fields = vec![SortField::new("value", None, SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_date_and_time_sort_objects() {
+        let object1 = json!({"value": "2005/04/11 16:35:50.243"});
+        let object2 = json!({"value": "2005/12/03 00:00:00.000"});
+
+        let fields = vec![SortField::new("value", None, SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_date_sort_objects() {
+        let object1 = json!({"value": "2022/06/20"});
+        let object2 = json!({"value": "2022/07/20"});
+
+        let fields = vec![SortField::new("value", None, SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_time_objects() {
+        let object1 = json!({"value": "08:00:00"});
+        let object2 = json!({"value": "08:00:01"});
+
+        let fields = vec![SortField::new("value", None, SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_bool_objects() {
+        let object1 = json!({"value": false});
+        let object2 = json!({"value": true});
+
+        let fields = vec![SortField::new("value", None, SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_duration_objects() {
+        let object1 = json!({"value": "PT010H30M"});
+        let object2 = json!({"value": "PT100H30M"});
+
+        let fields = vec![SortField::new("value", Some(SortType::Duration), SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_duration_miliseconds_objects() {
+        let object1 = json!({"value": "PT1.2S"});
+        let object2 = json!({"value": "PT1.3S"});
+
+        let fields = vec![SortField::new("value", Some(SortType::Duration), SortDirection::Ascending)];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Less);
+
+        assert_eq!(compare_objects(&fields, &object2, &object1), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_multi_field() {
+        // is object 2 before or after object 1
+        let object1 = json!({"value": "PT1.2S", "number": 2});
+        let object2 = json!({"value": "PT1.2S", "number": 1});
+
+        let fields = [
+            SortField::new("value", Some(SortType::Duration), SortDirection::Ascending),
+            SortField::new("number", None, SortDirection::Ascending)
+        ];
+
+        assert_eq!(compare_objects(&fields, &object1, &object2), Ordering::Greater);
+    }
+
+    #[test]
+    fn invalid_intent_test() {
+        let fields = [json!({"name": "code"}), json!({"direction": "asc"})];
+        let error = parse(&fields).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MissingProperty);
+        assert_eq!(error.path, "sort[1].name");
+
+        let fields = [json!({"name": "code", "direction": 1})];
+        let error = parse(&fields).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidType);
+        assert_eq!(error.path, "sort[0].direction");
+
+        let fields = [json!({"name": "code"}), json!({"name": "date", "type": "date", "formats": ["%Y", "%d/%m/%Y %Q"]})];
+        let error = parse(&fields).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "sort[1].formats[1]");
+    }
+}
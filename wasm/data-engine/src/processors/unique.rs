@@ -1,17 +1,20 @@
 use std::cmp::Ordering;
 use hashbrown::HashMap;
 use serde_json::{Value};
-use crate::duration::iso8601_placement;
-use crate::enums::Placement;
-use crate::evaluators::LessThan;
-use crate::iso8601_to_duration_str;
-use crate::traits::Eval;
+use crate::collation::Collation;
+use crate::compare::{compare_values, compare_values_with};
+use crate::duration::{duration_ordering, parse_duration};
+use crate::path::FieldPath;
+use crate::duration::iso8601_to_duration_str;
 use crate::utils::flood_indexes;
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::intent::{UniqueField, ValueType};
+use crate::row::Row;
 
-pub fn get_unique(fields: Vec<Value>, data: &Vec<Value>, rows: Option<Vec<usize>>) -> Value {
+pub fn get_unique<R: Row>(fields: &[UniqueField], data: &[R], rows: Option<Vec<usize>>) -> DataResult<Value> {
     let rows = match rows {
         Some(array) => array,
-        None => flood_indexes(&data)
+        None => flood_indexes(data)
     };
 
     let mut unique_sorted: UniqueSorted = UniqueSorted::new(fields, data, &rows);
@@ -21,24 +24,22 @@ pub fn get_unique(fields: Vec<Value>, data: &Vec<Value>, rows: Option<Vec<usize>
 /// This represents a field, the data type and the data
 struct FieldData {
     field: String,
-    data_type: String,
-    value_count: HashMap<String, i64>
+    field_path: FieldPath,
+    data_type: ValueType,
+    collation: Collation,
+    value_count: HashMap<String, i64>,
+    path: String
 }
 
 impl FieldData {
-    pub fn new(field_obj: Value) -> FieldData {
-        let mut field = field_obj["name"].as_str().unwrap().to_string();
-        field = format!("/{}", field.replace(".", "/"));
-
-        let data_type = match field_obj["type"].as_str() {
-            None => "string".to_string(),
-            Some(dt) => dt.to_string()
-        };
-
+    pub fn new(field: &UniqueField, index: usize) -> FieldData {
         FieldData {
-            field,
-            data_type,
-            value_count: Default::default()
+            field: field.name.clone(),
+            field_path: FieldPath::new(&field.name),
+            data_type: field.data_type,
+            collation: field.collation.unwrap_or_default(),
+            value_count: Default::default(),
+            path: format!("fields[{}]", index)
         }
     }
 
@@ -46,7 +47,7 @@ impl FieldData {
         let value_str: String = match value {
             Value::Null => "null".to_string(),
             Value::String(str_value) => {
-                if str_value.len() == 0 {
+                if str_value.is_empty() {
                     "null".to_string()
                 }
                 else {
@@ -61,14 +62,14 @@ impl FieldData {
             .or_insert(1);
     }
 
-    pub fn get_values(&mut self) -> Vec<Value> {
+    pub fn get_values(&mut self) -> DataResult<Vec<Value>> {
         let mut result: Vec<Value> = Vec::new();
 
         let mut null_obj: Option<Value> = None;
 
         for (value, count) in &self.value_count {
             let mut value_obj = Value::Object(Default::default());
-            value_obj["count"] = Value::from(count.clone());
+            value_obj["count"] = Value::from(*count);
 
             if value == "null" {
                 value_obj["value"] = Value::Null;
@@ -76,28 +77,27 @@ impl FieldData {
             }
             else {
                 let value_str = value.clone();
-                match self.data_type.as_ref() {
-                    "duration" => {
+                match self.data_type {
+                    ValueType::Duration => {
                         let mut result_obj = Value::Object(Default::default());
                         let value = Value::from(value_str);
                         result_obj["duration"] = Value::from(iso8601_to_duration_str(&value));
                         result_obj["iso"] = value;
                         value_obj["value"] = result_obj;
                     },
-                    "long" => {
-                        let i_value = value_str.parse::<i64>().unwrap();
+                    ValueType::Long => {
+                        let i_value = value_str.parse::<i64>().map_err(|_| self.invalid_value(value))?;
                         value_obj["value"] = Value::from(i_value);
                     }
-                    "number" => {
-                        let f_value = value_str.parse::<f64>().unwrap();
+                    ValueType::Number => {
+                        let f_value = value_str.parse::<f64>().map_err(|_| self.invalid_value(value))?;
                         value_obj["value"] = Value::from(f_value);
                     }
-                    "boolean" => {
-                        let b_value = value_str.parse::<bool>().unwrap();
+                    ValueType::Boolean => {
+                        let b_value = value_str.parse::<bool>().map_err(|_| self.invalid_value(value))?;
                         value_obj["value"] = Value::from(b_value);
                     }
-                    _ => {
-                        // defaults as string
+                    ValueType::String => {
                         value_obj["value"] = Value::from(value_str);
                     }
                 }
@@ -106,7 +106,7 @@ impl FieldData {
             }
         }
 
-        result.sort_by(|a, b| sort_eval(&self.data_type, a, b));
+        result.sort_by(|a, b| sort_eval(self.data_type, &self.collation, a, b));
 
         match null_obj {
             None => {}
@@ -115,25 +115,25 @@ impl FieldData {
             }
         }
 
-        return result;
+        Ok(result)
+    }
+
+    fn invalid_value(&self, value: &str) -> DataError {
+        let message = format!("'{}' is not a valid {}", value, self.data_type.as_str());
+        DataError::new(ErrorCode::InvalidValue, "type", message).at(&self.path)
     }
 }
 
-fn sort_eval(data_type: &String, obj1: &Value, obj2: &Value) -> Ordering {
+/// Values that are equal in the collation are ordered on their bytes so the result is always in the same order.
+fn sort_eval(data_type: ValueType, collation: &Collation, obj1: &Value, obj2: &Value) -> Ordering {
     let value1 = &obj1["value"];
     let value2 = &obj2["value"];
 
-    if data_type.as_str() == "duration" {
-        match iso8601_placement(&value1["iso"], &value2["iso"]) {
-            Placement::Before => Ordering::Less,
-            Placement::After => Ordering::Greater
-        }
+    if data_type == ValueType::Duration {
+        duration_ordering(&parse_duration(&value1["iso"]), &parse_duration(&value2["iso"]))
     }
     else {
-        match LessThan::evaluate(&value1, &value2) {
-            true => Ordering::Less,
-            false => Ordering::Greater
-        }
+        compare_values_with(value1, value2, collation).then_with(|| compare_values(value1, value2))
     }
 }
 
@@ -143,57 +143,51 @@ struct UniqueSorted {
 }
 
 impl UniqueSorted {
-    pub fn new(fields: Vec<Value>, data: &Vec<Value>, rows: &Vec<usize>) -> UniqueSorted {
+    pub fn new<R: Row>(fields: &[UniqueField], data: &[R], rows: &Vec<usize>) -> UniqueSorted {
         let mut result = UniqueSorted {
             fields: UniqueSorted::process_fields(fields)
         };
 
         result.process_data(data, rows);
-        return result;
+        result
     }
 
-    pub fn process_fields(fields_collection: Vec<Value>) -> HashMap<String, FieldData>{
+    pub fn process_fields(fields_collection: &[UniqueField]) -> HashMap<String, FieldData> {
         let mut fields: HashMap<String, FieldData> = HashMap::new();
 
-        for field in fields_collection {
-            let field = FieldData::new(field);
+        for (index, field) in fields_collection.iter().enumerate() {
+            let field = FieldData::new(field, index);
             fields.insert(field.field.clone(), field);
         }
 
         fields
     }
 
-    pub fn process_data(&mut self, data: &Vec<Value>, rows: &Vec<usize>) {
+    pub fn process_data<R: Row>(&mut self, data: &[R], rows: &Vec<usize>) {
         for row in rows {
             let record = &data[*row];
 
-            for (field, field_data) in &mut self.fields {
-                let value = record.pointer(field);
+            for field_data in self.fields.values_mut() {
+                let value = record.value(&field_data.field_path);
 
-                match value {
-                    None => {
-                        field_data.process_value(&Value::Null);
-                    }
-                    Some(value_obj) => {
-                        field_data.process_value(value_obj);
-                    }
+                // a projection such as "lines[*].code" counts the value of every item
+                match (field_data.field_path.is_projection(), value.as_ref()) {
+                    (true, Value::Array(items)) => items.iter().for_each(|item| field_data.process_value(item)),
+                    _ => field_data.process_value(&value)
                 }
             }
         }
     }
 
-    pub fn get_value(&mut self) -> Value {
+    pub fn get_value(&mut self) -> DataResult<Value> {
         let mut result: Value = Value::Object(Default::default());
 
         for (field, field_data) in &mut self.fields {
-            let mut field_name = field.replace("/", ".");
-            field_name.remove(0);
-
-            let values: Vec<Value> = field_data.get_values();
-            result[field_name] = Value::from(values);
+            let values: Vec<Value> = field_data.get_values()?;
+            result[field.as_str()] = Value::from(values);
         }
 
-        return result;
+        Ok(result)
     }
 }
 
@@ -201,7 +195,14 @@ impl UniqueSorted {
 mod test {
     use serde_json::{json, Value};
     use serde_json::Value::Null;
-    use crate::get_unique;
+    use crate::processors::get_unique;
+    use crate::error::{DataResult, ErrorCode};
+    use crate::intent;
+    use crate::intent::UniqueField;
+
+    fn parse(fields: &[Value]) -> DataResult<Vec<UniqueField>> {
+        intent::parse(&Value::from(fields.to_vec()), "fields")
+    }
 
     fn get_data() -> Vec<Value> {
         let mut result: Vec<Value> = Vec::new();
@@ -223,7 +224,7 @@ mod test {
         let mut fields: Vec<Value> = Vec::new();
         fields.push(json!({"name": "value", "type": "long"}));
 
-        let result = get_unique(fields, &data, Some(records));
+        let result = get_unique(&parse(&fields).unwrap(), &data, Some(records)).unwrap();
 
         assert_eq!(result.pointer("/value/0/value").unwrap(), &Value::from(10));
         assert_eq!(result.pointer("/value/0/count").unwrap(), &Value::from(2));
@@ -242,7 +243,7 @@ mod test {
         let mut fields: Vec<Value> = Vec::new();
         fields.push(json!({"name": "person.name", "type": "string"}));
 
-        let result = get_unique(fields, &data, None);
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
 
         assert_eq!(result.pointer("/person.name/0/value").unwrap(), &Value::from("Jane"));
         assert_eq!(result.pointer("/person.name/0/count").unwrap(), &Value::from(1));
@@ -252,13 +253,49 @@ mod test {
         assert_eq!(result.pointer("/person.name/2/count").unwrap(), &Value::from(2));
     }
 
+    #[test]
+    fn projection_test() {
+        let data = vec![
+            json!({"lines": [{"code": "A"}, {"code": "B"}]}),
+            json!({"lines": [{"code": "A"}]}),
+            json!({"lines": []})
+        ];
+
+        let fields = vec![json!({"name": "lines[*].code"}), json!({"name": "lines[0].code"})];
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
+
+        assert_eq!(result["lines[*].code"], json!([{"value": "A", "count": 2}, {"value": "B", "count": 1}]));
+        assert_eq!(result["lines[0].code"], json!([{"value": "A", "count": 2}, {"value": null, "count": 1}]));
+    }
+
+    #[test]
+    fn collation_test() {
+        let data = vec![
+            json!({"code": "item10"}),
+            json!({"code": "Item2"}),
+            json!({"code": "item2"}),
+            json!({"code": "Émile"}),
+            json!({"code": "item2"})
+        ];
+
+        let fields = [json!({"name": "code", "collation": { "case_insensitive": true, "natural": true, "ignore_accents": true }})];
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
+
+        assert_eq!(result["code"], json!([
+            {"value": "Émile", "count": 1},
+            {"value": "Item2", "count": 1},
+            {"value": "item2", "count": 2},
+            {"value": "item10", "count": 1}
+        ]));
+    }
+
     #[test]
     fn structure_test_code() {
         let data = get_data();
         let mut fields: Vec<Value> = Vec::new();
         fields.push(json!({"name": "code", "type": "string"}));
 
-        let result = get_unique(fields, &data, None);
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
 
         assert_eq!(result.pointer("/code/0/value").unwrap(), &Value::from("A"));
         assert_eq!(result.pointer("/code/0/count").unwrap(), &Value::from(1));
@@ -278,7 +315,7 @@ mod test {
         let mut fields: Vec<Value> = Vec::new();
         fields.push(json!({"name": "value", "type": "long"}));
 
-        let result = get_unique(fields, &data, None);
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
 
         assert_eq!(result.pointer("/value/0/value").unwrap(), &Value::from(5));
         assert_eq!(result.pointer("/value/0/count").unwrap(), &Value::from(1));
@@ -294,7 +331,7 @@ mod test {
         let mut fields: Vec<Value> = Vec::new();
         fields.push(json!({"name": "isActive", "type": "boolean"}));
 
-        let result = get_unique(fields, &data, None);
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
 
         assert_eq!(result.pointer("/isActive/0/value").unwrap(), &Value::from(false));
         assert_eq!(result.pointer("/isActive/0/count").unwrap(), &Value::from(2));
@@ -313,7 +350,7 @@ mod test {
         let mut fields: Vec<Value> = Vec::new();
         fields.push(json!({"name": "duration", "type": "duration"}));
 
-        let result = get_unique(fields, &data, None);
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
 
         assert_eq!(result.pointer("/duration/0/value/iso").unwrap(), &Value::from("P0DT1H2M30S"));
         assert_eq!(result.pointer("/duration/0/value/duration").unwrap(), &Value::from("0:1:2:30"));
@@ -334,7 +371,7 @@ mod test {
         let mut fields: Vec<Value> = Vec::new();
         fields.push(json!({"name": "duration", "type": "duration"}));
 
-        let result = get_unique(fields, &data, None);
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
 
         assert_eq!(result.pointer("/duration/0/value").unwrap(), &Value::Null);
         assert_eq!(result.pointer("/duration/0/count").unwrap(), &Value::from(1));
@@ -350,7 +387,7 @@ mod test {
         let mut fields: Vec<Value> = Vec::new();
         fields.push(json!({"name": "value", "type": "number"}));
 
-        let result = get_unique(fields, &data, None);
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
 
         assert_eq!(result.pointer("/value/0/value").unwrap(), &Value::from(10.));
         assert_eq!(result.pointer("/value/0/count").unwrap(), &Value::from(1));
@@ -370,7 +407,7 @@ mod test {
         let mut fields: Vec<Value> = Vec::new();
         fields.push(json!({"name": "value", "type": "string"}));
 
-        let result = get_unique(fields, &data, None);
+        let result = get_unique(&parse(&fields).unwrap(), &data, None).unwrap();
 
         assert_eq!(result.pointer("/value/0/value").unwrap(), &Value::from("test"));
         assert_eq!(result.pointer("/value/0/count").unwrap(), &Value::from(1));
@@ -378,4 +415,18 @@ mod test {
         assert_eq!(result.pointer("/value/1/count").unwrap(), &Value::from(2));
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn invalid_intent_test() {
+        let data = get_data();
+
+        let fields = vec![json!({"name": "code"}), json!({"type": "string"})];
+        let error = parse(&fields).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MissingProperty);
+        assert_eq!(error.path, "fields[1].name");
+
+        let fields = vec![json!({"name": "code", "type": "long"})];
+        let error = get_unique(&parse(&fields).unwrap(), &data, None).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidValue);
+        assert_eq!(error.path, "fields[0].type");
+    }
+}
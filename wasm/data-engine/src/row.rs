@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+use serde_json::Value;
+use crate::path::FieldPath;
+
+/// Read access to a record for the processors.
+/// The json adapter lends out its values, adapters on other records convert the field when it is read.
+pub trait Row {
+    /// The value on the row or None when the row does not have the field.
+    fn field(&self, path: &FieldPath) -> Option<Cow<'_, Value>>;
+
+    /// The value on the row, a missing field reads as null.
+    fn value(&self, path: &FieldPath) -> Cow<'_, Value> {
+        self.field(path).unwrap_or(Cow::Owned(Value::Null))
+    }
+}
+
+impl Row for Value {
+    fn field(&self, path: &FieldPath) -> Option<Cow<'_, Value>> {
+        path.find(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+    use serde_json::{json, Value};
+    use crate::path::FieldPath;
+    use crate::intent;
+    use crate::intent::{Aggregates, FilterExpr, GroupSpec, SortField, UniqueField};
+    use crate::processors;
+    use crate::row::Row;
+
+    /// A row that converts its field when it is read, the way the javascript adapter does.
+    struct TextRow(String);
+
+    impl Row for TextRow {
+        fn field(&self, path: &FieldPath) -> Option<Cow<'_, Value>> {
+            let row: Value = serde_json::from_str(&self.0).unwrap();
+            path.find(&row).map(|value| Cow::Owned(value.into_owned()))
+        }
+    }
+
+    fn get_data() -> Vec<Value> {
+        vec![
+            json!({"id": 0, "code": "A", "value": 10, "person": {"name": "John"}}),
+            json!({"id": 1, "code": "B", "value": 30, "person": {"name": "Jane"}}),
+            json!({"id": 2, "code": "C", "value": 20, "person": {"name": "John"}}),
+            json!({"id": 3, "code": "D", "value": 5})
+        ]
+    }
+
+    #[test]
+    fn json_row_test() {
+        let row = json!({"code": "A", "person": {"name": "John"}});
+
+        assert_eq!(row.field(&FieldPath::new("code")), Some(Cow::Borrowed(&Value::from("A"))));
+        assert_eq!(row.field(&FieldPath::new("person.name")).unwrap().as_ref(), "John");
+        assert_eq!(row.field(&FieldPath::new("value")), None);
+        assert_eq!(row.value(&FieldPath::new("person.age")).as_ref(), &Value::Null);
+    }
+
+    #[test]
+    fn processors_test() {
+        let data = get_data();
+        let text_rows: Vec<TextRow> = data.iter().map(|row| TextRow(row.to_string())).collect();
+
+        let filter: Vec<FilterExpr> = intent::parse(&json!([{ "field": "person.name", "operator": "==", "value": "John" }]), "filter").unwrap();
+        assert_eq!(processors::filter(&filter, &text_rows, true), processors::filter(&filter, &data, true));
+        assert_eq!(processors::filter(&filter, &text_rows, true), vec![0, 2]);
+
+        let sort: Vec<SortField> = intent::parse(&json!([{ "name": "value", "direction": "desc" }]), "sort").unwrap();
+        assert_eq!(processors::sort(&sort, &text_rows, None), vec![1, 2, 0, 3]);
+
+        let group: Vec<GroupSpec> = intent::parse(&json!(["person.name"]), "group").unwrap();
+        assert_eq!(processors::group(&group, &text_rows, None, None).unwrap(), processors::group(&group, &data, None, None).unwrap());
+
+        let aggregates: Aggregates = intent::parse(&json!({ "sum": "value", "max": "value" }), "aggregates").unwrap();
+        assert_eq!(processors::aggregate_rows(&aggregates.0, &text_rows, None).unwrap(), processors::aggregate_rows(&aggregates.0, &data, None).unwrap());
+
+        let unique: Vec<UniqueField> = intent::parse(&json!([{ "name": "person.name" }]), "fields").unwrap();
+        assert_eq!(processors::get_unique(&unique, &text_rows, None).unwrap(), processors::get_unique(&unique, &data, None).unwrap());
+    }
+}
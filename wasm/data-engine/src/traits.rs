@@ -0,0 +1,33 @@
+use std::any::Any;
+use chrono::NaiveDateTime;
+use iso8601_duration::Duration;
+use serde_json::Value;
+use crate::error::DataResult;
+
+pub trait Eval {
+    fn evaluate(obj1: &Value, obj2: &Value) -> bool;
+}
+
+pub trait Aggregate: Any {
+    fn add_value(&mut self, obj: &Value) -> DataResult<()>;
+    fn value(&self) -> Value;
+
+    /// Add the values another aggregate of the same kind has seen, aggregates of another kind are ignored.
+    /// The aggregate of a group is the merge of the groups under it without adding the rows again.
+    fn merge(&mut self, other: &dyn Aggregate);
+
+    /// Duration columns parse their values once, duration aggregates can use that instead of the string.
+    fn add_duration(&mut self, obj: &Value, _duration: &Duration) -> DataResult<()> {
+        self.add_value(obj)
+    }
+
+    /// Date columns parse their values once, date aggregates can use that instead of the string.
+    fn add_date(&mut self, obj: &Value, _date: &NaiveDateTime) -> DataResult<()> {
+        self.add_value(obj)
+    }
+}
+
+/// The aggregate as its own type when it is of that type.
+pub fn same_kind<T: Aggregate>(aggregate: &dyn Aggregate) -> Option<&T> {
+    (aggregate as &dyn Any).downcast_ref::<T>()
+}
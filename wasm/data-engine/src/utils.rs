@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+use crate::error::{DataError, DataResult, ErrorCode};
+use crate::intent::Page;
+
+pub fn flood_indexes<T>(data: &[T]) -> Vec<usize> {
+    let mut result: Vec<usize> = Vec::new();
+
+    let len = data.len();
+    for value in 0..len {
+        result.push(value);
+    }
+
+    result
+}
+
+/// Make sure the row indexes passed in by the caller exist in the data.
+pub fn check_rows(rows: &[usize], length: usize) -> DataResult<()> {
+    for (index, row) in rows.iter().enumerate() {
+        if *row >= length {
+            let message = format!("row {} is out of range for {} records", row, length);
+            return Err(DataError::new(ErrorCode::RowOutOfRange, &format!("rows[{}]", index), message));
+        }
+    }
+
+    Ok(())
+}
+
+/// Sort the rows and give back the rows of the page.
+/// A partial select first drops the rows after the end of the page, so only the rows up to the end get sorted.
+/// The compare must be a total order for the page to be the same as slicing the full sort.
+pub fn select_page(mut rows: Vec<usize>, page: &Page, compare: impl Fn(&usize, &usize) -> Ordering) -> Vec<usize> {
+    let end = page.end(rows.len());
+
+    if end == 0 {
+        return Vec::new();
+    }
+
+    if end < rows.len() {
+        rows.select_nth_unstable_by(end - 1, &compare);
+        rows.truncate(end);
+    }
+
+    rows.sort_unstable_by(compare);
+    page.apply(rows)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::utils::{check_rows, flood_indexes, select_page};
+    use crate::error::ErrorCode;
+    use crate::intent::Page;
+
+    #[test]
+    fn flood_indexes_test() {
+        let mut data: Vec<Value> = Vec::new();
+        data.push(json!({"id": 0, "code": "A", "value": 10, "isActive": true}));
+        data.push(json!({"id": 1, "code": "B", "value": 10, "isActive": false}));
+        data.push(json!({"id": 2, "code": "C", "value": 20, "isActive": true}));
+        data.push(json!({"id": 3, "code": "D", "value": 20, "isActive": true}));
+        data.push(json!({"id": 4, "code": "E", "value": 5, "isActive": false}));
+
+        let result = flood_indexes(&data);
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[0], 0);
+        assert_eq!(result[1], 1);
+        assert_eq!(result[2], 2);
+        assert_eq!(result[3], 3);
+        assert_eq!(result[4], 4);
+    }
+
+    #[test]
+    fn check_rows_test() {
+        assert_eq!(check_rows(&[0, 4], 5), Ok(()));
+
+        let error = check_rows(&[0, 5], 5).unwrap_err();
+        assert_eq!(error.code, ErrorCode::RowOutOfRange);
+        assert_eq!(error.path, "rows[1]");
+    }
+
+    #[test]
+    fn select_page_test() {
+        let rows = vec![5, 3, 9, 0, 7, 1, 8, 2, 6, 4];
+        let compare = |a: &usize, b: &usize| b.cmp(a);
+
+        assert_eq!(select_page(rows.clone(), &Page::default(), compare), vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(select_page(rows.clone(), &Page::new(0, Some(3)), compare), vec![9, 8, 7]);
+        assert_eq!(select_page(rows.clone(), &Page::new(3, Some(3)), compare), vec![6, 5, 4]);
+        assert_eq!(select_page(rows.clone(), &Page::new(8, Some(5)), compare), vec![1, 0]);
+        assert_eq!(select_page(rows.clone(), &Page::new(12, Some(5)), compare), Vec::<usize>::new());
+        assert_eq!(select_page(rows, &Page::new(0, Some(0)), compare), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,898 @@
+use serde_json::Value;
+use crate::error::{DataError, ErrorCode};
+use crate::expression::Expression;
+use crate::intent;
+use crate::intent::{AggregateKind, Bucket, Comparison, FilterType, Operator, Page, FIELD_REFERENCE};
+
+pub const FILTER_TYPES: [&str; 3] = ["date", "duration", "length"];
+pub const FILTER_QUANTIFIERS: [&str; 3] = ["any", "all", "none"];
+pub const SORT_TYPES: [&str; 5] = ["duration", "date", "number", "boolean", "enum"];
+pub const SORT_DIRECTIONS: [&str; 3] = ["asc", "dec", "desc"];
+pub const GROUP_ORDERS: [&str; 2] = ["key", "count"];
+pub const BUCKET_KINDS: [&str; 4] = ["date", "width", "edges", "durations"];
+pub const SORT_NULLS: [&str; 2] = ["first", "last"];
+pub const COLLATION_OPTIONS: [&str; 3] = ["case_insensitive", "natural", "ignore_accents"];
+
+const PERSPECTIVE_PROPERTIES: [&str; 7] = ["computed", "filter", "sort", "group", "aggregates", "case_sensitive", "page"];
+const PAGE_PROPERTIES: [&str; 2] = ["offset", "limit"];
+const PIVOT_PROPERTIES: [&str; 3] = ["rows", "columns", "values"];
+
+/// Check an intent of the given kind against the intent model.
+/// All the problems are collected so that a stored intent can be fixed in one go.
+/// An unknown kind is an error in itself as there is nothing to check against.
+pub fn validate_intent(kind: &str, intent: &Value) -> Result<Vec<DataError>, DataError> {
+    let mut problems: Vec<DataError> = Vec::new();
+
+    match kind {
+        "filter"        => validate_filter(intent, "filter", &mut problems),
+        "sort"          => validate_sort(intent, "sort", &mut problems),
+        "group"         => validate_group(intent, "group", &mut problems),
+        "aggregate"     |
+        "aggregates"    => validate_aggregates(intent, "aggregates", &mut problems),
+        "perspective"   => validate_perspective(intent, &mut problems),
+        "pivot"         => validate_pivot(intent, &mut problems),
+        _ => {
+            let message = format!("unknown intent kind '{}'", kind);
+            return Err(DataError::new(ErrorCode::InvalidValue, "kind", message));
+        }
+    }
+
+    Ok(problems)
+}
+
+fn validate_perspective(intent: &Value, problems: &mut Vec<DataError>) {
+    let perspective = match intent.as_object() {
+        None => return problems.push(DataError::invalid_type("", "an object")),
+        Some(perspective) => perspective
+    };
+
+    for (key, value) in perspective.iter() {
+        match key.as_str() {
+            "computed"          => validate_computed(value, "computed", problems),
+            "filter"            => validate_filter(value, "filter", problems),
+            "sort"              => validate_sort(value, "sort", problems),
+            "group"             => validate_group(value, "group", problems),
+            "aggregates"        => validate_aggregates(value, "aggregates", problems),
+            "page"              => validate_page(value, "page", problems),
+            "case_sensitive"    => {
+                if !value.is_boolean() {
+                    problems.push(DataError::invalid_type("case_sensitive", "a boolean"));
+                }
+            }
+            _ => {
+                let message = format!("unknown property '{}', expected one of {}", key, PERSPECTIVE_PROPERTIES.join(", "));
+                problems.push(DataError::new(ErrorCode::InvalidValue, key, message));
+            }
+        }
+    }
+
+    if perspective.contains_key("page") {
+        if let Err(problem) = Page::check_use(perspective.contains_key("group"), perspective.contains_key("aggregates")) {
+            problems.push(problem);
+        }
+    }
+}
+
+/// The rows and columns are group fields and can be left out, there must be at least one value.
+fn validate_pivot(intent: &Value, problems: &mut Vec<DataError>) {
+    let pivot = match intent.as_object() {
+        None => return problems.push(DataError::invalid_type("", "an object")),
+        Some(pivot) => pivot
+    };
+
+    for (key, value) in pivot.iter() {
+        match key.as_str() {
+            "rows" | "columns" => validate_group(value, key, problems),
+            "values" => validate_values(value, "values", problems),
+            _ => {
+                let message = format!("unknown property '{}', expected one of {}", key, PIVOT_PROPERTIES.join(", "));
+                problems.push(DataError::new(ErrorCode::InvalidValue, key, message));
+            }
+        }
+    }
+
+    if !pivot.contains_key("values") {
+        problems.push(DataError::missing_property("values", "values"));
+    }
+}
+
+/// The values of a pivot are a list of aggregate objects, { "agg": "sum", "field": "value" }.
+fn validate_values(intent: &Value, path: &str, problems: &mut Vec<DataError>) {
+    let values = match intent.as_array() {
+        None => return problems.push(DataError::invalid_type(path, "an array")),
+        Some(values) => values
+    };
+
+    if values.is_empty() {
+        problems.push(DataError::new(ErrorCode::InvalidValue, path, "at least one value is required".to_string()));
+    }
+
+    for (index, value) in values.iter().enumerate() {
+        let value_path = format!("{}[{}]", path, index);
+
+        if !value.is_object() {
+            problems.push(DataError::invalid_type(&value_path, "an object"));
+            continue;
+        }
+
+        match value.get("agg").map(|agg| agg.as_str()) {
+            None => problems.push(DataError::missing_property("agg", "agg").at(&value_path)),
+            Some(None) => problems.push(DataError::invalid_type("agg", "a string").at(&value_path)),
+            Some(Some(agg)) if AggregateKind::parse(agg).is_none() => {
+                let message = format!("unknown aggregate '{}'", agg);
+                problems.push(DataError::new(ErrorCode::UnknownAggregate, "agg", message).at(&value_path));
+            }
+            _ => {}
+        }
+
+        match value.get("field") {
+            None => problems.push(DataError::missing_property("field", "field").at(&value_path)),
+            Some(field) if !field.is_string() => problems.push(DataError::invalid_type("field", "a field name").at(&value_path)),
+            _ => {}
+        }
+    }
+}
+
+/// Every computed field needs a unique name and an expression that parses.
+fn validate_computed(intent: &Value, path: &str, problems: &mut Vec<DataError>) {
+    let fields = match intent.as_array() {
+        None => return problems.push(DataError::invalid_type(path, "an array")),
+        Some(fields) => fields
+    };
+
+    let mut names: Vec<&str> = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let field_path = format!("{}[{}]", path, index);
+
+        if !field.is_object() {
+            problems.push(DataError::invalid_type(&field_path, "an object"));
+            continue;
+        }
+
+        match field.get("name").map(|name| name.as_str()) {
+            None => problems.push(DataError::missing_property("name", "name").at(&field_path)),
+            Some(None) | Some(Some("")) => problems.push(DataError::invalid_type("name", "a field name").at(&field_path)),
+            Some(Some(name)) => {
+                if names.contains(&name) {
+                    let message = format!("the computed field '{}' is defined more than once", name);
+                    problems.push(DataError::new(ErrorCode::InvalidValue, "name", message).at(&field_path));
+                }
+
+                names.push(name);
+            }
+        }
+
+        match field.get("expression").map(|expression| expression.as_str()) {
+            None => problems.push(DataError::missing_property("expression", "expression").at(&field_path)),
+            Some(None) => problems.push(DataError::invalid_type("expression", "an expression").at(&field_path)),
+            Some(Some(expression)) => {
+                if let Err(error) = Expression::parse(expression) {
+                    problems.push(error.at("expression").at(&field_path));
+                }
+            }
+        }
+    }
+}
+
+/// The offset and limit of a page are counts of rows.
+fn validate_page(intent: &Value, path: &str, problems: &mut Vec<DataError>) {
+    let page = match intent.as_object() {
+        None => return problems.push(DataError::invalid_type(path, "an object")),
+        Some(page) => page
+    };
+
+    for (key, value) in page.iter() {
+        if !PAGE_PROPERTIES.contains(&key.as_str()) {
+            let message = format!("unknown property '{}', expected one of {}", key, PAGE_PROPERTIES.join(", "));
+            problems.push(DataError::new(ErrorCode::InvalidValue, key, message).at(path));
+        }
+        else if value.is_i64() && !value.is_u64() {
+            problems.push(DataError::new(ErrorCode::InvalidValue, key, "expected a positive integer".to_string()).at(path));
+        }
+        else if !value.is_u64() {
+            problems.push(DataError::invalid_type(key, "a positive integer").at(path));
+        }
+    }
+}
+
+fn validate_filter(intent: &Value, path: &str, problems: &mut Vec<DataError>) {
+    let expressions = match intent.as_array() {
+        None => return problems.push(DataError::invalid_type(path, "an array")),
+        Some(expressions) => expressions
+    };
+
+    for (index, expression) in expressions.iter().enumerate() {
+        validate_expression(expression, &format!("{}[{}]", path, index), problems);
+    }
+}
+
+fn validate_expression(intent: &Value, path: &str, problems: &mut Vec<DataError>) {
+    if !intent.is_object() {
+        return problems.push(DataError::invalid_type(path, "an object"));
+    }
+
+    let operator = match intent.get("operator") {
+        None => return problems.push(DataError::missing_property("operator", "operator").at(path)),
+        Some(operator) => match operator.as_str() {
+            None => return problems.push(DataError::invalid_type("operator", "a string").at(path)),
+            Some(operator) => operator
+        }
+    };
+
+    let operator = match Operator::parse(operator) {
+        None => {
+            let message = format!("unknown operator '{}'", operator);
+            problems.push(DataError::new(ErrorCode::UnknownOperator, "operator", message).at(path));
+
+            // the value can only be checked once we know what the operator expects
+            return validate_field(intent, path, problems);
+        }
+        Some(operator) => operator
+    };
+
+    if operator.is_logical() || operator.is_quantifier() {
+        if operator.is_quantifier() {
+            validate_field(intent, path, problems);
+        }
+
+        match intent.get("expressions") {
+            None => problems.push(DataError::missing_property("expressions", "expressions").at(path)),
+            Some(expressions) => validate_filter(expressions, &format!("{}.expressions", path), problems)
+        }
+
+        return;
+    }
+
+    validate_field(intent, path, problems);
+
+    if let Some(problem) = validate_option(intent.get("type"), "type", &FILTER_TYPES) {
+        problems.push(problem.at(path));
+    }
+
+    if let Some(problem) = validate_option(intent.get("quantifier"), "quantifier", &FILTER_QUANTIFIERS) {
+        problems.push(problem.at(path));
+    }
+
+    let data_type = match intent.get("type").and_then(Value::as_str) {
+        Some("date") => Some(FilterType::Date),
+        Some("duration") => Some(FilterType::Duration),
+        Some("length") => Some(FilterType::Length),
+        _ => None
+    };
+
+    let flags = match intent.get("flags") {
+        None => None,
+        Some(flags) => match flags.as_str() {
+            None => return problems.push(DataError::invalid_type("flags", "a string").at(path)),
+            Some(flags) => Some(flags.to_string())
+        }
+    };
+
+    let reference = intent.get("value_field").is_some() || intent.get("value").is_some_and(|value| value.get(FIELD_REFERENCE).is_some());
+
+    if reference {
+        if let Some(problem) = validate_reference(intent, operator, data_type) {
+            problems.push(problem.at(path));
+        }
+
+        return;
+    }
+
+    if let Some(problem) = validate_value(operator, data_type, flags, intent.get("value")) {
+        problems.push(problem.at(path));
+    }
+}
+
+/// The value is checked the same way as the intent model does, the shape depends on the operator
+/// and dates, durations and regex patterns must parse.
+fn validate_value(operator: Operator, data_type: Option<FilterType>, flags: Option<String>, value: Option<&Value>) -> Option<DataError> {
+    if value.is_none() && operator.needs_value() {
+        return Some(DataError::missing_property("value", "value"));
+    }
+
+    let mut comparison = Comparison::new("", operator, value.cloned().unwrap_or(Value::Null));
+    comparison.data_type = data_type;
+    comparison.flags = flags;
+    comparison.check().err()
+}
+
+/// The value is read from another field on the row, the field name and the operator can be checked.
+fn validate_reference(intent: &Value, operator: Operator, data_type: Option<FilterType>) -> Option<DataError> {
+    match (intent.get("value_field"), intent.get("value")) {
+        (Some(_), Some(value)) if !value.is_null() => {
+            Some(DataError::new(ErrorCode::InvalidValue, "value_field", "use either value or value_field, not both".to_string()))
+        }
+        (Some(field), _) if !field.is_string() => Some(DataError::invalid_type("value_field", "a string")),
+        (None, Some(value)) if !value[FIELD_REFERENCE].is_string() => Some(DataError::invalid_type("value.$field", "a field name")),
+        _ => {
+            let mut comparison = Comparison::with_field("", operator, "");
+            comparison.data_type = data_type;
+            comparison.check().err()
+        }
+    }
+}
+
+fn validate_field(intent: &Value, path: &str, problems: &mut Vec<DataError>) {
+    match intent.get("field") {
+        None => problems.push(DataError::missing_property("field", "field").at(path)),
+        Some(field) => {
+            if !field.is_string() {
+                problems.push(DataError::invalid_type("field", "a string").at(path));
+            }
+        }
+    }
+}
+
+fn validate_sort(intent: &Value, path: &str, problems: &mut Vec<DataError>) {
+    let fields = match intent.as_array() {
+        None => return problems.push(DataError::invalid_type(path, "an array")),
+        Some(fields) => fields
+    };
+
+    for (index, field) in fields.iter().enumerate() {
+        let field_path = format!("{}[{}]", path, index);
+
+        if !field.is_object() {
+            problems.push(DataError::invalid_type(&field_path, "an object"));
+            continue;
+        }
+
+        match field.get("name") {
+            None => problems.push(DataError::missing_property("name", "name").at(&field_path)),
+            Some(name) => {
+                if !name.is_string() {
+                    problems.push(DataError::invalid_type("name", "a string").at(&field_path));
+                }
+            }
+        }
+
+        validate_sort_options(field, &field_path, problems);
+    }
+}
+
+/// The options on how values compare, shared by the sort fields and the group objects.
+fn validate_sort_options(field: &Value, field_path: &str, problems: &mut Vec<DataError>) {
+    if let Some(problem) = validate_option(field.get("type"), "type", &SORT_TYPES) {
+        problems.push(problem.at(field_path));
+    }
+
+    match field.get("order") {
+        None if field.get("type").and_then(Value::as_str) == Some("enum") => {
+            problems.push(DataError::missing_property("order", "order").at(field_path));
+        }
+        Some(order) if !order.is_array() => problems.push(DataError::invalid_type("order", "an array").at(field_path)),
+        _ => {}
+    }
+
+    if let Some(problem) = validate_option(field.get("direction"), "direction", &SORT_DIRECTIONS) {
+        problems.push(problem.at(field_path));
+    }
+
+    if let Some(problem) = validate_option(field.get("nulls"), "nulls", &SORT_NULLS) {
+        problems.push(problem.at(field_path));
+    }
+
+    if let Some(collation) = field.get("collation") {
+        validate_collation(collation, field_path, problems);
+    }
+}
+
+/// A collation is an object of boolean options.
+fn validate_collation(collation: &Value, field_path: &str, problems: &mut Vec<DataError>) {
+    let path = format!("{}.collation", field_path);
+
+    let options = match collation.as_object() {
+        None => return problems.push(DataError::invalid_type(&path, "an object")),
+        Some(options) => options
+    };
+
+    for (key, value) in options.iter() {
+        if !COLLATION_OPTIONS.contains(&key.as_str()) {
+            let message = format!("unknown collation option '{}', expected one of {}", key, COLLATION_OPTIONS.join(", "));
+            problems.push(DataError::new(ErrorCode::InvalidValue, key, message).at(&path));
+        }
+        else if !value.is_boolean() {
+            problems.push(DataError::invalid_type(key, "a boolean").at(&path));
+        }
+    }
+}
+
+/// Optional properties that, when set, must be one of a known set of strings.
+fn validate_option(value: Option<&Value>, property: &str, options: &[&str]) -> Option<DataError> {
+    let value = value?;
+
+    match value.as_str() {
+        None => Some(DataError::invalid_type(property, "a string")),
+        Some(value) if !options.contains(&value) => {
+            let message = format!("unknown {} '{}', expected one of {}", property, value, options.join(", "));
+            Some(DataError::new(ErrorCode::InvalidValue, property, message))
+        }
+        _ => None
+    }
+}
+
+fn validate_group(intent: &Value, path: &str, problems: &mut Vec<DataError>) {
+    let fields = match intent.as_array() {
+        None => return problems.push(DataError::invalid_type(path, "an array")),
+        Some(fields) => fields
+    };
+
+    for (index, field) in fields.iter().enumerate() {
+        let field_path = format!("{}[{}]", path, index);
+
+        match field {
+            Value::String(_) => {}
+            Value::Object(_) => validate_group_object(field, &field_path, problems),
+            _ => problems.push(DataError::invalid_type(&field_path, "a field name"))
+        }
+    }
+}
+
+/// A group object has the field, what the groups are ordered on and the sort options.
+fn validate_group_object(field: &Value, field_path: &str, problems: &mut Vec<DataError>) {
+    match field.get("field") {
+        None => problems.push(DataError::missing_property("field", "field").at(field_path)),
+        Some(name) if !name.is_string() => problems.push(DataError::invalid_type("field", "a string").at(field_path)),
+        _ => {}
+    }
+
+    match field.get("order_by") {
+        None => {}
+        Some(Value::String(order)) if GROUP_ORDERS.contains(&order.as_str()) => {}
+        Some(Value::String(order)) => {
+            let message = format!("unknown group order '{}', expected {} or an aggregate", order, GROUP_ORDERS.join(", "));
+            problems.push(DataError::new(ErrorCode::InvalidValue, "order_by", message).at(field_path));
+        }
+        Some(Value::Object(order)) if order.len() == 1 => {
+            validate_aggregates(&field["order_by"], &format!("{}.order_by", field_path), problems);
+        }
+        Some(Value::Object(_)) => {
+            problems.push(DataError::new(ErrorCode::InvalidValue, "order_by", "the group order is one aggregate".to_string()).at(field_path));
+        }
+        Some(_) => problems.push(DataError::invalid_type("order_by", "key, count or an object with one aggregate").at(field_path))
+    }
+
+    if field.get("case_insensitive").is_some_and(|value| !value.is_boolean()) {
+        problems.push(DataError::invalid_type("case_insensitive", "a boolean").at(field_path));
+    }
+
+    if field.get("null_label").is_some_and(|value| !value.is_string()) {
+        problems.push(DataError::invalid_type("null_label", "a string").at(field_path));
+    }
+
+    if let Some(bucket) = field.get("bucket") {
+        validate_bucket(bucket, &format!("{}.bucket", field_path), problems);
+    }
+
+    validate_sort_options(field, field_path, problems);
+}
+
+/// A bucket has one kind and its labels, the known properties are checked by the intent model.
+fn validate_bucket(bucket: &Value, path: &str, problems: &mut Vec<DataError>) {
+    let options = match bucket.as_object() {
+        None => return problems.push(DataError::invalid_type(path, "an object")),
+        Some(options) => options
+    };
+
+    let mut known = options.clone();
+
+    for key in options.keys() {
+        if !BUCKET_KINDS.contains(&key.as_str()) && key != "labels" {
+            let message = format!("unknown property '{}', expected one of {} or labels", key, BUCKET_KINDS.join(", "));
+            problems.push(DataError::new(ErrorCode::InvalidValue, key, message).at(path));
+            known.remove(key);
+        }
+    }
+
+    if let Err(problem) = intent::parse::<Bucket>(&Value::Object(known), path) {
+        problems.push(problem);
+    }
+}
+
+fn validate_aggregates(intent: &Value, path: &str, problems: &mut Vec<DataError>) {
+    let aggregates = match intent.as_object() {
+        None => return problems.push(DataError::invalid_type(path, "an object")),
+        Some(aggregates) => aggregates
+    };
+
+    for (name, field) in aggregates.iter() {
+        if AggregateKind::parse(name).is_none() {
+            let message = format!("unknown aggregate '{}'", name);
+            problems.push(DataError::new(ErrorCode::UnknownAggregate, name, message).at(path));
+        }
+
+        if !field.is_string() {
+            problems.push(DataError::invalid_type(name, "a field name").at(path));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use crate::error::{DataError, ErrorCode};
+    use crate::intent;
+    use crate::intent::{Aggregates, FilterExpr, GroupSpec, Perspective, Pivot, SortField};
+    use crate::validate::validate_intent;
+
+    fn paths(kind: &str, intent: serde_json::Value) -> Vec<String> {
+        validate_intent(kind, &intent).unwrap().into_iter().map(|problem| problem.path).collect()
+    }
+
+    #[test]
+    fn valid_perspective_test() {
+        let intent = json!({
+            "filter": [
+                { "field": "value", "operator": "<", "value": 20 },
+                { "field": "date", "operator": "within_last", "value": "P7D" },
+                { "field": "date", "operator": ">=", "value": "today", "type": "date" },
+                { "operator": "or", "expressions": [
+                    { "field": "code", "operator": "in", "value": ["A", "B"] },
+                    { "field": "person.name", "operator": "is_null" }
+                ]}
+            ],
+            "case_sensitive": false,
+            "sort": [{"name": "code", "direction": "asc"}, {"name": "duration", "type": "duration", "direction": "dec"}],
+            "group": ["value"],
+            "aggregates": { "sum:duration": "duration", "count": "code" }
+        });
+
+        assert!(validate_intent("perspective", &intent).unwrap().is_empty());
+    }
+
+    #[test]
+    fn filter_problems_test() {
+        let intent = json!([
+            { "field": "value", "operator": "betwen", "value": [1, 10] },
+            { "field": "value", "operator": "between", "value": [1] },
+            { "field": "value", "operator": "in", "value": 1 },
+            { "operator": "==", "value": 1 },
+            { "operator": "and", "expressions": [
+                { "field": "code", "operator": "like", "value": 1 },
+                { "field": "code" }
+            ]}
+        ]);
+
+        let problems = validate_intent("filter", &intent).unwrap();
+        assert_eq!(problems.len(), 6);
+
+        assert_eq!(problems[0].code, ErrorCode::UnknownOperator);
+        assert_eq!(problems[0].path, "filter[0].operator");
+        assert_eq!(problems[0].message, "unknown operator 'betwen'");
+
+        assert_eq!(problems[1].code, ErrorCode::InvalidValue);
+        assert_eq!(problems[1].path, "filter[1].value");
+
+        assert_eq!(problems[2].code, ErrorCode::InvalidType);
+        assert_eq!(problems[2].path, "filter[2].value");
+
+        assert_eq!(problems[3].code, ErrorCode::MissingProperty);
+        assert_eq!(problems[3].path, "filter[3].field");
+
+        assert_eq!(problems[4].code, ErrorCode::InvalidType);
+        assert_eq!(problems[4].path, "filter[4].expressions[0].value");
+
+        assert_eq!(problems[5].code, ErrorCode::MissingProperty);
+        assert_eq!(problems[5].path, "filter[4].expressions[1].operator");
+    }
+
+    #[test]
+    fn typed_problems_test() {
+        let intent = json!([
+            { "field": "date", "operator": "before", "value": "someday" },
+            { "field": "date", "operator": "same_week" },
+            { "field": "date", "operator": "==", "value": "2022/01/01", "type": "datetime" },
+            { "field": "date", "operator": "in", "value": ["2022/01/01", "x"], "type": "date" },
+            { "field": "date", "operator": "within_next" },
+            { "field": "downtime", "operator": ">", "value": "4 hours", "type": "duration" },
+            { "field": "downtime", "operator": "like", "value": "PT4H", "type": "duration" },
+            { "field": "downtime", "operator": "between", "value": ["PT1H", "PT4H"], "type": "duration" },
+            { "field": "lines[*].qty", "operator": ">", "value": 1, "quantifier": "every" }
+        ]);
+
+        assert_eq!(paths("filter", intent), vec![
+            "filter[0].value", "filter[2].type", "filter[3].value[1]", "filter[4].value", "filter[5].value", "filter[6].operator",
+            "filter[8].quantifier"
+        ]);
+    }
+
+    #[test]
+    fn pattern_problems_test() {
+        let intent = json!([
+            { "field": "code", "operator": "like", "value": "WO-%-2024" },
+            { "field": "code", "operator": "matches", "value": "^WO-\\d+$", "flags": "i" },
+            { "field": "code", "operator": "matches", "value": "(WO" },
+            { "field": "code", "operator": "not_matches", "value": "WO", "flags": "g" },
+            { "field": "code", "operator": "matches", "value": "WO", "flags": 1 },
+            { "field": "code", "operator": "matches", "value": 1 }
+        ]);
+
+        assert_eq!(paths("filter", intent), vec!["filter[2].value", "filter[3].flags", "filter[4].flags", "filter[5].value"]);
+    }
+
+    #[test]
+    fn reference_problems_test() {
+        let intent = json!([
+            { "field": "actual", "operator": ">", "value_field": "planned" },
+            { "field": "end", "operator": "before", "value": { "$field": "due" }, "type": "date" },
+            { "field": "actual", "operator": ">", "value_field": 1 },
+            { "field": "actual", "operator": ">", "value": 1, "value_field": "planned" },
+            { "field": "actual", "operator": ">", "value": { "$field": true } },
+            { "field": "actual", "operator": "between", "value_field": "range" },
+            { "field": "end", "operator": "like", "value": { "$field": "due" }, "type": "date" }
+        ]);
+
+        assert_eq!(paths("filter", intent), vec![
+            "filter[2].value_field", "filter[3].value_field", "filter[4].value.$field", "filter[5].operator", "filter[6].operator"
+        ]);
+    }
+
+    #[test]
+    fn array_problems_test() {
+        let intent = json!([
+            { "field": "tags", "operator": "contains", "value": 1 },
+            { "field": "tags", "operator": "contains", "value": ["a"] },
+            { "field": "tags", "operator": "contains_any", "value": "a" },
+            { "field": "tags", "operator": "contains_all", "value": ["a", "b"] },
+            { "field": "tags", "operator": "is_empty" },
+            { "field": "tags", "operator": ">", "value": 2, "type": "length" },
+            { "field": "tags", "operator": ">", "value": "2", "type": "length" },
+            { "field": "tags", "operator": "like", "value": "a", "type": "length" },
+            { "field": "lines", "operator": "any", "expressions": [{ "field": "qty", "operator": ">", "value": [1] }] },
+            { "operator": "all", "expressions": [] },
+            { "field": "lines", "operator": "none" }
+        ]);
+
+        assert_eq!(paths("filter", intent), vec![
+            "filter[1].value", "filter[2].value", "filter[6].value", "filter[7].operator",
+            "filter[8].expressions[0].value", "filter[9].field", "filter[10].expressions"
+        ]);
+    }
+
+    #[test]
+    fn sort_problems_test() {
+        let intent = json!([
+            { "name": "code", "direction": "up" },
+            { "name": "date", "type": "datetime" },
+            { "name": "priority", "type": "enum" },
+            { "direction": "asc" },
+            { "name": "code", "nulls": "top" },
+            { "name": "code", "collation": { "natural": "yes", "locale": "en" } }
+        ]);
+
+        assert_eq!(paths("sort", intent), vec!["sort[0].direction", "sort[1].type", "sort[2].order", "sort[3].name", "sort[4].nulls", "sort[5].collation.locale", "sort[5].collation.natural"]);
+    }
+
+    #[test]
+    fn group_problems_test() {
+        assert_eq!(paths("group", json!(["value", 1])), vec!["group[1]"]);
+        assert!(paths("group", json!([])).is_empty());
+        assert_eq!(paths("group", json!("value")), vec!["group"]);
+    }
+
+    #[test]
+    fn group_object_problems_test() {
+        let intent = json!([
+            { "field": "site", "order_by": "count", "direction": "desc" },
+            { "field": "code", "order_by": "size" },
+            { "field": "code", "order_by": { "avg": "value" } },
+            { "order_by": { "sum": "value", "max": "value" } },
+            { "field": "code", "type": "enum" },
+            { "field": "code", "case_insensitive": "yes", "null_label": 0 },
+            { "field": "code", "case_insensitive": true, "null_label": "none" },
+            { "field": "date", "bucket": { "date": "month" } },
+            { "field": "date", "bucket": { "date": "hour" } },
+            { "field": "value", "bucket": { "width": 10, "edges": [1] } },
+            { "field": "value", "bucket": { "edges": [10, 5], "size": 1 } },
+            { "field": "value", "bucket": { "durations": ["PT1H"], "labels": ["short"] } }
+        ]);
+
+        assert_eq!(paths("group", intent), vec![
+            "group[1].order_by", "group[2].order_by.avg", "group[3].field", "group[3].order_by", "group[4].order",
+            "group[5].case_insensitive", "group[5].null_label", "group[8].bucket.date", "group[9].bucket",
+            "group[10].bucket.size", "group[10].bucket.edges[1]", "group[11].bucket.labels"
+        ]);
+    }
+
+    #[test]
+    fn aggregate_problems_test() {
+        let intent = json!({
+            "sum:duration": "duration",
+            "sum:durations": "duration",
+            "max": 1
+        });
+
+        let problems = validate_intent("aggregate", &intent).unwrap();
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[0].path, "aggregates.max");
+        assert_eq!(problems[0].code, ErrorCode::InvalidType);
+        assert_eq!(problems[1].path, "aggregates.sum:durations");
+        assert_eq!(problems[1].code, ErrorCode::UnknownAggregate);
+    }
+
+    #[test]
+    fn perspective_problems_test() {
+        let intent = json!({
+            "filter": [{ "field": "value", "operator": "=<", "value": 20 }],
+            "case_sensitive": "no",
+            "sorting": [],
+            "aggregates": { "avg": "value" },
+            "page": { "offset": -1, "size": 10 }
+        });
+
+        assert_eq!(paths("perspective", intent), vec!["aggregates.avg", "case_sensitive", "filter[0].operator", "page.offset", "page.size", "sorting", "page"]);
+    }
+
+    #[test]
+    fn page_problems_test() {
+        let problems = validate_intent("perspective", &json!({ "group": ["site"], "page": { "offset": 0, "limit": 10 } })).unwrap();
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].code, ErrorCode::InvalidValue);
+        assert_eq!(problems[0].path, "page");
+
+        assert_eq!(paths("perspective", json!({ "aggregates": { "sum": "value" }, "page": { "limit": "10" } })), vec!["page.limit", "page"]);
+        assert!(paths("perspective", json!({ "sort": [{ "name": "code" }], "page": { "limit": 10 } })).is_empty());
+    }
+
+    #[test]
+    fn computed_problems_test() {
+        let intent = json!({
+            "computed": [
+                { "name": "total", "expression": "qty * price" },
+                { "name": "total", "expression": "qty *" },
+                { "expression": "unknown(qty)" },
+                "qty"
+            ]
+        });
+
+        let problems = validate_intent("perspective", &intent).unwrap();
+        let paths: Vec<&str> = problems.iter().map(|problem| problem.path.as_str()).collect();
+        assert_eq!(paths, vec!["computed[1].name", "computed[1].expression", "computed[2].name", "computed[2].expression", "computed[3]"]);
+        assert_eq!(problems[3].message, "unknown function 'unknown' at position 0");
+    }
+
+    fn parse_problem(kind: &str, intent: &Value) -> Option<DataError> {
+        let result = match kind {
+            "filter"        => intent::parse::<Vec<FilterExpr>>(intent, kind).map(|_| ()),
+            "sort"          => intent::parse::<Vec<SortField>>(intent, kind).map(|_| ()),
+            "group"         => intent::parse::<Vec<GroupSpec>>(intent, kind).map(|_| ()),
+            "aggregates"    => intent::parse::<Aggregates>(intent, kind).map(|_| ()),
+            "perspective"   => intent::parse::<Perspective>(intent, "").map(|_| ()),
+            "pivot"         => intent::parse::<Pivot>(intent, "").map(|_| ()),
+            _ => unreachable!()
+        };
+
+        result.err()
+    }
+
+    /// The validator and the typed intent model must agree on the first problem of an intent.
+    #[test]
+    fn agrees_with_parse_test() {
+        let cases = vec![
+            ("filter", json!([{ "field": "value", "operator": "betwen", "value": [1, 10] }])),
+            ("filter", json!([{ "field": "value", "operator": "between", "value": [1] }])),
+            ("filter", json!([{ "field": "value", "operator": "between", "value": 1 }])),
+            ("filter", json!([{ "field": "value", "operator": "in", "value": 1 }])),
+            ("filter", json!([{ "field": "value", "operator": "in" }])),
+            ("filter", json!([{ "operator": "==", "value": 1 }])),
+            ("filter", json!([{ "field": 1, "operator": "==", "value": 1 }])),
+            ("filter", json!([{ "field": "code", "operator": "like", "value": 1 }])),
+            ("filter", json!([{ "field": "code", "operator": "like" }])),
+            ("filter", json!([{ "field": "code", "operator": "matches", "value": "(WO" }])),
+            ("filter", json!([{ "field": "code", "operator": "matches", "value": "WO", "flags": "g" }])),
+            ("filter", json!([{ "field": "code", "operator": "matches", "value": "WO", "flags": 1 }])),
+            ("filter", json!([{ "field": "code" }])),
+            ("filter", json!([{ "field": "code", "operator": 1 }])),
+            ("filter", json!([{ "field": "code", "operator": ">" }])),
+            ("filter", json!([{ "operator": "and", "expressions": [{ "field": "code", "operator": "like", "value": 1 }] }])),
+            ("filter", json!([{ "operator": "and" }])),
+            ("filter", json!([{ "operator": "or", "expressions": 1 }])),
+            ("filter", json!([{ "field": "date", "operator": "before", "value": "someday" }])),
+            ("filter", json!([{ "field": "date", "operator": "==", "value": "2022/01/01", "type": "datetime" }])),
+            ("filter", json!([{ "field": "date", "operator": "in", "value": ["2022/01/01", "x"], "type": "date" }])),
+            ("filter", json!([{ "field": "date", "operator": "within_next" }])),
+            ("filter", json!([{ "field": "downtime", "operator": ">", "value": "4 hours", "type": "duration" }])),
+            ("filter", json!([{ "field": "downtime", "operator": "like", "value": "PT4H", "type": "duration" }])),
+            ("filter", json!([{ "field": "lines[*].qty", "operator": ">", "value": 1, "quantifier": "every" }])),
+            ("filter", json!([{ "field": "actual", "operator": ">", "value_field": 1 }])),
+            ("filter", json!([{ "field": "actual", "operator": ">", "value": 1, "value_field": "planned" }])),
+            ("filter", json!([{ "field": "actual", "operator": ">", "value": { "$field": true } }])),
+            ("filter", json!([{ "field": "actual", "operator": "between", "value_field": "range" }])),
+            ("filter", json!([{ "field": "tags", "operator": "contains", "value": ["a"] }])),
+            ("filter", json!([{ "field": "tags", "operator": "contains_any", "value": "a" }])),
+            ("filter", json!([{ "field": "tags", "operator": ">", "value": "2", "type": "length" }])),
+            ("filter", json!([{ "field": "tags", "operator": "like", "value": "a", "type": "length" }])),
+            ("filter", json!([{ "operator": "all", "expressions": [] }])),
+            ("filter", json!([{ "field": "lines", "operator": "none" }])),
+            ("filter", json!([{ "field": "code", "operator": "==", "value": 1, "colour": "red" }])),
+            ("filter", json!({ "field": "code" })),
+            ("sort", json!([{ "name": "code", "direction": "up" }])),
+            ("sort", json!([{ "name": "date", "type": "datetime" }])),
+            ("sort", json!([{ "name": "priority", "type": "enum" }])),
+            ("sort", json!([{ "direction": "asc" }])),
+            ("sort", json!([{ "name": "code", "nulls": "top" }])),
+            ("sort", json!([{ "name": "code", "collation": { "locale": "en" } }])),
+            ("sort", json!([{ "name": "code", "collation": { "natural": "yes" } }])),
+            ("sort", json!([{ "name": "code", "order": "up" }])),
+            ("sort", json!("code")),
+            ("group", json!(["value", 1])),
+            ("group", json!([])),
+            ("group", json!("value")),
+            ("group", json!([{ "field": "code", "order_by": "size" }])),
+            ("group", json!([{ "field": "code", "order_by": { "avg": "value" } }])),
+            ("group", json!([{ "order_by": "count" }])),
+            ("group", json!([{ "field": "code", "order_by": { "sum": "value", "max": "value" } }])),
+            ("group", json!([{ "field": "code", "type": "enum" }])),
+            ("group", json!([{ "field": "code", "case_insensitive": "yes" }])),
+            ("group", json!([{ "field": "code", "null_label": 0 }])),
+            ("group", json!([{ "field": "date", "bucket": { "date": "hour" } }])),
+            ("group", json!([{ "field": "value", "bucket": { "width": 10, "edges": [1] } }])),
+            ("group", json!([{ "field": "value", "bucket": { "width": 0 } }])),
+            ("group", json!([{ "field": "value", "bucket": { "edges": [10, 5] } }])),
+            ("group", json!([{ "field": "value", "bucket": { "durations": ["PT1H"], "labels": ["short"] } }])),
+            ("aggregates", json!({ "max": 1 })),
+            ("aggregates", json!({ "sum:durations": "duration" })),
+            ("aggregates", json!([])),
+            ("perspective", json!({ "filter": [{ "field": "value", "operator": "=<", "value": 20 }] })),
+            ("perspective", json!({ "case_sensitive": "no" })),
+            ("perspective", json!({ "sorting": [] })),
+            ("perspective", json!({ "page": { "offset": -1 } })),
+            ("perspective", json!({ "page": { "size": 10 } })),
+            ("perspective", json!({ "computed": [{ "name": "total", "expression": "qty *" }] })),
+            ("perspective", json!({ "computed": [{ "expression": "qty" }] })),
+            ("perspective", json!({ "computed": ["qty"] })),
+            ("perspective", json!({ "computed": [{ "name": "a", "expression": "1" }, { "name": "a", "expression": "2" }] })),
+            ("perspective", json!({ "group": ["site"], "page": { "offset": 0, "limit": 10 } })),
+            ("perspective", json!({ "aggregates": { "sum": "value" }, "page": { "limit": 10 } })),
+            ("perspective", json!(1)),
+            ("pivot", json!({ "rows": ["site"] })),
+            ("pivot", json!({ "values": [] })),
+            ("pivot", json!({ "values": [{ "agg": "avg", "field": "value" }] })),
+            ("pivot", json!({ "values": [{ "field": 1 }] })),
+            ("pivot", json!({ "values": [{ "agg": "sum", "field": 1 }] })),
+            ("pivot", json!({ "values": [{ "agg": "sum", "field": "value" }], "totals": true })),
+            ("pivot", json!({ "rows": [{ "field": "date", "bucket": { "date": "hour" } }], "values": [{ "agg": "sum", "field": "value" }] }))
+        ];
+
+        let mut disagreements: Vec<String> = Vec::new();
+
+        for (kind, intent) in cases {
+            let problems: Vec<(ErrorCode, String)> = validate_intent(kind, &intent).unwrap().into_iter()
+                .map(|problem| (problem.code, problem.path))
+                .collect();
+
+            // parsing stops at the first problem, the validator reports it along with any others
+            let agrees = match parse_problem(kind, &intent) {
+                None => problems.is_empty(),
+                Some(error) => problems.contains(&(error.code, error.path))
+            };
+
+            if !agrees {
+                disagreements.push(format!("{} {}: {:?} {:?}", kind, intent, parse_problem(kind, &intent), problems));
+            }
+        }
+
+        assert!(disagreements.is_empty(), "{}", disagreements.join("\n"));
+    }
+
+    #[test]
+    fn unknown_kind_test() {
+        let error = validate_intent("chart", &json!({})).unwrap_err();
+        assert_eq!(error.path, "kind");
+    }
+
+    #[test]
+    fn pivot_problems_test() {
+        let intent = json!({
+            "rows": ["site", { "field": "date", "bucket": { "date": "hour" } }],
+            "columns": [],
+            "values": [{ "agg": "sum", "field": "value" }, { "agg": "avg", "field": "value" }, { "field": 1 }],
+            "totals": true
+        });
+
+        assert_eq!(paths("pivot", intent), vec!["rows[1].bucket.date", "totals", "values[1].agg", "values[2].agg", "values[2].field"]);
+        assert_eq!(paths("pivot", json!({ "rows": ["site"] })), vec!["values"]);
+        assert!(paths("pivot", json!({ "values": [{ "agg": "count", "field": "code" }] })).is_empty());
+    }
+}
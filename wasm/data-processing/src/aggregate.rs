@@ -1,6 +0,0 @@
-mod aggregate_partial;
-mod aggregate_all;
-mod aggregate_numbers;
-
-pub use aggregate_all::aggregate_all;
-pub use aggregate_partial::aggregate_partial;
\ No newline at end of file
@@ -1,5 +0,0 @@
-mod group_all;
-mod group_partial;
-
-pub use group_all::group_data_all;
-pub use group_partial::group_data_partial;
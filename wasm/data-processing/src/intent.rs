@@ -0,0 +1,102 @@
+use data_engine::error::{DataError, DataResult};
+use data_engine::intent;
+use data_engine::intent::{AggregateKind, AggregateSpec, FilterExpr, GroupSpec, Page, Pivot, SortField, UniqueField, ValueType};
+use js_sys::Array;
+use serde_json::{json, Value};
+use wasm_bindgen::JsValue;
+use crate::row::to_value;
+
+/**
+    The aggregates calculated for every field of the aggregate intent.
+**/
+pub const NUMBER_AGGREGATES: [AggregateKind; 5] = [
+    AggregateKind::Min,
+    AggregateKind::Max,
+    AggregateKind::Sum,
+    AggregateKind::Ave,
+    AggregateKind::Count
+];
+
+/**
+    A filter intent is a single expression or an array of expressions that must all pass.
+**/
+pub fn filter_intent(intent: &JsValue) -> DataResult<Vec<FilterExpr>> {
+    let value = to_value(intent);
+
+    match value.is_array() {
+        true => intent::parse(&value, "filter"),
+        false => Ok(vec![intent::parse(&value, "filter")?])
+    }
+}
+
+/**
+    Sort fields are written as "field:direction:nulls", for example "code:desc:first".
+    The direction is optional and ascending by default, nulls are last unless set to "first".
+    Fields that need a collation are passed as sort field objects,
+    for example { name: "code", collation: { natural: true, case_insensitive: true } }.
+**/
+pub fn sort_intent(intent: &Array) -> DataResult<Vec<SortField>> {
+    let fields: Vec<Value> = intent.iter().map(|item| {
+        match item.as_string() {
+            None => to_value(&item),
+            Some(text) => {
+                let mut parts = text.split(':');
+                let name = parts.next().unwrap_or_default();
+                let direction = parts.next().unwrap_or("asc");
+
+                match parts.next() {
+                    None => json!({ "name": name, "direction": direction }),
+                    Some(nulls) => json!({ "name": name, "direction": direction, "nulls": nulls })
+                }
+            }
+        }
+    }).collect();
+
+    intent::parse(&Value::from(fields), "sort")
+}
+
+/**
+    A page is { offset, limit }, both optional.
+**/
+pub fn page_intent(intent: &JsValue) -> DataResult<Page> {
+    intent::parse(&to_value(intent), "page")
+}
+
+/**
+    The group intent is an array of field names or group objects with the order of the groups.
+**/
+pub fn group_intent(intent: &Array) -> DataResult<Vec<GroupSpec>> {
+    intent::parse(&to_value(intent), "group")
+}
+
+/**
+    A pivot is { rows, columns, values } with the group specs of the rows and columns and the aggregates of the cells.
+**/
+pub fn pivot_intent(intent: &JsValue) -> DataResult<Pivot> {
+    intent::parse(&to_value(intent), "pivot")
+}
+
+/**
+    Every field gets the min, max, sum, average and count aggregates.
+**/
+pub fn aggregate_intent(intent: &[JsValue]) -> DataResult<Vec<AggregateSpec>> {
+    let fields = field_names(intent, "aggregate")?;
+
+    Ok(fields.iter()
+        .flat_map(|field| NUMBER_AGGREGATES.iter().map(move |kind| AggregateSpec::new(*kind, field)))
+        .collect())
+}
+
+/**
+    The unique values of the fields are collected as strings.
+**/
+pub fn unique_intent(intent: &[JsValue]) -> DataResult<Vec<UniqueField>> {
+    let fields = field_names(intent, "fields")?;
+    Ok(fields.iter().map(|field| UniqueField::new(field, ValueType::String)).collect())
+}
+
+fn field_names(intent: &[JsValue], name: &str) -> DataResult<Vec<String>> {
+    intent.iter().enumerate().map(|(index, field)| {
+        field.as_string().ok_or_else(|| DataError::invalid_type(&format!("[{}]", index), "a field name").at(name))
+    }).collect()
+}
@@ -1,16 +1,19 @@
 extern crate core;
 
-mod evaluators;
-mod traits;
 mod macros;
-mod sort;
 mod utils;
-mod group;
-mod aggregate;
-mod unique_values;
-
+mod row;
+mod intent;
+mod results;
+
+use data_engine::error::{DataError, DataResult};
+use data_engine::processors;
+use data_engine::expression::Expression;
+use data_engine::intent::{GroupSpec, Page};
+use data_engine::utils::check_rows;
 use js_sys::{Array, Reflect, try_iter};
 use wasm_bindgen::prelude::*;
+use crate::row::{from_value, rows, JsRow};
 
 #[wasm_bindgen]
 pub fn init_panic_hook() {
@@ -38,6 +41,16 @@ pub fn get_value(obj: &JsValue, path: &str) -> JsValue {
     }
 }
 
+/**
+    Evaluate an expression such as "qty * price" or "coalesce(discount, 0)" on the object.
+    The fields of the expression are read from the object, math on missing values gives null.
+**/
+#[wasm_bindgen]
+pub fn evaluate_expression(expression: &str, values: &JsValue) -> Result<JsValue, JsValue> {
+    let expression = Expression::parse(expression).map_err(|error| error.at("expression"))?;
+    from_value(&expression.evaluate(&JsRow(values.clone())))
+}
+
 /**
     Check if a object matches the filter intent.
     Based on the filter intent, return true if the object passes evaluation.
@@ -45,7 +58,8 @@ pub fn get_value(obj: &JsValue, path: &str) -> JsValue {
 **/
 #[wasm_bindgen]
 pub fn in_filter(intent: &JsValue, row: &JsValue, case_sensitive: bool) -> Result<bool, JsValue> {
-    evaluators::evaluate_object(intent, row, case_sensitive)
+    let filters = intent::filter_intent(intent)?;
+    Ok(processors::in_filter(&filters, &JsRow(row.clone()), case_sensitive))
 }
 
 /**
@@ -54,31 +68,23 @@ pub fn in_filter(intent: &JsValue, row: &JsValue, case_sensitive: bool) -> Resul
 **/
 #[wasm_bindgen]
 pub fn filter(data: &Array, intent: &JsValue, case_sensitive: bool) -> Result<Array, JsValue> {
-    let result = Array::new();
-    let iterator = data.iter();
-
-    for (index, row) in iterator.enumerate() {
-        let pass = in_filter(intent, &row, case_sensitive)?;
-
-        if pass {
-            result.push(&JsValue::from(index));
-        }
-    }
+    let filters = intent::filter_intent(intent)?;
+    let result = processors::filter(&filters, &rows(data), case_sensitive);
 
-    Ok(result)
+    Ok(result.into_iter().map(JsValue::from).collect())
 }
 
 #[wasm_bindgen]
 pub fn fuzzy_filter(data: &Array, intent: &JsValue) -> Result<Array, JsValue> {
     // Extract fields and value from intent
-    let fields = Reflect::get(&intent, &JsValue::from("fields"))
+    let fields = Reflect::get(intent, &JsValue::from("fields"))
         .map_err(|_| JsValue::from("fuzzy_filter - failed to get fields"))?;
 
     let fields_vec: Vec<JsValue> = try_iter(&fields)?
         .ok_or_else(|| JsValue::from("fuzzy_filter - fields must be an array"))?
         .collect::<Result<Vec<_>, _>>()?;
 
-    let value = Reflect::get(&intent, &JsValue::from("value"))
+    let value = Reflect::get(intent, &JsValue::from("value"))
         .and_then(|v| v.as_string().ok_or_else(|| JsValue::from("fuzzy_filter - value must be a string")))?;
     let value = value.to_lowercase();
 
@@ -106,37 +112,44 @@ pub fn fuzzy_filter(data: &Array, intent: &JsValue) -> Result<Array, JsValue> {
     Sort the array of objects based on the sort intent.
     If you only want to sort a subset of the records, pass in an array of indexes for the objects
     that must make up the sort result.
+    Pass a limit and offset to get one page of the sorted records, only the records up to the end
+    of the page are sorted.
 **/
 #[wasm_bindgen]
-pub fn sort(data: &Array, intent: &Array, rows: Option<Vec<usize>>) -> Result<Vec<usize>, JsValue> {
+pub fn sort(data: &Array, intent: &Array, row_indexes: Option<Vec<usize>>, limit: Option<usize>, offset: Option<usize>) -> Result<Vec<usize>, JsValue> {
     if data.length() == 0 {
         let result: Vec<usize> = vec![];
         return Ok(result);
     }
 
-    let result: Result<Vec<usize>, JsValue> = match rows {
-        None => sort::sort_all(data, intent),
-        Some(rows) => sort::sort_partial(data, intent, rows)
-    };
-
-    result
+    let sort_intent = intent::sort_intent(intent)?;
+    let page = Page::new(offset.unwrap_or(0), limit);
+    Ok(processors::sort_page(&sort_intent, &rows(data), checked_rows(row_indexes, data)?, &page))
 }
 
 /**
-    JHR todo: we need to be able to pass in sort data so that the group items can be sorted if a group field matched a sort field.
+    Every group has an id made from the keys on its path and the value it groups on.
+    The children of a group are ordered, groups on a field of the sort intent follow that sort
+    unless the group intent gives an order of its own.
 **/
 #[wasm_bindgen]
-pub fn group(data: &Array, intent: &Array, rows: Option<Vec<usize>>) -> Result<js_sys::Object, JsValue> {
+pub fn group(data: &Array, intent: &Array, row_indexes: Option<Vec<usize>>, sort: Option<Array>) -> Result<js_sys::Object, JsValue> {
     if data.length() == 0 {
         return Ok(js_sys::Object::new());
     }
 
-    let result: Result<js_sys::Object, JsValue> = match rows {
-        None => group::group_data_all(data, intent),
-        Some(rows) => group::group_data_partial(data, intent, rows)
+    let sort_intent = match &sort {
+        Some(sort) => intent::sort_intent(sort)?,
+        None => Vec::new()
     };
 
-    result
+    let group_intent: Vec<GroupSpec> = intent::group_intent(intent)?.iter()
+        .map(|spec| spec.follow_sort(&sort_intent))
+        .collect();
+
+    let result = processors::group(&group_intent, &rows(data), checked_rows(row_indexes, data)?, None)?;
+
+    Ok(from_value(&result)?.into())
 }
 
 /**
@@ -157,17 +170,15 @@ pub fn group(data: &Array, intent: &Array, rows: Option<Vec<usize>>) -> Result<j
     pass it back where the assets are sorted alphabetically
 **/
 #[wasm_bindgen]
-pub fn aggregate(data: &Array, intent: Vec<JsValue>, rows: Option<Vec<usize>>) -> Result<JsValue, JsValue> {
+pub fn aggregate(data: &Array, intent: Vec<JsValue>, row_indexes: Option<Vec<usize>>) -> Result<JsValue, JsValue> {
     if data.length() == 0 {
         return Ok(JsValue::NULL);
     }
 
-    let result: Result<JsValue, JsValue> = match rows {
-        None => aggregate::aggregate_all(data, &intent),
-        Some(rows) => aggregate::aggregate_partial(data, &intent, rows)
-    };
+    let aggregate_intent = intent::aggregate_intent(&intent)?;
+    let result = processors::aggregate_rows(&aggregate_intent, &rows(data), checked_rows(row_indexes, data)?)?;
 
-    result
+    from_value(&results::aggregate_result(&aggregate_intent, &result))
 }
 
 /**
@@ -184,68 +195,111 @@ pub fn aggregate(data: &Array, intent: Vec<JsValue>, rows: Option<Vec<usize>>) -
     Show me the values in a ascending order of the value itself
 **/
 #[wasm_bindgen]
-pub fn unique_values(data: &Array, intent: Vec<JsValue>, rows: Option<Vec<usize>>) -> Result<JsValue, JsValue> {
+pub fn unique_values(data: &Array, intent: Vec<JsValue>, row_indexes: Option<Vec<usize>>) -> Result<JsValue, JsValue> {
     if data.length() == 0 {
         return Ok(JsValue::NULL);
     }
 
-    let result: Result<JsValue, JsValue> = match rows {
-        None => unique_values::unique_values_all(data, &intent),
-        Some(rows) => unique_values::unique_values_partial(data, &intent, rows)
-    };
+    let unique_intent = intent::unique_intent(&intent)?;
+    let result = processors::get_unique(&unique_intent, &rows(data), checked_rows(row_indexes, data)?)?;
 
-    result
+    from_value(&results::unique_result(&result))
 }
 
+/**
+    Cross tab the records on the row and column groups of the intent with the aggregates in the cells.
+    Every group is followed by its total and the grand total is last.
+    The records and fields of the result are the flat rows and columns for export_to_excel.
+**/
 #[wasm_bindgen]
-pub fn get_perspective(data: &Array, intent: JsValue) -> Result<JsValue, JsValue> {
-    let filter_def = Reflect::get(&intent, &JsValue::from("filter")).unwrap();
-    let fuzzy_filter_def = Reflect::get(&intent, &JsValue::from("fuzzy_filter")).unwrap();
-    let sort_def = Reflect::get(&intent, &JsValue::from("sort")).unwrap();
-    let group_def = Reflect::get(&intent, &JsValue::from("group")).unwrap();
-    let aggregate_def = Reflect::get(&intent, &JsValue::from("aggregate")).unwrap();
+pub fn pivot(data: &Array, intent: &JsValue, row_indexes: Option<Vec<usize>>) -> Result<JsValue, JsValue> {
+    let pivot_intent = intent::pivot_intent(intent)?;
+    let result = processors::pivot(&pivot_intent, &rows(data), checked_rows(row_indexes, data)?)?;
+
+    from_value(&result)
+}
 
-    let has_filter = !filter_def.is_undefined() && !filter_def.is_null();
-    let has_fuzzy_filter = !fuzzy_filter_def.is_undefined() && !fuzzy_filter_def.is_null();
-    let has_sort = !sort_def.is_undefined() && !sort_def.is_null();
-    let has_group = !group_def.is_undefined() && !group_def.is_null();
-    let has_aggregate = !aggregate_def.is_undefined() && !aggregate_def.is_null();
+/**
+    Filter, sort and group the records in one call.
+    With a page, { offset, limit }, the result is { rows, total } with the records on the page
+    and the number of records in all the pages, a page can't be used with group or aggregate.
+**/
+#[wasm_bindgen]
+pub fn get_perspective(data: &Array, intent: JsValue) -> Result<JsValue, JsValue> {
+    let filter_def = intent_property(&intent, "filter")?;
+    let fuzzy_filter_def = intent_property(&intent, "fuzzy_filter")?;
+    let sort_def = intent_property(&intent, "sort")?;
+    let group_def = intent_property(&intent, "group")?;
+    let aggregate_def = intent_property(&intent, "aggregate")?;
+    let page_def = intent_property(&intent, "page")?;
+
+    let has_sort = sort_def.is_some();
+    let has_group = group_def.is_some();
+    let has_aggregate = aggregate_def.is_some();
+    let has_page = page_def.is_some();
+
+    let page = match &page_def {
+        Some(page_def) => {
+            Page::check_use(has_group, has_aggregate)?;
+            Some(intent::page_intent(page_def)?)
+        }
+        None => None
+    };
 
-    let mut rows: Vec<usize> = vec![];
+    // None until a filter picked the rows, all the rows are then in the perspective
+    let mut rows: Option<Vec<usize>> = None;
 
-    if has_filter {
-        let filter_result = filter(data, &filter_def, false)?;
+    if let Some(filter_def) = &filter_def {
+        let filter_result = filter(data, filter_def, false)?;
 
-        if !has_sort && !has_group && !has_aggregate {
+        if fuzzy_filter_def.is_none() && !has_sort && !has_group && !has_aggregate && !has_page {
             return Ok(JsValue::from(filter_result));
         }
 
-        rows = filter_result.iter().map(|x| x.as_f64().unwrap() as usize).collect();
+        rows = Some(filter_result.iter().filter_map(|x| x.as_f64()).map(|x| x as usize).collect());
     }
 
-    if has_fuzzy_filter {
+    if let Some(fuzzy_filter_def) = &fuzzy_filter_def {
         let mut fuzzy_data: &Array = data;
         let temp_array = Array::new();
 
-        if !rows.is_empty() {
+        if let Some(rows) = &rows {
             for row in rows.iter() {
                 temp_array.push(&data.at(*row as i32));
             }
             fuzzy_data = &temp_array;
         }
 
-        let fuzzy_filter_result = fuzzy_filter(fuzzy_data, &fuzzy_filter_def)?;
+        // the fuzzy filter gives positions in the rows it was given, those are the rows of the filter
+        let fuzzy_rows: Vec<usize> = fuzzy_filter(fuzzy_data, fuzzy_filter_def)?.iter()
+            .filter_map(|x| x.as_f64())
+            .map(|x| x as usize)
+            .map(|position| rows.as_ref().map_or(position, |rows| rows[position]))
+            .collect();
 
-        if !has_sort && !has_group && !has_aggregate {
-            return Ok(JsValue::from(fuzzy_filter_result));
+        if !has_sort && !has_group && !has_aggregate && !has_page {
+            return Ok(fuzzy_rows.into_iter().map(JsValue::from).collect::<Array>().into());
         }
 
-        rows = fuzzy_filter_result.iter().map(|x| x.as_f64().unwrap() as usize).collect();
+        rows = Some(fuzzy_rows);
+    }
+
+    if let Some(page) = page {
+        let total = rows.as_ref().map_or(data.length() as usize, |rows| rows.len());
+
+        let page_rows = match &sort_def {
+            Some(sort_def) => sort(data, &sort_def.clone().into(), rows, page.limit, Some(page.offset))?,
+            None => page.apply(rows.unwrap_or_else(|| (0..data.length() as usize).collect()))
+        };
+
+        return from_value(&processors::page_result(page_rows, total));
     }
 
-    if has_sort {
-        let sort_intent: Array = sort_def.into();
-        let sort_result = sort(data, &sort_intent, Some(rows))?;
+    let mut rows = rows.unwrap_or_else(|| (0..data.length() as usize).collect());
+
+    if let Some(sort_def) = &sort_def {
+        let sort_intent: Array = sort_def.clone().into();
+        let sort_result = sort(data, &sort_intent, Some(rows), None, None)?;
         rows = sort_result;
 
         let result: Array = rows.iter().map(|x| JsValue::from(*x)).collect();
@@ -255,9 +309,10 @@ pub fn get_perspective(data: &Array, intent: JsValue) -> Result<JsValue, JsValue
         }
     }
 
-    if has_group {
+    if let Some(group_def) = group_def {
         let group_intent: Array = group_def.into();
-        let group_result = group(data, &group_intent, Some(rows))?;
+        let sort_intent: Option<Array> = sort_def.map(|sort_def| sort_def.into());
+        let group_result = group(data, &group_intent, Some(rows), sort_intent)?;
         let result = group_result;
 
         if !has_aggregate {
@@ -267,3 +322,27 @@ pub fn get_perspective(data: &Array, intent: JsValue) -> Result<JsValue, JsValue
 
     Ok(JsValue::NULL)
 }
+
+/**
+    A property of the perspective intent, undefined and null properties are left out.
+    An intent that is not an object fails here instead of panicking.
+**/
+fn intent_property(intent: &JsValue, name: &str) -> DataResult<Option<JsValue>> {
+    if !intent.is_object() {
+        return Err(DataError::invalid_type("", "a perspective object"));
+    }
+
+    let value = Reflect::get(intent, &JsValue::from(name)).map_err(|_| DataError::invalid_type(name, "a readable property"))?;
+    Ok((!value.is_undefined() && !value.is_null()).then_some(value))
+}
+
+/**
+    Row indexes passed in by the caller must exist in the data.
+**/
+fn checked_rows(row_indexes: Option<Vec<usize>>, data: &Array) -> Result<Option<Vec<usize>>, JsValue> {
+    if let Some(row_indexes) = &row_indexes {
+        check_rows(row_indexes, data.length() as usize)?;
+    }
+
+    Ok(row_indexes)
+}
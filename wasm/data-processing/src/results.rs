@@ -0,0 +1,59 @@
+use data_engine::intent::{AggregateSpec, AggregateKind};
+use serde_json::{Map, Value};
+
+/**
+    The engine gives back a list of aggregates, here they are an object per field with the aggregates on it.
+    For example {"value": {"min": 1, "max": 10, "sum": 20, "ave": 5, "count": 4}}.
+**/
+pub fn aggregate_result(intent: &[AggregateSpec], aggregates: &Value) -> Value {
+    let mut result = Map::new();
+    let values = aggregates.as_array().map(Vec::as_slice).unwrap_or_default();
+
+    for (spec, aggregate) in intent.iter().zip(values) {
+        let value = match spec.aggregate {
+            AggregateKind::Count => Value::from(value_count(&aggregate["value"])),
+            _ => aggregate["value"].clone()
+        };
+
+        let field = result.entry(spec.field.clone()).or_insert_with(|| Value::Object(Map::new()));
+        field[spec.aggregate.as_str()] = value;
+    }
+
+    Value::Object(result)
+}
+
+/**
+    The count aggregate counts every value, the total is the number of values aggregated.
+**/
+fn value_count(counts: &Value) -> i64 {
+    counts.as_array()
+        .map(|counts| counts.iter().filter_map(|count| count["count"].as_i64()).sum())
+        .unwrap_or(0)
+}
+
+/**
+    The unique values of a field as an object of value and the number of times it was found.
+**/
+pub fn unique_result(unique: &Value) -> Value {
+    let mut result = Map::new();
+
+    if let Some(fields) = unique.as_object() {
+        for (field, values) in fields {
+            let mut counts = Map::new();
+
+            for item in values.as_array().map(Vec::as_slice).unwrap_or_default() {
+                let key = match &item["value"] {
+                    Value::Null => "null".to_string(),
+                    Value::String(value) => value.clone(),
+                    value => value.to_string()
+                };
+
+                counts.insert(key, item["count"].clone());
+            }
+
+            result.insert(field.clone(), Value::Object(counts));
+        }
+    }
+
+    Value::Object(result)
+}
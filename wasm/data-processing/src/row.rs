@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+use data_engine::path::{resolve, FieldPath, Segment};
+use data_engine::Row;
+use js_sys::{Array, Reflect, JSON};
+use serde_json::Value;
+use wasm_bindgen::JsValue;
+
+/**
+    A javascript object read by the engine processors.
+    Only the fields used by the intent are read, each is converted to a json value when it is read.
+**/
+pub struct JsRow(pub JsValue);
+
+impl Row for JsRow {
+    fn field(&self, path: &FieldPath) -> Option<Cow<'_, Value>> {
+        let segments = path.segments();
+        let projection = segments.iter().position(|segment| *segment == Segment::Each).unwrap_or(segments.len());
+        let value = to_value(&get_property(&self.0, &segments[..projection])?);
+
+        if projection == segments.len() {
+            return Some(Cow::Owned(value));
+        }
+
+        // the array is converted once, the values on the items are read from the json
+        resolve(&value, &segments[projection..]).map(|value| Cow::Owned(value.into_owned()))
+    }
+}
+
+/**
+    Follow the keys and indexes of the path on a javascript value.
+    None when the value does not have the property or the path has a projection.
+**/
+pub fn get_property(value: &JsValue, segments: &[Segment]) -> Option<JsValue> {
+    let mut current = value.clone();
+
+    for segment in segments {
+        let key = match segment {
+            Segment::Key(key) => JsValue::from(key),
+            Segment::Index(index) => JsValue::from(*index as u32),
+            Segment::Each => return None
+        };
+
+        if !current.is_object() || !Reflect::has(&current, &key).ok()? {
+            return None;
+        }
+
+        current = Reflect::get(&current, &key).ok()?;
+    }
+
+    Some(current)
+}
+
+/**
+    Wrap the records of the array so the engine can read them.
+**/
+pub fn rows(data: &Array) -> Vec<JsRow> {
+    data.iter().map(JsRow).collect()
+}
+
+/**
+    Convert a javascript value to json, undefined and functions become null.
+    Objects and arrays go through JSON.stringify so dates end up as ISO strings.
+**/
+pub fn to_value(value: &JsValue) -> Value {
+    if let Some(text) = value.as_string() {
+        return Value::from(text);
+    }
+
+    if let Some(number) = value.as_f64() {
+        return number_value(number);
+    }
+
+    if let Some(flag) = value.as_bool() {
+        return Value::from(flag);
+    }
+
+    if value.is_null() || value.is_undefined() || value.is_function() {
+        return Value::Null;
+    }
+
+    JSON::stringify(value).ok()
+        .and_then(|json| json.as_string())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or(Value::Null)
+}
+
+/**
+    Convert a json result of the engine to a javascript value.
+**/
+pub fn from_value(value: &Value) -> Result<JsValue, JsValue> {
+    JSON::parse(&value.to_string())
+}
+
+/**
+    Whole numbers are integers, the same as when the number is parsed from json text.
+**/
+fn number_value(number: f64) -> Value {
+    const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+    if number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER {
+        return Value::from(number as i64);
+    }
+
+    Value::from(number)
+}
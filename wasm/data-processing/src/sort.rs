@@ -1,6 +0,0 @@
-mod sort_all;
-mod sort_partial;
-
-pub use sort_all::sort_all;
-pub use sort_partial::sort_partial;
-
@@ -1,5 +0,0 @@
-mod unique_values_all;
-mod unique_values_partial;
-
-pub use unique_values_all::unique_values_all;
-pub use unique_values_partial::unique_values_partial;
\ No newline at end of file
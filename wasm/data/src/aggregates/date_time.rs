@@ -1,5 +0,0 @@
-mod min;
-mod max;
-
-pub use min::Min;
-pub use max::Max;
\ No newline at end of file
@@ -1,32 +0,0 @@
-mod greater_than;
-mod less_than;
-mod greater_or_equal;
-mod less_or_equal;
-mod equal;
-mod not_equal;
-mod object_evaluator;
-mod is_null;
-mod is_not_null;
-mod like;
-mod not_like;
-mod one_of;
-mod between;
-mod starts_with;
-mod ends_with;
-
-pub use greater_than::GreaterThan;
-pub use less_than::LessThan;
-pub use greater_or_equal::GreaterOrEqual;
-pub use less_or_equal::LessOrEqual;
-pub use equal::Equal;
-pub use not_equal::NotEqual;
-pub use is_null::IsNull;
-pub use is_not_null::IsNotNull;
-pub use like::Like;
-pub use not_like::NotLike;
-pub use one_of::OneOf;
-pub use between::Between;
-pub use starts_with::StartsWith;
-pub use ends_with::EndsWith;
-
-pub use object_evaluator::evaluate_object;
\ No newline at end of file
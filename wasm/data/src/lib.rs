@@ -2,19 +2,17 @@
 
 use wasm_bindgen::prelude::*;
 use serde_json::{Value};
-use crate::evaluators::evaluate_object;
+use data_engine::{columnar, intent, processors, validate};
+use data_engine::evaluators::{evaluate_object, FilterPlan};
 
-mod evaluators;
-mod macros;
-mod processors;
-mod utils;
-mod duration;
-mod enums;
-mod aggregates;
-mod traits;
+mod store;
 
-use crate::duration::{iso8601_to_duration_str, iso8601_to_duration_str_batch};
-use crate::processors::get_unique;
+use data_engine::columnar::ColumnTable;
+use data_engine::duration::{iso8601_to_duration_str, iso8601_to_duration_str_batch};
+use data_engine::processors::{get_unique, LivePerspective};
+use data_engine::error::{DataError, DataResult};
+use data_engine::utils::check_rows;
+use data_engine::intent::{Intent, FilterExpr, SortField, GroupSpec, Aggregates, UniqueField, Perspective, Pivot, RowDelta};
 
 #[wasm_bindgen]
 pub fn init_panic_hook() {
@@ -23,120 +21,337 @@ pub fn init_panic_hook() {
 
 /// Test if a object is visible in the scope of the defined filter.
 #[wasm_bindgen]
-pub fn in_filter(intent: String, object: String, case_sensitive: bool) -> bool {
-    let filters: Vec<Value> = serde_json::from_str(intent.as_str()).unwrap();
-    let obj: Value = serde_json::from_str(object.as_str()).unwrap();
-    return processors::in_filter(&filters, &obj, case_sensitive);
+pub fn in_filter(intent: String, object: String, case_sensitive: bool) -> Result<bool, JsValue> {
+    let filters: Vec<FilterExpr> = parse_intent(&intent, "filter")?;
+    let obj: Value = parse_json(&object, "object")?;
+    Ok(processors::in_filter(&filters, &obj, case_sensitive))
 }
 
 /// Filter a set of records and give back the indexes of the records visible in the filter.
 #[wasm_bindgen]
-pub fn filter_data(intent: String, data: String, case_sensitive: bool) -> Vec<usize> {
-    let intent_val: Vec<Value> = serde_json::from_str(intent.as_str()).unwrap();
-    let data_array: Vec<Value> = serde_json::from_str(data.as_str()).unwrap();
+pub fn filter_data(intent: String, data: String, case_sensitive: bool) -> Result<Vec<usize>, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(filter_records(&intent, &data_array, None, case_sensitive)?)
+}
 
-    return processors::filter(&intent_val, &data_array, case_sensitive);
+/// A filter intent compiled once so it can be run against many datasets without parsing the intent again.
+#[wasm_bindgen]
+pub struct FilterHandle {
+    plan: FilterPlan
 }
 
+/// Compile the filter intent into a plan that is kept in wasm, use run_filter to apply it.
 #[wasm_bindgen]
-pub fn group_data(intent: String, data: String) -> String {
-    let intent_array: Vec<&str> = serde_json::from_str(intent.as_str()).unwrap();
-    let data_array: Vec<Value> = serde_json::from_str(data.as_str()).unwrap();
+pub fn compile_filter(intent: String, case_sensitive: bool) -> Result<FilterHandle, JsValue> {
+    let filters: Vec<FilterExpr> = parse_intent(&intent, "filter")?;
 
-    let result = processors::group(&intent_array, &data_array, None, None);
+    Ok(FilterHandle {
+        plan: FilterPlan::compile(&filters, case_sensitive)
+    })
+}
 
-    return result.to_string();
+/// Give back the indexes of the records visible in the compiled filter.
+#[wasm_bindgen]
+pub fn run_filter(handle: &FilterHandle, data: String) -> Result<Vec<usize>, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(handle.plan.run(&data_array))
 }
 
 #[wasm_bindgen]
-pub fn sort_data(intent: String, data: String, rows: Vec<usize>) -> Vec<usize> {
-    let intent_value: Vec<Value> = serde_json::from_str(intent.as_str()).unwrap();
-    let data_array: Vec<Value> = serde_json::from_str(data.as_str()).unwrap();
+pub fn group_data(intent: String, data: String) -> Result<String, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(group_records(&intent, &data_array, None)?)
+}
 
-    let sort_rows;
-    if rows.len() == 0 {
-        sort_rows = None;
-    }
-    else {
-        sort_rows = Some(rows);
-    }
+#[wasm_bindgen]
+pub fn sort_data(intent: String, data: String, rows: Vec<usize>) -> Result<Vec<usize>, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(sort_records(&intent, &data_array, None, rows)?)
+}
 
-    return processors::sort(&intent_value, &data_array, sort_rows);
+#[wasm_bindgen]
+pub fn aggregate_rows(intent: String, data: String, rows: Vec<usize>) -> Result<String, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(aggregate_records(&intent, &data_array, None, rows)?)
 }
 
 #[wasm_bindgen]
-pub fn aggregate_rows(intent: String, data: String, rows: Vec<usize>) -> String {
-    let intent_obj: Value = serde_json::from_str(intent.as_str()).unwrap();
-    let data_array: Vec<Value> = serde_json::from_str(data.as_str()).unwrap();
+pub fn calculate_group_aggregate(group: String, aggregate_intent: String, data: String) -> Result<String, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(group_aggregate_records(&group, &aggregate_intent, &data_array, None)?)
+}
 
-    let agg_rows;
-    if rows.len() == 0 {
-        agg_rows = None;
-    }
-    else {
-        agg_rows = Some(rows);
-    }
+#[wasm_bindgen]
+pub fn unique_values(intent: String, data: String, rows: Vec<usize>) -> Result<String, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(unique_records(&intent, &data_array, rows)?)
+}
 
-    let result = processors::aggregate_rows(&intent_obj, &data_array, agg_rows);
+/// Convert PT100H30M into "0:0:100:30:0"
+#[wasm_bindgen]
+pub fn iso8601_to_string(duration: String) -> String {
+    iso8601_to_duration_str(&Value::from(duration))
+}
 
-    return result.to_string();
+#[wasm_bindgen]
+pub fn iso8601_batch(dates: String, field_name: Option<String>) -> Result<String, JsValue> {
+    let dates_array: Vec<Value> = parse_json(&dates, "dates")?;
+    let result = iso8601_to_duration_str_batch(dates_array, field_name);
+    let obj = Value::from(result);
+    Ok(obj.to_string())
 }
 
 #[wasm_bindgen]
-pub fn calculate_group_aggregate(group: String, aggregate_intent: String, data: String) -> String {
-    let mut group_obj = serde_json::from_str(group.as_str()).unwrap();
-    let agg_obj = serde_json::from_str(aggregate_intent.as_str()).unwrap();
-    let data_array: Vec<Value> = serde_json::from_str(data.as_str()).unwrap();
+pub fn evaluate_obj(expr: String, object: String, case_sensitive: bool) -> Result<bool, JsValue> {
+    let intent_obj: Value = parse_json(&expr, "expression")?;
+    let object_obj: Value = parse_json(&object, "object")?;
 
-    processors::calculate_group_aggregate(&mut group_obj, &agg_obj, &data_array);
+    Ok(evaluate_object(&intent_obj, &object_obj, case_sensitive)?)
+}
 
-    return group_obj.to_string();
+#[wasm_bindgen]
+pub fn build_perspective(intent: String, data: String, rows: Vec<usize>) -> Result<String, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(perspective_records(&intent, &data_array, None, rows)?)
 }
 
+/// The cross tab of the records for a grid or an export, rows and columns are group fields with their totals.
+/// An empty rows collection uses all the records.
 #[wasm_bindgen]
-pub fn unique_values(intent: String, data: String, rows: Vec<usize>) -> String {
-    let fields_array: Vec<Value> = serde_json::from_str(intent.as_str()).unwrap();
-    let data_array: Vec<Value> = serde_json::from_str(data.as_str()).unwrap();
+pub fn pivot_data(intent: String, data: String, rows: Vec<usize>) -> Result<String, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(pivot_records(&intent, &data_array, rows)?)
+}
 
-    let unique_rows;
-    if rows.len() == 0 {
-        unique_rows = None;
-    }
-    else {
-        unique_rows = Some(rows);
-    }
+/// A perspective kept in wasm that is updated with row deltas instead of being built again.
+#[wasm_bindgen]
+pub struct PerspectiveHandle {
+    perspective: LivePerspective
+}
+
+/// Build a live perspective on the data, with an id field the deltas can find rows by id.
+#[wasm_bindgen]
+pub fn create_perspective(intent: String, data: String, id_field: Option<String>) -> Result<PerspectiveHandle, JsValue> {
+    let intent_obj: Value = parse_json(&intent, "perspective")?;
+    let perspective: Perspective = intent::parse(&intent_obj, "")?;
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
 
-    let result = get_unique(fields_array, &data_array, unique_rows);
-    return result.to_string();
+    Ok(PerspectiveHandle {
+        perspective: LivePerspective::new(perspective, data_array, id_field)?
+    })
 }
 
-/// Convert PT100H30M into "0:0:100:30:0"
+/// Apply a json array of insert, update and delete deltas and give back a json diff of what changed.
+/// When a delta fails the ones before it stay applied, get_perspective gives the current state.
 #[wasm_bindgen]
-pub fn iso8601_to_string(duration: String) -> String {
-    iso8601_to_duration_str(&Value::from(duration))
+pub fn update_perspective(handle: &mut PerspectiveHandle, deltas: String) -> Result<String, JsValue> {
+    let deltas: Vec<RowDelta> = parse_intent(&deltas, "deltas")?;
+    let diff = handle.perspective.apply(&deltas).map_err(|error| error.at("deltas"))?;
+    Ok(diff.to_string())
 }
 
+/// The current result of the live perspective, the same as build_perspective.
 #[wasm_bindgen]
-pub fn iso8601_batch(dates: String, field_name: Option<String>) -> String {
-    let dates_array: Vec<Value> = serde_json::from_str(dates.as_str()).unwrap();
-    let result = iso8601_to_duration_str_batch(dates_array, field_name);
-    let obj = Value::from(result);
-    return obj.to_string();
+pub fn get_perspective(handle: &PerspectiveHandle) -> String {
+    handle.perspective.result().to_string()
 }
 
+/// Check an intent of the given kind (filter, sort, group, aggregate, perspective or pivot) without running it.
+/// Gives back a json array of all the problems found, each with a code, path and message.
 #[wasm_bindgen]
-pub fn evaluate_obj(expr: String, object: String, case_sensitive: bool) -> bool {
-    let intent_obj = serde_json::from_str(expr.as_str()).unwrap();
-    let object_obj = serde_json::from_str(object.as_str()).unwrap();
+pub fn validate_intent(kind: String, intent: String) -> Result<String, JsValue> {
+    let intent_obj: Value = parse_json(&intent, kind.as_str())?;
+    let problems = validate::validate_intent(&kind, &intent_obj)?;
 
-    evaluate_object(&intent_obj, &object_obj, case_sensitive)
+    let result: Vec<Value> = problems.iter().map(DataError::to_value).collect();
+    Ok(Value::from(result).to_string())
+}
+
+/// Load a json array of records into the store and give back the id of the dataset.
+/// The processors ending in "_store" work on the stored dataset so the records are only sent across once.
+#[wasm_bindgen]
+pub fn store_load(data: String) -> Result<u32, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(store::with_store(|store| Ok(store.load(data_array)))?)
+}
+
+/// Same as store_load but the dataset is columnar, the processors read typed columns instead of the json rows.
+/// Use it for large datasets that are filtered, sorted or grouped often, the results are the same.
+#[wasm_bindgen]
+pub fn store_load_columnar(data: String) -> Result<u32, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(store::with_store(|store| Ok(store.load_columnar(data_array)))?)
+}
+
+/// Add a json array of records to the end of the dataset, gives back the new record count.
+#[wasm_bindgen]
+pub fn store_append(id: u32, data: String) -> Result<usize, JsValue> {
+    let data_array: Vec<Value> = parse_json(&data, "data")?;
+    Ok(store::with_store(|store| store.append(id, data_array))?)
+}
+
+/// Replace the record at the index with the json object.
+#[wasm_bindgen]
+pub fn store_update_row(id: u32, index: usize, row: String) -> Result<(), JsValue> {
+    let row_obj: Value = parse_json(&row, "row")?;
+    Ok(store::with_store(|store| store.update_row(id, index, row_obj))?)
+}
+
+/// Remove the records at the indexes, the records after them move up. Gives back the new record count.
+#[wasm_bindgen]
+pub fn store_remove_rows(id: u32, rows: Vec<usize>) -> Result<usize, JsValue> {
+    Ok(store::with_store(|store| store.remove_rows(id, &rows))?)
+}
+
+/// Release the dataset, the id can't be used after this.
+#[wasm_bindgen]
+pub fn store_drop(id: u32) -> Result<(), JsValue> {
+    Ok(store::with_store(|store| store.remove(id))?)
+}
+
+#[wasm_bindgen]
+pub fn filter_store(id: u32, intent: String, case_sensitive: bool) -> Result<Vec<usize>, JsValue> {
+    Ok(store::with_dataset(id, |data, columns| filter_records(&intent, data, columns, case_sensitive))?)
 }
 
 #[wasm_bindgen]
-pub fn build_perspective(intent: String, data: String, rows: Vec<usize>) -> String {
-    let intent_obj = serde_json::from_str(intent.as_str()).unwrap();
-    let data_array: Vec<Value> = serde_json::from_str(data.as_str()).unwrap();
+pub fn run_filter_store(handle: &FilterHandle, id: u32) -> Result<Vec<usize>, JsValue> {
+    Ok(store::with_data(id, |data| Ok(handle.plan.run(data)))?)
+}
+
+#[wasm_bindgen]
+pub fn group_store(id: u32, intent: String) -> Result<String, JsValue> {
+    Ok(store::with_dataset(id, |data, columns| group_records(&intent, data, columns))?)
+}
+
+#[wasm_bindgen]
+pub fn sort_store(id: u32, intent: String, rows: Vec<usize>) -> Result<Vec<usize>, JsValue> {
+    Ok(store::with_dataset(id, |data, columns| sort_records(&intent, data, columns, rows))?)
+}
+
+#[wasm_bindgen]
+pub fn aggregate_store(id: u32, intent: String, rows: Vec<usize>) -> Result<String, JsValue> {
+    Ok(store::with_dataset(id, |data, columns| aggregate_records(&intent, data, columns, rows))?)
+}
+
+#[wasm_bindgen]
+pub fn group_aggregate_store(id: u32, group: String, aggregate_intent: String) -> Result<String, JsValue> {
+    Ok(store::with_dataset(id, |data, columns| group_aggregate_records(&group, &aggregate_intent, data, columns))?)
+}
+
+#[wasm_bindgen]
+pub fn unique_store(id: u32, intent: String, rows: Vec<usize>) -> Result<String, JsValue> {
+    Ok(store::with_data(id, |data| unique_records(&intent, data, rows))?)
+}
+
+#[wasm_bindgen]
+pub fn perspective_store(id: u32, intent: String, rows: Vec<usize>) -> Result<String, JsValue> {
+    Ok(store::with_dataset(id, |data, columns| perspective_records(&intent, data, columns, rows))?)
+}
+
+#[wasm_bindgen]
+pub fn pivot_store(id: u32, intent: String, rows: Vec<usize>) -> Result<String, JsValue> {
+    Ok(store::with_data(id, |data| pivot_records(&intent, data, rows))?)
+}
+
+fn filter_records(intent: &str, data: &[Value], columns: Option<&ColumnTable>, case_sensitive: bool) -> DataResult<Vec<usize>> {
+    let filters: Vec<FilterExpr> = parse_intent(intent, "filter")?;
+
+    match columns {
+        None => Ok(processors::filter(&filters, data, case_sensitive)),
+        Some(table) => Ok(columnar::filter(table, &filters, data, case_sensitive))
+    }
+}
+
+fn group_records(intent: &str, data: &[Value], columns: Option<&ColumnTable>) -> DataResult<String> {
+    let intent_array: Vec<GroupSpec> = parse_intent(intent, "group")?;
+
+    let result = match columns {
+        None => processors::group(&intent_array, data, None, None)?,
+        Some(table) => columnar::group(table, &intent_array, data, None, None)?
+    };
+
+    Ok(result.to_string())
+}
+
+fn sort_records(intent: &str, data: &[Value], columns: Option<&ColumnTable>, rows: Vec<usize>) -> DataResult<Vec<usize>> {
+    let intent_value: Vec<SortField> = parse_intent(intent, "sort")?;
+    let sort_rows = get_rows(rows, data)?;
+
+    match columns {
+        None => Ok(processors::sort(&intent_value, data, sort_rows)),
+        Some(table) => Ok(columnar::sort(table, &intent_value, data, sort_rows))
+    }
+}
+
+fn aggregate_records(intent: &str, data: &[Value], columns: Option<&ColumnTable>, rows: Vec<usize>) -> DataResult<String> {
+    let intent_obj: Aggregates = parse_intent(intent, "aggregates")?;
+    let agg_rows = get_rows(rows, data)?;
+
+    let result = match columns {
+        None => processors::aggregate_rows(&intent_obj.0, data, agg_rows)?,
+        Some(table) => columnar::aggregate_rows(table, &intent_obj.0, data, agg_rows)?
+    };
+
+    Ok(result.to_string())
+}
+
+fn group_aggregate_records(group: &str, aggregate_intent: &str, data: &[Value], columns: Option<&ColumnTable>) -> DataResult<String> {
+    let mut group_obj: Value = parse_json(group, "group")?;
+    let agg_obj: Aggregates = parse_intent(aggregate_intent, "aggregates")?;
+
+    match columns {
+        None => processors::calculate_group_aggregate(&mut group_obj, &agg_obj.0, data)?,
+        Some(table) => columnar::calculate_group_aggregate(table, &mut group_obj, &agg_obj.0, data)?
+    }
+
+    Ok(group_obj.to_string())
+}
+
+fn unique_records(intent: &str, data: &[Value], rows: Vec<usize>) -> DataResult<String> {
+    let fields_array: Vec<UniqueField> = parse_intent(intent, "fields")?;
+    let unique_rows = get_rows(rows, data)?;
+    let result = get_unique(&fields_array, data, unique_rows)?;
+    Ok(result.to_string())
+}
+
+fn perspective_records(intent: &str, data: &[Value], columns: Option<&ColumnTable>, rows: Vec<usize>) -> DataResult<String> {
+    let intent_obj: Value = parse_json(intent, "perspective")?;
+    check_rows(&rows, data.len())?;
+
+    // the perspective properties are the roots of the error paths, for example "filter[2].operator"
+    let perspective: Perspective = intent::parse(&intent_obj, "")?;
+    processors::build_perspective(&perspective, data, columns, &rows)
+}
+
+fn pivot_records(intent: &str, data: &[Value], rows: Vec<usize>) -> DataResult<String> {
+    let intent_obj: Value = parse_json(intent, "pivot")?;
+    let pivot_rows = get_rows(rows, data)?;
+
+    // the same as the perspective, the pivot properties are the roots of the error paths
+    let pivot: Pivot = intent::parse(&intent_obj, "")?;
+    let result = processors::pivot(&pivot, data, pivot_rows)?;
+    Ok(result.to_string())
+}
+
+/// Parse a json argument, the name is used as the error path so the caller knows which argument failed.
+fn parse_json<'a, T: serde::Deserialize<'a>>(json: &'a str, name: &str) -> DataResult<T> {
+    serde_json::from_str(json).map_err(|error| DataError::invalid_json(name, error))
+}
+
+/// Parse a json intent argument into the intent model, the name is the root of the error path.
+/// The json is read into a value first so aggregates keep the same order as before the model existed.
+fn parse_intent<T: Intent>(json: &str, name: &str) -> DataResult<T> {
+    let value: Value = parse_json(json, name)?;
+    intent::parse(&value, name)
+}
+
+/// An empty rows collection means all the rows must be processed.
+fn get_rows(rows: Vec<usize>, data: &[Value]) -> DataResult<Option<Vec<usize>>> {
+    if rows.is_empty() {
+        return Ok(None);
+    }
 
-    let result = processors::build_perspective(&intent_obj, &data_array, &rows);
-    return result;
+    check_rows(&rows, data.len())?;
+    Ok(Some(rows))
 }
\ No newline at end of file
@@ -1,17 +0,0 @@
-mod filter;
-mod sort;
-mod group;
-mod aggregate;
-mod unique;
-mod perspective;
-//mod summary;
-//mod structures;
-
-pub use filter::{filter, in_filter};
-pub use sort::{sort};
-pub use group::group;
-pub use group::get_group_rows;
-pub use group::calculate_group_aggregate;
-pub use aggregate::aggregate_rows;
-pub use unique::get_unique;
-pub use perspective::build_perspective;
\ No newline at end of file
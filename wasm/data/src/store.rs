@@ -0,0 +1,246 @@
+use std::cell::RefCell;
+use hashbrown::HashMap;
+use serde_json::Value;
+use data_engine::columnar::ColumnTable;
+use data_engine::error::{DataError, DataResult, ErrorCode};
+use data_engine::utils::check_rows;
+
+thread_local! {
+    static STORE: RefCell<DataStore> = RefCell::new(DataStore::default());
+}
+
+/// The records of a stored dataset.
+/// A columnar dataset also keeps its fields as typed columns that the processors run on.
+pub struct Dataset {
+    pub rows: Vec<Value>,
+    pub columns: Option<ColumnTable>
+}
+
+/// Datasets kept in wasm memory so they only cross the js boundary once.
+/// Each dataset is known by the id it got when it was loaded.
+#[derive(Default)]
+pub struct DataStore {
+    datasets: HashMap<u32, Dataset>,
+    last_id: u32
+}
+
+impl DataStore {
+    pub fn load(&mut self, data: Vec<Value>) -> u32 {
+        self.insert(Dataset { rows: data, columns: None })
+    }
+
+    /// Load the data as a columnar dataset.
+    pub fn load_columnar(&mut self, data: Vec<Value>) -> u32 {
+        self.insert(Dataset { rows: data, columns: Some(ColumnTable::default()) })
+    }
+
+    fn insert(&mut self, dataset: Dataset) -> u32 {
+        self.last_id += 1;
+        self.datasets.insert(self.last_id, dataset);
+        self.last_id
+    }
+
+    pub fn get(&self, id: u32) -> DataResult<&Vec<Value>> {
+        Ok(&self.dataset(id)?.rows)
+    }
+
+    pub fn dataset(&self, id: u32) -> DataResult<&Dataset> {
+        self.datasets.get(&id).ok_or_else(|| unknown_dataset(id))
+    }
+
+    /// The rows of the dataset to change, the columns are cleared because they no longer match.
+    fn get_mut(&mut self, id: u32) -> DataResult<&mut Vec<Value>> {
+        let dataset = self.datasets.get_mut(&id).ok_or_else(|| unknown_dataset(id))?;
+
+        if let Some(columns) = &mut dataset.columns {
+            columns.clear();
+        }
+
+        Ok(&mut dataset.rows)
+    }
+
+    /// Add the rows to the end of the dataset, gives back the new record count.
+    pub fn append(&mut self, id: u32, rows: Vec<Value>) -> DataResult<usize> {
+        let data = self.get_mut(id)?;
+        data.extend(rows);
+        Ok(data.len())
+    }
+
+    pub fn update_row(&mut self, id: u32, index: usize, row: Value) -> DataResult<()> {
+        let data = self.get_mut(id)?;
+
+        match data.get_mut(index) {
+            None => {
+                let message = format!("row {} is out of range for {} records", index, data.len());
+                Err(DataError::new(ErrorCode::RowOutOfRange, "index", message))
+            }
+            Some(record) => {
+                *record = row;
+                Ok(())
+            }
+        }
+    }
+
+    /// Remove the rows from the dataset, the records after them move up to fill the gap.
+    /// Gives back the new record count.
+    pub fn remove_rows(&mut self, id: u32, rows: &[usize]) -> DataResult<usize> {
+        let data = self.get_mut(id)?;
+        check_rows(rows, data.len())?;
+
+        let mut keep = vec![true; data.len()];
+        for row in rows {
+            keep[*row] = false;
+        }
+
+        let mut index = 0;
+        data.retain(|_| {
+            index += 1;
+            keep[index - 1]
+        });
+
+        Ok(data.len())
+    }
+
+    pub fn remove(&mut self, id: u32) -> DataResult<()> {
+        match self.datasets.remove(&id) {
+            None => Err(unknown_dataset(id)),
+            Some(_) => Ok(())
+        }
+    }
+}
+
+fn unknown_dataset(id: u32) -> DataError {
+    DataError::new(ErrorCode::UnknownDataset, "id", format!("there is no dataset with id {}", id))
+}
+
+/// Run the callback with the store that lives for the lifetime of the wasm module.
+pub fn with_store<T>(callback: impl FnOnce(&mut DataStore) -> DataResult<T>) -> DataResult<T> {
+    STORE.with(|store| callback(&mut store.borrow_mut()))
+}
+
+/// Run the callback against the records of a stored dataset.
+pub fn with_data<T>(id: u32, callback: impl FnOnce(&[Value]) -> DataResult<T>) -> DataResult<T> {
+    with_store(|store| callback(store.get(id)?))
+}
+
+/// Run the callback against the records of a stored dataset and its columns if it is columnar.
+pub fn with_dataset<T>(id: u32, callback: impl FnOnce(&[Value], Option<&ColumnTable>) -> DataResult<T>) -> DataResult<T> {
+    with_store(|store| {
+        let dataset = store.dataset(id)?;
+        callback(&dataset.rows, dataset.columns.as_ref())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+    use data_engine::error::ErrorCode;
+    use data_engine::path::FieldPath;
+    use crate::store::{with_data, with_dataset, with_store, DataStore};
+
+    fn get_data() -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+        result.push(json!({"id": 0, "code": "A", "value": 10}));
+        result.push(json!({"id": 1, "code": "B", "value": 10}));
+        result.push(json!({"id": 2, "code": "C", "value": 20}));
+        result
+    }
+
+    #[test]
+    fn load_test() {
+        let mut store = DataStore::default();
+        let id1 = store.load(get_data());
+        let id2 = store.load(Vec::new());
+
+        assert_ne!(id1, id2);
+        assert_eq!(store.get(id1).unwrap().len(), 3);
+        assert_eq!(store.get(id2).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn append_test() {
+        let mut store = DataStore::default();
+        let id = store.load(get_data());
+
+        assert_eq!(store.append(id, vec![json!({"id": 3, "code": "D", "value": 5})]), Ok(4));
+        assert_eq!(store.get(id).unwrap()[3]["code"], "D");
+    }
+
+    #[test]
+    fn update_row_test() {
+        let mut store = DataStore::default();
+        let id = store.load(get_data());
+
+        store.update_row(id, 1, json!({"id": 1, "code": "X", "value": 1})).unwrap();
+        assert_eq!(store.get(id).unwrap()[1]["code"], "X");
+
+        let error = store.update_row(id, 3, json!({})).unwrap_err();
+        assert_eq!(error.code, ErrorCode::RowOutOfRange);
+        assert_eq!(error.path, "index");
+    }
+
+    #[test]
+    fn remove_rows_test() {
+        let mut store = DataStore::default();
+        let id = store.load(get_data());
+
+        assert_eq!(store.remove_rows(id, &[2, 0, 2]), Ok(1));
+        assert_eq!(store.get(id).unwrap()[0]["code"], "B");
+
+        let error = store.remove_rows(id, &[1]).unwrap_err();
+        assert_eq!(error.code, ErrorCode::RowOutOfRange);
+    }
+
+    #[test]
+    fn remove_test() {
+        let mut store = DataStore::default();
+        let id = store.load(get_data());
+
+        store.remove(id).unwrap();
+
+        let error = store.get(id).unwrap_err();
+        assert_eq!(error.code, ErrorCode::UnknownDataset);
+        assert_eq!(error.path, "id");
+        assert_eq!(store.remove(id).unwrap_err().code, ErrorCode::UnknownDataset);
+    }
+
+    #[test]
+    fn with_data_test() {
+        let id = with_store(|store| Ok(store.load(get_data()))).unwrap();
+
+        let count = with_data(id, |data| Ok(data.len())).unwrap();
+        assert_eq!(count, 3);
+
+        with_store(|store| store.remove(id)).unwrap();
+        assert!(with_data(id, |data| Ok(data.len())).is_err());
+    }
+
+    #[test]
+    fn columnar_test() {
+        let mut store = DataStore::default();
+        let id = store.load_columnar(get_data());
+        let row_id = store.load(get_data());
+        assert!(store.dataset(id).unwrap().columns.is_some());
+        assert!(store.dataset(row_id).unwrap().columns.is_none());
+
+        let columns = store.dataset(id).unwrap().columns.as_ref().unwrap();
+        let column = columns.column(store.get(id).unwrap(), &FieldPath::new("code"));
+        assert_eq!(column.value(1).as_ref(), "B");
+
+        store.update_row(id, 1, json!({"id": 1, "code": "X", "value": 1})).unwrap();
+
+        let columns = store.dataset(id).unwrap().columns.as_ref().unwrap();
+        let column = columns.column(store.get(id).unwrap(), &FieldPath::new("code"));
+        assert_eq!(column.value(1).as_ref(), "X");
+    }
+
+    #[test]
+    fn with_dataset_test() {
+        let id = with_store(|store| Ok(store.load_columnar(get_data()))).unwrap();
+
+        let columnar = with_dataset(id, |data, columns| Ok(data.len() == 3 && columns.is_some())).unwrap();
+        assert!(columnar);
+
+        with_store(|store| store.remove(id)).unwrap();
+    }
+}